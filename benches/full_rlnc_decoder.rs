@@ -1,7 +1,12 @@
 use rand::Rng;
 use rlnc::{
     RLNCError,
-    full::{decoder::Decoder, encoder::Encoder},
+    full::{
+        coded_piece::CodedPiece,
+        decoder::{Decoder, DecoderMatrix, MatrixLayout},
+        encoder::Encoder,
+        recoder::Recoder,
+    },
 };
 use std::{fmt::Debug, time::Duration};
 
@@ -105,25 +110,58 @@ const ARGS: &[RLNCConfig] = &[
 
 #[divan::bench(args = ARGS, max_time = Duration::from_secs(100), skip_ext_time = true)]
 fn decode(bencher: divan::Bencher, rlnc_config: &RLNCConfig) {
+    decode_with_layout(bencher, rlnc_config, MatrixLayout::default())
+}
+
+#[divan::bench(args = ARGS, max_time = Duration::from_secs(100), skip_ext_time = true)]
+fn decode_coefficient_separated(bencher: divan::Bencher, rlnc_config: &RLNCConfig) {
+    decode_with_layout(bencher, rlnc_config, MatrixLayout::CoefficientSeparated)
+}
+
+fn decode_with_layout(bencher: divan::Bencher, rlnc_config: &RLNCConfig, layout: MatrixLayout) {
     let mut rng = rand::rng();
 
     let data = (0..rlnc_config.data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
     let encoder = Encoder::new(data, rlnc_config.piece_count).expect("Failed to create RLNC encoder");
 
     let num_pieces_to_produce = rlnc_config.piece_count * 2;
-    let coded_pieces = (0..num_pieces_to_produce).flat_map(|_| encoder.code(&mut rng)).collect::<Vec<u8>>();
+    let coded_pieces = (0..num_pieces_to_produce).map(|_| encoder.code(&mut rng)).collect::<Vec<CodedPiece>>();
+
+    decode_coded_pieces(bencher, &encoder, &coded_pieces, layout);
+}
+
+/// Decodes systematic pieces i.e. uncoded original pieces, each prefixed by a unit coding vector, which take the
+/// decoder's fast path, skipping the elimination. Compare against `decode`, to see how much that saves.
+#[divan::bench(args = ARGS, max_time = Duration::from_secs(100), skip_ext_time = true)]
+fn decode_systematic(bencher: divan::Bencher, rlnc_config: &RLNCConfig) {
+    let mut rng = rand::rng();
+
+    let data = (0..rlnc_config.data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+    let encoder = Encoder::new(data, rlnc_config.piece_count).expect("Failed to create RLNC encoder");
+
+    let coded_pieces = (0..rlnc_config.piece_count)
+        .map(|piece_idx| {
+            let mut coding_vector = vec![0u8; rlnc_config.piece_count];
+            coding_vector[piece_idx] = 1;
+
+            encoder.code_with_coding_vector(&coding_vector).expect("Failed to code systematic piece")
+        })
+        .collect::<Vec<CodedPiece>>();
+
+    decode_coded_pieces(bencher, &encoder, &coded_pieces, MatrixLayout::default());
+}
+
+fn decode_coded_pieces(bencher: divan::Bencher, encoder: &Encoder, coded_pieces: &[CodedPiece], layout: MatrixLayout) {
+    let num_pieces_to_produce = coded_pieces.len();
 
     bencher
-        .with_inputs(|| Decoder::new(encoder.get_piece_byte_len(), encoder.get_piece_count()).expect("Failed to create RLNC decoder"))
+        .with_inputs(|| Decoder::with_matrix_layout(encoder.get_piece_byte_len(), encoder.get_piece_count(), layout).expect("Failed to create RLNC decoder"))
         .input_counter(|decoder| divan::counter::BytesCount::new(decoder.get_full_coded_piece_byte_len() * decoder.get_num_pieces_coded_together()))
         .bench_refs(|mut decoder| {
             let mut piece_index = 0;
-            let per_piece_byte_len = coded_pieces.len() / num_pieces_to_produce;
 
             while piece_index < num_pieces_to_produce {
-                let coded_piece_begins_at = piece_index * per_piece_byte_len;
-                let coded_piece_ends_at = coded_piece_begins_at + per_piece_byte_len;
-                let coded_piece = &coded_pieces[coded_piece_begins_at..coded_piece_ends_at];
+                let coded_piece = &coded_pieces[piece_index];
 
                 match divan::black_box(&mut decoder).decode(divan::black_box(coded_piece)) {
                     Ok(_) => {}
@@ -138,3 +176,109 @@ fn decode(bencher: divan::Bencher, rlnc_config: &RLNCConfig) {
             }
         });
 }
+
+/// Configurations where number of pieces coded together is close to the byte length of each piece, so that
+/// almost half of each full coded piece is coding vector, making arithmetic over coefficients as costly as
+/// arithmetic over coded data.
+const COEFFICIENT_DOMINATED_ARGS: &[RLNCConfig] = &[
+    RLNCConfig {
+        data_byte_len: 1usize << 10,
+        piece_count: 1usize << 5,
+    },
+    RLNCConfig {
+        data_byte_len: 1usize << 12,
+        piece_count: 1usize << 6,
+    },
+    RLNCConfig {
+        data_byte_len: 1usize << 14,
+        piece_count: 1usize << 7,
+    },
+    RLNCConfig {
+        data_byte_len: 1usize << 16,
+        piece_count: 1usize << 8,
+    },
+];
+
+#[divan::bench(args = COEFFICIENT_DOMINATED_ARGS, max_time = Duration::from_secs(100), skip_ext_time = true)]
+fn decode_coefficient_dominated(bencher: divan::Bencher, rlnc_config: &RLNCConfig) {
+    decode_with_layout(bencher, rlnc_config, MatrixLayout::default())
+}
+
+#[divan::bench(args = COEFFICIENT_DOMINATED_ARGS, max_time = Duration::from_secs(100), skip_ext_time = true)]
+fn decode_coefficient_dominated_coefficient_separated(bencher: divan::Bencher, rlnc_config: &RLNCConfig) {
+    decode_with_layout(bencher, rlnc_config, MatrixLayout::CoefficientSeparated)
+}
+
+/// Brings a full matrix, holding `piece_count` rows, to RREF, where only one in eight rows is linearly independent of the
+/// others, as on a lossy link delivering lots of dependent pieces, so that most rows zero out and get removed.
+#[divan::bench(args = COEFFICIENT_DOMINATED_ARGS, max_time = Duration::from_secs(100), skip_ext_time = true)]
+fn rref_mostly_dependent_rows(bencher: divan::Bencher, rlnc_config: &RLNCConfig) {
+    const INDEPENDENT_ROW_RATIO: usize = 8;
+
+    let mut rng = rand::rng();
+
+    let data = (0..rlnc_config.data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+    let encoder = Encoder::new(data, rlnc_config.piece_count).expect("Failed to create RLNC encoder");
+
+    let num_independent_pieces = (rlnc_config.piece_count / INDEPENDENT_ROW_RATIO).max(1);
+    let independent_pieces = encoder.coded_pieces(&mut rng).take(num_independent_pieces).flatten().collect::<Vec<u8>>();
+    let recoder =
+        Recoder::new(independent_pieces.clone(), encoder.get_full_coded_piece_byte_len(), encoder.get_piece_count()).expect("Failed to create RLNC recoder");
+
+    let mut rows = independent_pieces;
+    rows.extend((num_independent_pieces..rlnc_config.piece_count).flat_map(|_| recoder.recode(&mut rng)));
+    let rows_byte_len = rows.len();
+
+    bencher
+        .with_inputs(|| {
+            let mut matrix: DecoderMatrix =
+                DecoderMatrix::new(encoder.get_piece_count(), encoder.get_piece_byte_len()).expect("Failed to create decoder matrix");
+            rows.chunks_exact(encoder.get_full_coded_piece_byte_len()).for_each(|row| {
+                matrix.add_row(row).expect("Failed to add row to decoder matrix");
+            });
+
+            matrix
+        })
+        .input_counter(move |_| divan::counter::BytesCount::new(rows_byte_len))
+        .bench_refs(|matrix| {
+            divan::black_box(matrix).rref();
+        });
+}
+
+/// Brings a matrix back to RREF, after adding the last few rows, needed to reach full rank, to a matrix already holding
+/// all the others in RREF, as while decoding the final stretch of pieces. Only the new rows get reduced, against the
+/// pivots of the already reduced rows, instead of running Gaussian elimination over the nearly complete matrix.
+#[divan::bench(args = COEFFICIENT_DOMINATED_ARGS, max_time = Duration::from_secs(100), skip_ext_time = true)]
+fn rref_last_few_rows(bencher: divan::Bencher, rlnc_config: &RLNCConfig) {
+    const NUM_LAST_ROWS: usize = 4;
+
+    let mut rng = rand::rng();
+
+    let data = (0..rlnc_config.data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+    let encoder = Encoder::new(data, rlnc_config.piece_count).expect("Failed to create RLNC encoder");
+
+    let rows = encoder.coded_pieces(&mut rng).take(rlnc_config.piece_count).flatten().collect::<Vec<u8>>();
+    let (reduced_rows, last_rows) = rows.split_at((rlnc_config.piece_count - NUM_LAST_ROWS) * encoder.get_full_coded_piece_byte_len());
+    let last_rows_byte_len = last_rows.len();
+
+    let mut reduced_matrix: DecoderMatrix =
+        DecoderMatrix::new(encoder.get_piece_count(), encoder.get_piece_byte_len()).expect("Failed to create decoder matrix");
+    reduced_rows.chunks_exact(encoder.get_full_coded_piece_byte_len()).for_each(|row| {
+        reduced_matrix.add_row(row).expect("Failed to add row to decoder matrix");
+    });
+    reduced_matrix.rref();
+
+    bencher
+        .with_inputs(|| {
+            let mut matrix = reduced_matrix.clone();
+            last_rows.chunks_exact(encoder.get_full_coded_piece_byte_len()).for_each(|row| {
+                matrix.add_row(row).expect("Failed to add row to decoder matrix");
+            });
+
+            matrix
+        })
+        .input_counter(move |_| divan::counter::BytesCount::new(last_rows_byte_len))
+        .bench_refs(|matrix| {
+            divan::black_box(matrix).rref();
+        });
+}
@@ -115,3 +115,70 @@ fn encode(bencher: divan::Bencher, rlnc_config: &RLNCConfig) {
         .with_inputs(rand::rng)
         .bench_refs(|rng| divan::black_box(&encoder).code(divan::black_box(rng)));
 }
+
+/// Number of coded pieces produced by a single `Encoder::code_batch` call, in `encode_batch`.
+const BATCH_SIZE: usize = 64;
+
+#[divan::bench(args = ARGS, max_time = Duration::from_secs(100), skip_ext_time = true)]
+fn encode_batch(bencher: divan::Bencher, rlnc_config: &RLNCConfig) {
+    let mut rng = rand::rng();
+    let data = (0..rlnc_config.data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+
+    let encoder = Encoder::new(data, rlnc_config.piece_count).expect("Failed to create RLNC encoder");
+
+    bencher
+        .counter(divan::counter::BytesCount::new(
+            encoder.get_piece_byte_len() * encoder.get_piece_count() +  // Number of bytes used as input to encoder
+            encoder.get_full_coded_piece_byte_len() * BATCH_SIZE, // Number of bytes for all coded pieces of the batch
+        ))
+        .with_inputs(rand::rng)
+        .bench_refs(|rng| divan::black_box(&encoder).code_batch(divan::black_box(rng), BATCH_SIZE));
+}
+
+/// Objects of 1MB and beyond, where coding a piece, using `Encoder::code_with_coding_vector`, accumulates enough bytes of
+/// source pieces to be split across threads, with `parallel` feature.
+#[cfg(feature = "parallel")]
+const PARALLEL_ARGS: &[RLNCConfig] = &[
+    RLNCConfig {
+        data_byte_len: 1usize << 20,
+        piece_count: 1usize << 5,
+    },
+    RLNCConfig {
+        data_byte_len: 1usize << 24,
+        piece_count: 1usize << 6,
+    },
+    RLNCConfig {
+        data_byte_len: 1usize << 25,
+        piece_count: 1usize << 7,
+    },
+];
+
+/// Codes a piece with a fixed coding vector, inside a `rayon` thread pool of `NUM_THREADS` threads, showing how
+/// accumulating the scaled source pieces in parallel scales across cores.
+#[cfg(feature = "parallel")]
+#[divan::bench(consts = [1, 2, 4, 8], args = PARALLEL_ARGS, max_time = Duration::from_secs(100), skip_ext_time = true)]
+fn encode_with_coding_vector_on_threads<const NUM_THREADS: usize>(bencher: divan::Bencher, rlnc_config: &RLNCConfig) {
+    let mut rng = rand::rng();
+    let data = (0..rlnc_config.data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+
+    let encoder = Encoder::new(data, rlnc_config.piece_count).expect("Failed to create RLNC encoder");
+    let coding_vector = (0..encoder.get_piece_count()).map(|_| rng.random()).collect::<Vec<u8>>();
+
+    let thread_pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(NUM_THREADS)
+        .build()
+        .expect("Failed to build thread pool");
+
+    bencher
+        .counter(divan::counter::BytesCount::new(
+            encoder.get_piece_byte_len() * encoder.get_piece_count() +  // Number of bytes used as input to encoder
+            encoder.get_full_coded_piece_byte_len(), // Number of bytes for each coded piece
+        ))
+        .bench(|| {
+            thread_pool.install(|| {
+                divan::black_box(&encoder)
+                    .code_with_coding_vector(divan::black_box(&coding_vector))
+                    .expect("Failed to code piece")
+            })
+        });
+}
@@ -0,0 +1,88 @@
+use rand::Rng;
+use rlnc::common::{
+    gf256::Gf256,
+    simd::{active_simd_backend, gf256_inplace_add_vectors, gf256_inplace_mul_vec_by_scalar, gf256_mul_vec_by_scalar_then_add_into_vec},
+};
+use std::time::Duration;
+
+fn main() {
+    println!("GF(2^8) vector arithmetic dispatches to `{}` SIMD backend", active_simd_backend());
+    divan::Divan::default().bytes_format(divan::counter::BytesFormat::Binary).main();
+}
+
+const NUM_SCALAR_OPS: usize = 1usize << 10;
+const VECTOR_BYTE_LENS: &[usize] = &[1usize << 6, 1usize << 10, 1usize << 14, 1usize << 20];
+
+fn random_nonzero_symbols(count: usize) -> Vec<u8> {
+    let mut rng = rand::rng();
+    (0..count).map(|_| rng.random_range(1..=u8::MAX)).collect()
+}
+
+#[divan::bench(max_time = Duration::from_secs(10))]
+fn scalar_mul(bencher: divan::Bencher) {
+    bencher
+        .counter(divan::counter::ItemsCount::new(NUM_SCALAR_OPS))
+        .with_inputs(|| (random_nonzero_symbols(NUM_SCALAR_OPS), random_nonzero_symbols(NUM_SCALAR_OPS)))
+        .bench_refs(|(lhs, rhs)| {
+            lhs.iter().zip(rhs.iter()).fold(Gf256::zero(), |acc, (&a, &b)| {
+                acc + Gf256::new(divan::black_box(a)) * Gf256::new(divan::black_box(b))
+            })
+        });
+}
+
+#[divan::bench(max_time = Duration::from_secs(10))]
+fn scalar_mul_const(bencher: divan::Bencher) {
+    bencher
+        .counter(divan::counter::ItemsCount::new(NUM_SCALAR_OPS))
+        .with_inputs(|| (random_nonzero_symbols(NUM_SCALAR_OPS), random_nonzero_symbols(NUM_SCALAR_OPS)))
+        .bench_refs(|(lhs, rhs)| {
+            lhs.iter()
+                .zip(rhs.iter())
+                .fold(0u8, |acc, (&a, &b)| acc ^ Gf256::mul_const(divan::black_box(a), divan::black_box(b)))
+        });
+}
+
+#[divan::bench(max_time = Duration::from_secs(10))]
+fn scalar_inv(bencher: divan::Bencher) {
+    bencher
+        .counter(divan::counter::ItemsCount::new(NUM_SCALAR_OPS))
+        .with_inputs(|| random_nonzero_symbols(NUM_SCALAR_OPS))
+        .bench_refs(|symbols| {
+            symbols
+                .iter()
+                .filter_map(|&a| Gf256::new(divan::black_box(a)).inv())
+                .fold(Gf256::zero(), |acc, a_inv| acc + a_inv)
+        });
+}
+
+#[divan::bench(args = VECTOR_BYTE_LENS, max_time = Duration::from_secs(10))]
+fn inplace_mul_vec_by_scalar(bencher: divan::Bencher, &vec_byte_len: &usize) {
+    bencher
+        .counter(divan::counter::BytesCount::new(vec_byte_len))
+        .with_inputs(|| (random_nonzero_symbols(vec_byte_len), rand::rng().random_range(2..=u8::MAX)))
+        .bench_refs(|(vec, scalar)| gf256_inplace_mul_vec_by_scalar(divan::black_box(vec), divan::black_box(*scalar)));
+}
+
+#[divan::bench(args = VECTOR_BYTE_LENS, max_time = Duration::from_secs(10))]
+fn inplace_add_vectors(bencher: divan::Bencher, &vec_byte_len: &usize) {
+    bencher
+        .counter(divan::counter::BytesCount::new(2 * vec_byte_len))
+        .with_inputs(|| (random_nonzero_symbols(vec_byte_len), random_nonzero_symbols(vec_byte_len)))
+        .bench_refs(|(vec_dst, vec_src)| gf256_inplace_add_vectors(divan::black_box(vec_dst), divan::black_box(vec_src)));
+}
+
+#[divan::bench(args = VECTOR_BYTE_LENS, max_time = Duration::from_secs(10))]
+fn mul_vec_by_scalar_then_add_into_vec(bencher: divan::Bencher, &vec_byte_len: &usize) {
+    bencher
+        .counter(divan::counter::BytesCount::new(2 * vec_byte_len))
+        .with_inputs(|| {
+            (
+                random_nonzero_symbols(vec_byte_len),
+                random_nonzero_symbols(vec_byte_len),
+                rand::rng().random_range(2..=u8::MAX),
+            )
+        })
+        .bench_refs(|(add_into_vec, mul_vec, scalar)| {
+            gf256_mul_vec_by_scalar_then_add_into_vec(divan::black_box(add_into_vec), divan::black_box(mul_vec), divan::black_box(*scalar))
+        });
+}
@@ -1,8 +1,5 @@
 use rand::Rng;
-use rlnc::{
-    RLNCError,
-    full::{decoder::Decoder, encoder::Encoder, recoder::Recoder},
-};
+use rlnc::prelude::*;
 
 fn main() {
     let mut rng = rand::rng();
@@ -29,7 +26,7 @@ fn main() {
         encoder.get_piece_count(),
         encoder.get_piece_byte_len()
     );
-    let mut decoder = Decoder::new(encoder.get_piece_byte_len(), encoder.get_piece_count()).expect("Failed to create RLNC decoder");
+    let mut decoder = Decoder::matching(&encoder).expect("Failed to create RLNC decoder");
 
     // 4. Simulate a sender generating initial coded pieces
     let num_initial_coded_pieces_from_sender = encoder.get_piece_count() / 2; // Send half directly
@@ -38,7 +35,7 @@ fn main() {
 
     for i in 0..num_initial_coded_pieces_from_sender {
         let coded_piece = encoder.code(&mut rng);
-        pieces_for_recoder.extend_from_slice(&coded_piece); // Collect the same coded piece for recoder
+        pieces_for_recoder.extend_from_slice(coded_piece.as_bytes()); // Collect the same coded piece for recoder
 
         match decoder.decode(&coded_piece) {
             Ok(_) => println!("  Decoded direct piece {}: Useful.", i + 1),
@@ -82,7 +79,7 @@ fn main() {
     let mut pieces_for_new_recoder = Vec::new();
     for _ in 0..num_initial_coded_pieces_from_sender {
         let coded_piece = encoder.code(&mut rng);
-        pieces_for_new_recoder.extend_from_slice(&coded_piece); // Collect for new recoder
+        pieces_for_new_recoder.extend_from_slice(coded_piece.as_bytes()); // Collect for new recoder
     }
 
     println!(
@@ -0,0 +1,156 @@
+#ifndef RLNC_H
+#define RLNC_H
+
+// Warning, this file is autogenerated by cbindgen, using `make capi-header`. Don't modify this manually.
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Returned on success.
+#define RLNC_OK 0
+
+// See `RLNCError::CodingVectorLengthMismatch`.
+#define RLNC_ERROR_CODING_VECTOR_LENGTH_MISMATCH -1
+
+// See `RLNCError::DataLengthMismatch`.
+#define RLNC_ERROR_DATA_LENGTH_MISMATCH -2
+
+// See `RLNCError::PieceCountZero`.
+#define RLNC_ERROR_PIECE_COUNT_ZERO -3
+
+// See `RLNCError::DataLengthZero`.
+#define RLNC_ERROR_DATA_LENGTH_ZERO -4
+
+// See `RLNCError::PieceLengthZero`.
+#define RLNC_ERROR_PIECE_LENGTH_ZERO -5
+
+// See `RLNCError::DegreeOutOfRange`.
+#define RLNC_ERROR_DEGREE_OUT_OF_RANGE -6
+
+// See `RLNCError::InvalidDegreeDistribution`.
+#define RLNC_ERROR_INVALID_DEGREE_DISTRIBUTION -7
+
+// See `RLNCError::NotEnoughPiecesToRecode`.
+#define RLNC_ERROR_NOT_ENOUGH_PIECES_TO_RECODE -8
+
+// See `RLNCError::PieceLengthTooShort`.
+#define RLNC_ERROR_PIECE_LENGTH_TOO_SHORT -9
+
+// See `RLNCError::PieceNotUseful`.
+#define RLNC_ERROR_PIECE_NOT_USEFUL -10
+
+// See `RLNCError::ZeroCodingVector`.
+#define RLNC_ERROR_ZERO_CODING_VECTOR -11
+
+// See `RLNCError::ReceivedAllPieces`.
+#define RLNC_ERROR_RECEIVED_ALL_PIECES -12
+
+// See `RLNCError::NotAllPiecesReceivedYet`.
+#define RLNC_ERROR_NOT_ALL_PIECES_RECEIVED_YET -13
+
+// See `RLNCError::InvalidDecodedDataFormat`.
+#define RLNC_ERROR_INVALID_DECODED_DATA_FORMAT -14
+
+// See `RLNCError::InvalidPieceLength`.
+#define RLNC_ERROR_INVALID_PIECE_LENGTH -15
+
+// See `RLNCError::StaleGeneration`.
+#define RLNC_ERROR_STALE_GENERATION -16
+
+// See `RLNCError::ChecksumMismatch`.
+#define RLNC_ERROR_CHECKSUM_MISMATCH -17
+
+// See `RLNCError::DecodedDataWriteFailed`.
+#define RLNC_ERROR_DECODED_DATA_WRITE_FAILED -18
+
+// See `RLNCError::IncompatibleDecoders`.
+#define RLNC_ERROR_INCOMPATIBLE_DECODERS -19
+
+// See `RLNCError::InvalidFrameHeader`.
+#define RLNC_ERROR_INVALID_FRAME_HEADER -20
+
+// See `RLNCError::ParametersTooLarge`.
+#define RLNC_ERROR_PARAMETERS_TOO_LARGE -21
+
+// See `RLNCError::GenerationMismatch`.
+#define RLNC_ERROR_GENERATION_MISMATCH -22
+
+// See `RLNCError::TooManyPieces`.
+#define RLNC_ERROR_TOO_MANY_PIECES -23
+
+// See `RLNCError::PieceIndexOutOfRange`.
+#define RLNC_ERROR_PIECE_INDEX_OUT_OF_RANGE -24
+
+// See `RLNCError::TaggingMismatch`.
+#define RLNC_ERROR_TAGGING_MISMATCH -25
+
+// See `RLNCError::PieceAlreadyDrained`.
+#define RLNC_ERROR_PIECE_ALREADY_DRAINED -26
+
+// A required pointer argument is null.
+#define RLNC_ERROR_NULL_POINTER -100
+
+// See `RLNCError::OutputBufferTooSmall`.
+#define RLNC_ERROR_BUFFER_TOO_SMALL -101
+
+typedef struct RlncDecoder RlncDecoder;
+
+#ifdef __cplusplus
+extern "C" {
+#endif  // __cplusplus
+
+// Creates a new decoder, for `piece_count` pieces of `piece_byte_len` bytes each, same as `Decoder::new`.
+//
+// Returns an opaque pointer to the decoder, which must be released using `rlnc_decoder_free`, or a null pointer, if
+// either argument is zero.
+RlncDecoder *rlnc_decoder_new(uintptr_t piece_byte_len, uintptr_t piece_count);
+
+// Decodes a full coded piece, i.e. `piece_count` coefficients followed by `piece_byte_len` bytes of coded data, of
+// `piece_len` bytes, same as `Decoder::decode`.
+//
+// Returns `RLNC_OK` if the piece was useful, `RLNC_ERROR_PIECE_NOT_USEFUL` if it was linearly dependent on the
+// already received useful pieces, or any other negative error code.
+//
+// # Safety
+// `decoder` must be null or a pointer returned by `rlnc_decoder_new`, not yet released, and not accessed
+// concurrently. `piece` must be null or valid for reading `piece_len` bytes.
+int32_t rlnc_decoder_decode(RlncDecoder *decoder, const uint8_t *piece, uintptr_t piece_len);
+
+// Returns whether enough useful pieces are received for recovering the original data, same as
+// `Decoder::is_already_decoded`. Returns false for a null `decoder`.
+//
+// # Safety
+// `decoder` must be null or a pointer returned by `rlnc_decoder_new`, not yet released.
+bool rlnc_decoder_is_decoded(const RlncDecoder *decoder);
+
+// Returns the byte length of the original data, same as `Decoder::original_data_len`, so that the output buffer of
+// `rlnc_decoder_copy_data` can be sized exactly, or a negative error code, if decoding is not complete yet.
+//
+// # Safety
+// `decoder` must be null or a pointer returned by `rlnc_decoder_new`, not yet released.
+int64_t rlnc_decoder_data_len(const RlncDecoder *decoder);
+
+// Copies the original data, once decoding is complete, into `out`, which must have room for at least
+// `rlnc_decoder_data_len` bytes, same as `Decoder::write_decoded_data`.
+//
+// Returns the number of copied bytes, or a negative error code, in which case `out` is left untouched.
+//
+// # Safety
+// `decoder` must be null or a pointer returned by `rlnc_decoder_new`, not yet released. `out` must be null or valid
+// for writing `out_len` bytes.
+int64_t rlnc_decoder_copy_data(const RlncDecoder *decoder, uint8_t *out, uintptr_t out_len);
+
+// Releases a decoder, created using `rlnc_decoder_new`. Passing a null pointer is a no-op.
+//
+// # Safety
+// `decoder` must be null or a pointer returned by `rlnc_decoder_new`, not yet released. It must not be used after
+// this call.
+void rlnc_decoder_free(RlncDecoder *decoder);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* RLNC_H */
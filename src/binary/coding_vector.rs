@@ -0,0 +1,78 @@
+//! Bit-packed GF(2) coding vectors, held in `u64` words in memory, while being sent on the wire as bytes, as described
+//! by the `binary` module.
+
+/// Number of coefficients packed into one word of a coding vector.
+pub(crate) const COEFFICIENTS_PER_WORD: usize = u64::BITS as usize;
+
+/// Byte length of a bit-packed coding vector of `piece_count` coefficients, as sent on the wire.
+pub(crate) const fn coding_vector_byte_len(piece_count: usize) -> usize {
+    piece_count.div_ceil(u8::BITS as usize)
+}
+
+/// Number of words holding a bit-packed coding vector of `piece_count` coefficients, in memory.
+pub(crate) const fn coding_vector_word_len(piece_count: usize) -> usize {
+    piece_count.div_ceil(COEFFICIENTS_PER_WORD)
+}
+
+/// Mask of the bits of the last word of a coding vector of `piece_count` coefficients, which hold a coefficient.
+pub(crate) const fn last_word_mask(piece_count: usize) -> u64 {
+    match piece_count % COEFFICIENTS_PER_WORD {
+        0 => u64::MAX,
+        num_used_bits => (1u64 << num_used_bits) - 1,
+    }
+}
+
+/// Whether `col_idx` -th coefficient of a coding vector, held in `words`, is set.
+pub(crate) fn is_set(words: &[u64], col_idx: usize) -> bool {
+    (words[col_idx / COEFFICIENTS_PER_WORD] >> (col_idx % COEFFICIENTS_PER_WORD)) & 1 == 1
+}
+
+/// Returns an iterator over the indices of the set coefficients of a coding vector, held in `words`, in ascending order.
+pub(crate) fn set_coefficients(words: &[u64]) -> impl Iterator<Item = usize> + '_ {
+    words.iter().enumerate().flat_map(|(word_idx, &word)| {
+        let mut remaining = word;
+        core::iter::from_fn(move || {
+            if remaining == 0 {
+                return None;
+            }
+
+            let bit_idx = remaining.trailing_zeros() as usize;
+            remaining &= remaining - 1;
+
+            Some(word_idx * COEFFICIENTS_PER_WORD + bit_idx)
+        })
+    })
+}
+
+/// Number of coefficients a bit-packed coding vector, in its wire form `bytes`, spans, i.e. index of its highest set
+/// coefficient plus one, or zero if no coefficient is set.
+pub(crate) fn spanned_coefficient_count(bytes: &[u8]) -> usize {
+    bytes.iter().rposition(|&byte| byte != 0).map_or(0, |byte_idx| {
+        byte_idx * u8::BITS as usize + (u8::BITS - bytes[byte_idx].leading_zeros()) as usize
+    })
+}
+
+/// Unpacks a coding vector of `piece_count` coefficients, from its wire form, into `words`.
+///
+/// # Returns
+/// Returns `false` if any unused high bit of the last byte of `bytes` is set, in which case `words` are unspecified.
+pub(crate) fn unpack_into(bytes: &[u8], piece_count: usize, words: &mut [u64]) -> bool {
+    debug_assert_eq!(bytes.len(), coding_vector_byte_len(piece_count));
+    debug_assert_eq!(words.len(), coding_vector_word_len(piece_count));
+
+    words.iter_mut().zip(bytes.chunks(COEFFICIENTS_PER_WORD / 8)).for_each(|(word, word_bytes)| {
+        let mut le_bytes = [0u8; COEFFICIENTS_PER_WORD / 8];
+        le_bytes[..word_bytes.len()].copy_from_slice(word_bytes);
+
+        *word = u64::from_le_bytes(le_bytes);
+    });
+
+    words.last().is_none_or(|&last_word| last_word & !last_word_mask(piece_count) == 0)
+}
+
+/// Packs a coding vector, held in `words`, into its wire form, filling `bytes`.
+pub(crate) fn pack_into(words: &[u64], bytes: &mut [u8]) {
+    bytes.chunks_mut(COEFFICIENTS_PER_WORD / 8).zip(words).for_each(|(word_bytes, word)| {
+        word_bytes.copy_from_slice(&word.to_le_bytes()[..word_bytes.len()]);
+    });
+}
@@ -0,0 +1,290 @@
+use super::coding_vector::{coding_vector_byte_len, coding_vector_word_len, is_set, spanned_coefficient_count, unpack_into};
+use crate::{
+    RLNCError,
+    common::simd::gf256_inplace_add_vectors,
+    full::{encoder::checked_buffer_byte_len, padding::unpad},
+};
+use alloc::{vec, vec::Vec};
+
+/// Binary RLNC decoder, recovering the original data from pieces coded by `BinaryEncoder`, using Gaussian elimination over
+/// GF(2), where eliminating a pivot is nothing but XOR-ing rows together.
+///
+/// Received rows are kept in reduced row echelon form, same as `DecoderMatrix::add_row_and_reduce` does, but coefficients
+/// are bit-packed into `u64` words, 64 coefficients per word, so that a whole word of coefficients is eliminated using a
+/// single XOR, which is why `DecoderMatrix`, storing each coefficient in whole bytes, is not reused.
+#[derive(Clone, Debug)]
+pub struct BinaryDecoder {
+    /// Row-major buffer of bit-packed coding vectors, `coding_vector_word_len(required_piece_count)` words per row.
+    coefficients: Vec<u64>,
+    /// Row-major buffer of coded data, `piece_byte_len` bytes per row.
+    payloads: Vec<u8>,
+    /// Pivot column of each row.
+    pivot_cols: Vec<usize>,
+    piece_byte_len: usize,
+    required_piece_count: usize,
+    received_piece_count: usize,
+}
+
+impl BinaryDecoder {
+    /// Number of pieces original data got splitted into and coded together.
+    pub fn get_num_pieces_coded_together(&self) -> usize {
+        self.required_piece_count
+    }
+
+    /// After padding the original data, it gets splitted into `self.get_num_pieces_coded_together()` many pieces, which results into these many bytes per piece.
+    pub fn get_piece_byte_len(&self) -> usize {
+        self.piece_byte_len
+    }
+
+    /// Each full coded piece consists of the bit-packed coding vector, of `self.get_num_pieces_coded_together().div_ceil(8)`
+    /// bytes, appended by corresponding coded piece of `self.get_piece_byte_len()` bytes.
+    pub fn get_full_coded_piece_byte_len(&self) -> usize {
+        coding_vector_byte_len(self.required_piece_count) + self.piece_byte_len
+    }
+
+    /// Total number of pieces received by the decoder so far.
+    pub fn get_received_piece_count(&self) -> usize {
+        self.received_piece_count
+    }
+
+    /// Number of useful pieces received by the decoder so far.
+    pub fn get_useful_piece_count(&self) -> usize {
+        self.pivot_cols.len()
+    }
+
+    /// Creates a new `BinaryDecoder` instance, for decoding `required_piece_count` many pieces, of `piece_byte_len` bytes each,
+    /// coded together by `BinaryEncoder`.
+    ///
+    /// # Returns
+    /// Returns `Ok(BinaryDecoder)` on successful creation.
+    /// Returns `Err(RLNCError::PieceLengthZero)` if `piece_byte_len` is zero.
+    /// Returns `Err(RLNCError::PieceCountZero)` if `required_piece_count` is zero.
+    /// Returns `Err(RLNCError::ParametersTooLarge)` if the decoded data would be longer than `isize::MAX` bytes.
+    pub fn new(piece_byte_len: usize, required_piece_count: usize) -> Result<BinaryDecoder, RLNCError> {
+        if piece_byte_len == 0 {
+            return Err(RLNCError::PieceLengthZero);
+        }
+        if required_piece_count == 0 {
+            return Err(RLNCError::PieceCountZero);
+        }
+
+        checked_buffer_byte_len(required_piece_count, piece_byte_len)?;
+
+        Ok(BinaryDecoder {
+            coefficients: Vec::with_capacity(required_piece_count * coding_vector_word_len(required_piece_count)),
+            payloads: Vec::with_capacity(required_piece_count * piece_byte_len),
+            pivot_cols: Vec::with_capacity(required_piece_count),
+            piece_byte_len,
+            required_piece_count,
+            received_piece_count: 0,
+        })
+    }
+
+    /// Decodes a single full coded piece, as produced by `BinaryEncoder`, reducing it against the rows received so far.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the piece was useful and added successfully.
+    /// Returns `Err(RLNCError::ReceivedAllPieces)` if decoding is already complete.
+    /// Returns `Err(RLNCError::InvalidPieceLength)` if `full_coded_piece` is not `self.get_full_coded_piece_byte_len()` bytes long.
+    /// Returns `Err(RLNCError::CodingVectorLengthMismatch)` if any unused high bit of its coding vector is set.
+    /// Returns `Err(RLNCError::ZeroCodingVector)` if the coding vector is entirely zero. Such a piece is not counted as received.
+    /// Returns `Err(RLNCError::PieceNotUseful)` if the piece was linearly dependent on the already received useful pieces.
+    pub fn decode(&mut self, full_coded_piece: &[u8]) -> Result<(), RLNCError> {
+        if self.is_already_decoded() {
+            return Err(RLNCError::ReceivedAllPieces);
+        }
+        if full_coded_piece.len() != self.get_full_coded_piece_byte_len() {
+            return Err(RLNCError::InvalidPieceLength {
+                expected: self.get_full_coded_piece_byte_len(),
+                got: full_coded_piece.len(),
+            });
+        }
+
+        let word_len = coding_vector_word_len(self.required_piece_count);
+        let (coding_vector, coded_piece) = full_coded_piece.split_at(coding_vector_byte_len(self.required_piece_count));
+
+        let mut row_coefficients = vec![0u64; word_len];
+        if !unpack_into(coding_vector, self.required_piece_count, &mut row_coefficients) {
+            return Err(RLNCError::CodingVectorLengthMismatch {
+                expected: self.required_piece_count,
+                got: spanned_coefficient_count(coding_vector),
+            });
+        }
+        if row_coefficients.iter().all(|&word| word == 0) {
+            return Err(RLNCError::ZeroCodingVector);
+        }
+
+        self.received_piece_count += 1;
+        let mut row_payload = coded_piece.to_vec();
+
+        // Stored rows are zero at each other's pivot column, so that one pass over them fully reduces the new row.
+        for (row_idx, &pivot_col_idx) in self.pivot_cols.iter().enumerate() {
+            if !is_set(&row_coefficients, pivot_col_idx) {
+                continue;
+            }
+
+            let row_begins_at = row_idx * word_len;
+            row_coefficients
+                .iter_mut()
+                .zip(&self.coefficients[row_begins_at..(row_begins_at + word_len)])
+                .for_each(|(dst, src)| *dst ^= src);
+            gf256_inplace_add_vectors(&mut row_payload, self.payload(row_idx));
+        }
+
+        let Some(pivot_col_idx) = (0..self.required_piece_count).find(|&col_idx| is_set(&row_coefficients, col_idx)) else {
+            return Err(RLNCError::PieceNotUseful);
+        };
+
+        // Eliminate the new pivot column from the stored rows, keeping them in reduced row echelon form.
+        for row_idx in 0..self.pivot_cols.len() {
+            let row_begins_at = row_idx * word_len;
+            if !is_set(&self.coefficients[row_begins_at..(row_begins_at + word_len)], pivot_col_idx) {
+                continue;
+            }
+
+            self.coefficients[row_begins_at..(row_begins_at + word_len)]
+                .iter_mut()
+                .zip(&row_coefficients)
+                .for_each(|(dst, src)| *dst ^= src);
+
+            let payload_begins_at = row_idx * self.piece_byte_len;
+            gf256_inplace_add_vectors(&mut self.payloads[payload_begins_at..(payload_begins_at + self.piece_byte_len)], &row_payload);
+        }
+
+        self.coefficients.extend_from_slice(&row_coefficients);
+        self.payloads.extend_from_slice(&row_payload);
+        self.pivot_cols.push(pivot_col_idx);
+
+        Ok(())
+    }
+
+    /// Checks if the decoder has received enough linearly independent pieces to recover the original data.
+    pub fn is_already_decoded(&self) -> bool {
+        self.pivot_cols.len() == self.required_piece_count
+    }
+
+    /// Returns the decoded data, with the padding trimmed, once decoding is complete, consuming the decoder.
+    ///
+    /// # Returns
+    /// Returns `Ok(Vec<u8>)`, the original data, on success.
+    /// Returns `Err(RLNCError::NotAllPiecesReceivedYet)` if not enough useful pieces have been received.
+    /// Returns `Err(RLNCError::InvalidDecodedDataFormat)` if the decoded data does not end with the boundary marker,
+    /// followed by zero padding.
+    pub fn get_decoded_data(self) -> Result<Vec<u8>, RLNCError> {
+        if !self.is_already_decoded() {
+            return Err(RLNCError::NotAllPiecesReceivedYet);
+        }
+
+        // At full rank, each row is a unit vector, so that the row with pivot column `i` holds `i` -th source piece.
+        let mut row_idx_of_col = vec![0usize; self.required_piece_count];
+        self.pivot_cols
+            .iter()
+            .enumerate()
+            .for_each(|(row_idx, &pivot_col_idx)| row_idx_of_col[pivot_col_idx] = row_idx);
+
+        let mut decoded_data = Vec::with_capacity(self.required_piece_count * self.piece_byte_len);
+        row_idx_of_col.iter().for_each(|&row_idx| decoded_data.extend_from_slice(self.payload(row_idx)));
+
+        unpad(&mut decoded_data)?;
+        Ok(decoded_data)
+    }
+
+    /// Coded data of the row at `row_idx`.
+    fn payload(&self, row_idx: usize) -> &[u8] {
+        let payload_begins_at = row_idx * self.piece_byte_len;
+        &self.payloads[payload_begins_at..(payload_begins_at + self.piece_byte_len)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BinaryDecoder, RLNCError};
+    use crate::binary::encoder::BinaryEncoder;
+    use rand::Rng;
+
+    #[test]
+    fn test_binary_decoder_new_invalid_inputs() {
+        assert_eq!(BinaryDecoder::new(0, 16).map(|_| ()), Err(RLNCError::PieceLengthZero));
+        assert_eq!(BinaryDecoder::new(16, 0).map(|_| ()), Err(RLNCError::PieceCountZero));
+        assert_eq!(BinaryDecoder::new(usize::MAX, 2).map(|_| ()), Err(RLNCError::ParametersTooLarge));
+    }
+
+    #[test]
+    fn prop_test_binary_encoder_decoder() {
+        const NUM_TEST_ITERATIONS: usize = 100;
+
+        const MIN_DATA_BYTE_LEN: usize = 1;
+        const MAX_DATA_BYTE_LEN: usize = 1usize << 14;
+
+        const MIN_PIECE_COUNT: usize = 1;
+        const MAX_PIECE_COUNT: usize = 200;
+
+        let mut rng = rand::rng();
+
+        (0..NUM_TEST_ITERATIONS).for_each(|_| {
+            let data_byte_len = rng.random_range(MIN_DATA_BYTE_LEN..=MAX_DATA_BYTE_LEN);
+            let piece_count = rng.random_range(MIN_PIECE_COUNT..=MAX_PIECE_COUNT);
+
+            let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+            let encoder = BinaryEncoder::new(data.clone(), piece_count).expect("Failed to create BinaryEncoder");
+            let mut decoder = BinaryDecoder::new(encoder.get_piece_byte_len(), piece_count).expect("Failed to create BinaryDecoder");
+
+            assert_eq!(decoder.get_full_coded_piece_byte_len(), encoder.get_full_coded_piece_byte_len());
+
+            while !decoder.is_already_decoded() {
+                match decoder.decode(&encoder.code(&mut rng)) {
+                    Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                    Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+                }
+            }
+
+            // Over GF(2), a few more pieces than needed are expected to be received, though never many more.
+            assert_eq!(decoder.get_useful_piece_count(), piece_count);
+            assert!(decoder.get_received_piece_count() < piece_count + 32);
+
+            assert_eq!(decoder.decode(&encoder.code(&mut rng)), Err(RLNCError::ReceivedAllPieces));
+            assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+        });
+    }
+
+    #[test]
+    fn test_binary_decoder_decode_invalid_pieces() {
+        let mut rng = rand::rng();
+
+        let piece_count = 12usize;
+        let data = (0..1024).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = BinaryEncoder::new(data, piece_count).expect("Failed to create BinaryEncoder");
+        let mut decoder = BinaryDecoder::new(encoder.get_piece_byte_len(), piece_count).expect("Failed to create BinaryDecoder");
+
+        let mut full_coded_piece = encoder.code(&mut rng);
+        assert_eq!(
+            decoder.decode(&full_coded_piece[1..]),
+            Err(RLNCError::InvalidPieceLength {
+                expected: full_coded_piece.len(),
+                got: full_coded_piece.len() - 1
+            })
+        );
+
+        // Unused high bits of the coding vector are set
+        full_coded_piece[1] |= 0x80;
+        assert_eq!(
+            decoder.decode(&full_coded_piece),
+            Err(RLNCError::CodingVectorLengthMismatch {
+                expected: piece_count,
+                got: 16
+            })
+        );
+
+        full_coded_piece[..2].fill(0);
+        assert_eq!(decoder.decode(&full_coded_piece), Err(RLNCError::ZeroCodingVector));
+        assert_eq!(decoder.get_received_piece_count(), 0);
+
+        let full_coded_piece = encoder.code(&mut rng);
+        decoder.decode(&full_coded_piece).expect("First non-zero piece must be useful");
+        assert_eq!(decoder.decode(&full_coded_piece), Err(RLNCError::PieceNotUseful));
+        assert_eq!(decoder.get_received_piece_count(), 2);
+        assert_eq!(decoder.get_useful_piece_count(), 1);
+
+        assert_eq!(decoder.get_decoded_data(), Err(RLNCError::NotAllPiecesReceivedYet));
+    }
+}
@@ -0,0 +1,180 @@
+use super::coding_vector::{
+    coding_vector_byte_len, coding_vector_word_len, last_word_mask, pack_into, set_coefficients, spanned_coefficient_count, unpack_into,
+};
+use crate::{RLNCError, common::simd::gf256_inplace_add_vectors, full::padding::pad};
+use alloc::{vec, vec::Vec};
+use rand::Rng;
+
+/// Binary RLNC encoder, splitting data into pieces, same as `Encoder` does, but coding them together over GF(2), i.e.
+/// each coded piece is the XOR of a random subset of source pieces, prefixed by the bit-packed coding vector.
+#[derive(Clone, Debug)]
+pub struct BinaryEncoder {
+    data: Vec<u8>,
+    piece_count: usize,
+    piece_byte_len: usize,
+}
+
+impl BinaryEncoder {
+    /// Number of pieces original data got splitted into, to be coded together.
+    pub fn get_piece_count(&self) -> usize {
+        self.piece_count
+    }
+
+    /// After padding the original data, it gets splitted into `self.get_piece_count()` many pieces, which results into these many bytes per piece.
+    pub fn get_piece_byte_len(&self) -> usize {
+        self.piece_byte_len
+    }
+
+    /// Byte length of the bit-packed coding vector, prefixed to each coded piece, i.e. one bit per source piece.
+    pub fn get_coding_vector_byte_len(&self) -> usize {
+        coding_vector_byte_len(self.piece_count)
+    }
+
+    /// Each full coded piece consists of `self.get_coding_vector_byte_len()` bytes of bit-packed coding vector, appended by
+    /// corresponding coded piece of `self.get_piece_byte_len()` bytes.
+    pub fn get_full_coded_piece_byte_len(&self) -> usize {
+        self.get_coding_vector_byte_len() + self.piece_byte_len
+    }
+
+    /// Creates a new `BinaryEncoder`, padding the input data, same as `Encoder::new` does, so that it splits into
+    /// `piece_count` many equal sized pieces.
+    ///
+    /// # Returns
+    /// Returns `Ok(BinaryEncoder)` on success.
+    /// Returns `Err(RLNCError::DataLengthZero)` if `data` is empty.
+    /// Returns `Err(RLNCError::PieceCountZero)` if `piece_count` is zero.
+    /// Returns `Err(RLNCError::ParametersTooLarge)` if the padded data would be longer than `isize::MAX` bytes.
+    pub fn new(mut data: Vec<u8>, piece_count: usize) -> Result<BinaryEncoder, RLNCError> {
+        let piece_byte_len = pad(&mut data, piece_count)?;
+
+        Ok(BinaryEncoder {
+            data,
+            piece_count,
+            piece_byte_len,
+        })
+    }
+
+    /// Encodes the data held by the encoder, XOR-ing together the source pieces whose coefficient is set in the bit-packed
+    /// `coding_vector`.
+    ///
+    /// # Returns
+    /// Returns `Ok(Vec<u8>)`, the full coded piece, prefixed by `coding_vector`, on success.
+    /// Returns `Err(RLNCError::CodingVectorLengthMismatch)` carrying byte lengths, if `coding_vector` is not
+    /// `self.get_coding_vector_byte_len()` bytes long, or carrying coefficient counts, if any of its unused high bits is set.
+    pub fn code_with_coding_vector(&self, coding_vector: &[u8]) -> Result<Vec<u8>, RLNCError> {
+        if coding_vector.len() != self.get_coding_vector_byte_len() {
+            return Err(RLNCError::CodingVectorLengthMismatch {
+                expected: self.get_coding_vector_byte_len(),
+                got: coding_vector.len(),
+            });
+        }
+
+        let mut words = vec![0u64; coding_vector_word_len(self.piece_count)];
+        if !unpack_into(coding_vector, self.piece_count, &mut words) {
+            return Err(RLNCError::CodingVectorLengthMismatch {
+                expected: self.piece_count,
+                got: spanned_coefficient_count(coding_vector),
+            });
+        }
+
+        Ok(self.code_with_words(&words))
+    }
+
+    /// Encodes the data held by the encoder, using a randomly sampled coding vector, i.e. XOR-ing together a uniformly
+    /// random, non-empty, subset of source pieces. All-zero coding vectors are resampled, as they are useless to any decoder.
+    ///
+    /// Returns the full coded piece, prefixed by the bit-packed random coding vector.
+    pub fn code<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<u8> {
+        let mut words = vec![0u64; coding_vector_word_len(self.piece_count)];
+        let last_word_mask = last_word_mask(self.piece_count);
+
+        loop {
+            words.iter_mut().for_each(|word| *word = rng.random());
+            if let Some(last_word) = words.last_mut() {
+                *last_word &= last_word_mask;
+            }
+
+            if words.iter().any(|&word| word != 0) {
+                break;
+            }
+        }
+
+        self.code_with_words(&words)
+    }
+
+    /// Encodes the data held by the encoder, using the coding vector, held in `words`, with no unused bit set.
+    fn code_with_words(&self, words: &[u64]) -> Vec<u8> {
+        let mut full_coded_piece = vec![0u8; self.get_full_coded_piece_byte_len()];
+        let (coding_vector, coded_piece) = full_coded_piece.split_at_mut(self.get_coding_vector_byte_len());
+
+        pack_into(words, coding_vector);
+        set_coefficients(words).for_each(|piece_idx| {
+            let piece_begins_at = piece_idx * self.piece_byte_len;
+            gf256_inplace_add_vectors(coded_piece, &self.data[piece_begins_at..(piece_begins_at + self.piece_byte_len)]);
+        });
+
+        full_coded_piece
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BinaryEncoder, RLNCError};
+    use crate::full::padding::BOUNDARY_MARKER;
+    use rand::Rng;
+
+    #[test]
+    fn test_binary_encoder_code_with_coding_vector() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 12usize;
+
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = BinaryEncoder::new(data.clone(), piece_count).expect("Failed to create BinaryEncoder");
+
+        let piece_byte_len = encoder.get_piece_byte_len();
+        assert_eq!(encoder.get_coding_vector_byte_len(), 2);
+        assert_eq!(encoder.get_full_coded_piece_byte_len(), 2 + piece_byte_len);
+
+        // Test case 1: Invalid coding vectors
+        assert_eq!(
+            encoder.code_with_coding_vector(&[1]),
+            Err(RLNCError::CodingVectorLengthMismatch { expected: 2, got: 1 })
+        );
+        assert_eq!(
+            encoder.code_with_coding_vector(&[1, 0x10]),
+            Err(RLNCError::CodingVectorLengthMismatch {
+                expected: piece_count,
+                got: 13
+            })
+        );
+
+        // Test case 2: Coded piece is XOR of source pieces 0, 2 and 11
+        let full_coded_piece = encoder.code_with_coding_vector(&[0b0000_0101, 0b0000_1000]).expect("Coding must not fail");
+        let (coding_vector, coded_piece) = full_coded_piece.split_at(encoder.get_coding_vector_byte_len());
+        assert_eq!(coding_vector, [0b0000_0101, 0b0000_1000]);
+
+        let mut padded_data = data;
+        padded_data.resize(piece_count * piece_byte_len, 0);
+        padded_data[data_byte_len] = BOUNDARY_MARKER;
+
+        let expected_coded_piece = (0..piece_byte_len)
+            .map(|byte_idx| {
+                [0, 2, 11]
+                    .iter()
+                    .fold(0u8, |acc, &piece_idx| acc ^ padded_data[piece_idx * piece_byte_len + byte_idx])
+            })
+            .collect::<Vec<u8>>();
+        assert_eq!(coded_piece, expected_coded_piece);
+
+        // Test case 3: Random coding vectors are never all-zero, nor set unused bits
+        (0..1000).for_each(|_| {
+            let full_coded_piece = encoder.code(&mut rng);
+
+            assert_eq!(full_coded_piece.len(), encoder.get_full_coded_piece_byte_len());
+            assert!(full_coded_piece[..2].iter().any(|&byte| byte != 0));
+            assert_eq!(full_coded_piece[1] & 0xf0, 0);
+        });
+    }
+}
@@ -0,0 +1,19 @@
+//! Binary RLNC, where coding vectors and coded data are computed over GF(2), instead of GF(2^8), as done by the `full`
+//! module. Each coefficient of a coding vector is a single bit, so that a coding vector of `piece_count` coefficients is
+//! packed into `piece_count.div_ceil(8)` bytes, cutting header overhead of each coded piece 8 times, while coding and
+//! decoding are nothing but XOR-ing source pieces together.
+//!
+//! This comes at the cost of more coded pieces being needed for decoding. A random coding vector over GF(q) is linearly
+//! dependent on `r` already received ones with probability `q^(r - piece_count)`, which, for the last missing piece, is
+//! 1/2 over GF(2), versus 1/256 over GF(2^8). So, in expectation, ~1.6 extra coded pieces are received before decoding
+//! completes, irrespective of `piece_count`, versus ~0.004 over GF(2^8). It pays off when the coding vector makes up a
+//! considerable share of the full coded piece, i.e. many small pieces coded together.
+//!
+//! Each full coded piece is the bit-packed coding vector, followed by the coded data. `i` -th coefficient is bit `i % 8`
+//! of byte `i / 8` of the packed coding vector, while unused high bits of its last byte are zero. Data is padded same as
+//! `Encoder::new` does, using `padding::pad`.
+
+pub mod decoder;
+pub mod encoder;
+
+mod coding_vector;
@@ -0,0 +1,292 @@
+//! C ABI for embedding the RLNC `Decoder` in non-Rust applications, enabled by the `capi` feature.
+//!
+//! The decoder is kept behind an opaque pointer, created using `rlnc_decoder_new` and released using
+//! `rlnc_decoder_free`. Functions returning a status code return `RLNC_OK` on success, or one of the negative
+//! `RLNC_ERROR_*` codes, each corresponding to a variant of `RLNCError`, except for `RLNC_ERROR_NULL_POINTER`, which is
+//! specific to this interface. The C header, `include/rlnc.h`, is generated
+//! from this module, using `cbindgen`.
+//!
+//! Build the static or the dynamic library with `cargo rustc --release --features capi --crate-type staticlib`, or
+//! `--crate-type cdylib`, respectively.
+
+use crate::{RLNCError, full::decoder::Decoder};
+use alloc::boxed::Box;
+use core::{ptr, slice};
+
+/// Returned on success.
+pub const RLNC_OK: i32 = 0;
+/// See `RLNCError::CodingVectorLengthMismatch`.
+pub const RLNC_ERROR_CODING_VECTOR_LENGTH_MISMATCH: i32 = -1;
+/// See `RLNCError::DataLengthMismatch`.
+pub const RLNC_ERROR_DATA_LENGTH_MISMATCH: i32 = -2;
+/// See `RLNCError::PieceCountZero`.
+pub const RLNC_ERROR_PIECE_COUNT_ZERO: i32 = -3;
+/// See `RLNCError::DataLengthZero`.
+pub const RLNC_ERROR_DATA_LENGTH_ZERO: i32 = -4;
+/// See `RLNCError::PieceLengthZero`.
+pub const RLNC_ERROR_PIECE_LENGTH_ZERO: i32 = -5;
+/// See `RLNCError::DegreeOutOfRange`.
+pub const RLNC_ERROR_DEGREE_OUT_OF_RANGE: i32 = -6;
+/// See `RLNCError::InvalidDegreeDistribution`.
+pub const RLNC_ERROR_INVALID_DEGREE_DISTRIBUTION: i32 = -7;
+/// See `RLNCError::NotEnoughPiecesToRecode`.
+pub const RLNC_ERROR_NOT_ENOUGH_PIECES_TO_RECODE: i32 = -8;
+/// See `RLNCError::PieceLengthTooShort`.
+pub const RLNC_ERROR_PIECE_LENGTH_TOO_SHORT: i32 = -9;
+/// See `RLNCError::PieceNotUseful`.
+pub const RLNC_ERROR_PIECE_NOT_USEFUL: i32 = -10;
+/// See `RLNCError::ZeroCodingVector`.
+pub const RLNC_ERROR_ZERO_CODING_VECTOR: i32 = -11;
+/// See `RLNCError::ReceivedAllPieces`.
+pub const RLNC_ERROR_RECEIVED_ALL_PIECES: i32 = -12;
+/// See `RLNCError::NotAllPiecesReceivedYet`.
+pub const RLNC_ERROR_NOT_ALL_PIECES_RECEIVED_YET: i32 = -13;
+/// See `RLNCError::InvalidDecodedDataFormat`.
+pub const RLNC_ERROR_INVALID_DECODED_DATA_FORMAT: i32 = -14;
+/// See `RLNCError::InvalidPieceLength`.
+pub const RLNC_ERROR_INVALID_PIECE_LENGTH: i32 = -15;
+/// See `RLNCError::StaleGeneration`.
+pub const RLNC_ERROR_STALE_GENERATION: i32 = -16;
+/// See `RLNCError::ChecksumMismatch`.
+pub const RLNC_ERROR_CHECKSUM_MISMATCH: i32 = -17;
+/// See `RLNCError::DecodedDataWriteFailed`.
+pub const RLNC_ERROR_DECODED_DATA_WRITE_FAILED: i32 = -18;
+/// See `RLNCError::IncompatibleDecoders`.
+pub const RLNC_ERROR_INCOMPATIBLE_DECODERS: i32 = -19;
+/// See `RLNCError::InvalidFrameHeader`.
+pub const RLNC_ERROR_INVALID_FRAME_HEADER: i32 = -20;
+/// See `RLNCError::ParametersTooLarge`.
+pub const RLNC_ERROR_PARAMETERS_TOO_LARGE: i32 = -21;
+/// See `RLNCError::GenerationMismatch`.
+pub const RLNC_ERROR_GENERATION_MISMATCH: i32 = -22;
+/// See `RLNCError::TooManyPieces`.
+pub const RLNC_ERROR_TOO_MANY_PIECES: i32 = -23;
+/// See `RLNCError::PieceIndexOutOfRange`.
+pub const RLNC_ERROR_PIECE_INDEX_OUT_OF_RANGE: i32 = -24;
+/// See `RLNCError::TaggingMismatch`.
+pub const RLNC_ERROR_TAGGING_MISMATCH: i32 = -25;
+/// See `RLNCError::PieceAlreadyDrained`.
+pub const RLNC_ERROR_PIECE_ALREADY_DRAINED: i32 = -26;
+/// A required pointer argument is null.
+pub const RLNC_ERROR_NULL_POINTER: i32 = -100;
+/// See `RLNCError::OutputBufferTooSmall`.
+pub const RLNC_ERROR_BUFFER_TOO_SMALL: i32 = -101;
+
+/// Maps an `RLNCError` to its stable, negative, C ABI error code.
+fn error_code(err: RLNCError) -> i32 {
+    match err {
+        RLNCError::CodingVectorLengthMismatch { .. } => RLNC_ERROR_CODING_VECTOR_LENGTH_MISMATCH,
+        RLNCError::DataLengthMismatch => RLNC_ERROR_DATA_LENGTH_MISMATCH,
+        RLNCError::PieceCountZero => RLNC_ERROR_PIECE_COUNT_ZERO,
+        RLNCError::DataLengthZero => RLNC_ERROR_DATA_LENGTH_ZERO,
+        RLNCError::PieceLengthZero => RLNC_ERROR_PIECE_LENGTH_ZERO,
+        RLNCError::DegreeOutOfRange => RLNC_ERROR_DEGREE_OUT_OF_RANGE,
+        RLNCError::InvalidDegreeDistribution => RLNC_ERROR_INVALID_DEGREE_DISTRIBUTION,
+        RLNCError::NotEnoughPiecesToRecode => RLNC_ERROR_NOT_ENOUGH_PIECES_TO_RECODE,
+        RLNCError::PieceLengthTooShort => RLNC_ERROR_PIECE_LENGTH_TOO_SHORT,
+        RLNCError::PieceNotUseful => RLNC_ERROR_PIECE_NOT_USEFUL,
+        RLNCError::ZeroCodingVector => RLNC_ERROR_ZERO_CODING_VECTOR,
+        RLNCError::ReceivedAllPieces => RLNC_ERROR_RECEIVED_ALL_PIECES,
+        RLNCError::NotAllPiecesReceivedYet => RLNC_ERROR_NOT_ALL_PIECES_RECEIVED_YET,
+        RLNCError::InvalidDecodedDataFormat => RLNC_ERROR_INVALID_DECODED_DATA_FORMAT,
+        RLNCError::InvalidPieceLength { .. } => RLNC_ERROR_INVALID_PIECE_LENGTH,
+        RLNCError::StaleGeneration => RLNC_ERROR_STALE_GENERATION,
+        RLNCError::ChecksumMismatch => RLNC_ERROR_CHECKSUM_MISMATCH,
+        RLNCError::DecodedDataWriteFailed => RLNC_ERROR_DECODED_DATA_WRITE_FAILED,
+        RLNCError::IncompatibleDecoders => RLNC_ERROR_INCOMPATIBLE_DECODERS,
+        RLNCError::InvalidFrameHeader => RLNC_ERROR_INVALID_FRAME_HEADER,
+        RLNCError::ParametersTooLarge => RLNC_ERROR_PARAMETERS_TOO_LARGE,
+        RLNCError::GenerationMismatch => RLNC_ERROR_GENERATION_MISMATCH,
+        RLNCError::TooManyPieces => RLNC_ERROR_TOO_MANY_PIECES,
+        RLNCError::PieceIndexOutOfRange => RLNC_ERROR_PIECE_INDEX_OUT_OF_RANGE,
+        RLNCError::TaggingMismatch => RLNC_ERROR_TAGGING_MISMATCH,
+        RLNCError::PieceAlreadyDrained => RLNC_ERROR_PIECE_ALREADY_DRAINED,
+        RLNCError::OutputBufferTooSmall => RLNC_ERROR_BUFFER_TOO_SMALL,
+    }
+}
+
+/// Creates a new decoder, for `piece_count` pieces of `piece_byte_len` bytes each, same as `Decoder::new`.
+///
+/// Returns an opaque pointer to the decoder, which must be released using `rlnc_decoder_free`, or a null pointer, if
+/// either argument is zero.
+#[unsafe(no_mangle)]
+pub extern "C" fn rlnc_decoder_new(piece_byte_len: usize, piece_count: usize) -> *mut Decoder {
+    match Decoder::new(piece_byte_len, piece_count) {
+        Ok(decoder) => Box::into_raw(Box::new(decoder)),
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// Decodes a full coded piece, i.e. `piece_count` coefficients followed by `piece_byte_len` bytes of coded data, of
+/// `piece_len` bytes, same as `Decoder::decode`.
+///
+/// Returns `RLNC_OK` if the piece was useful, `RLNC_ERROR_PIECE_NOT_USEFUL` if it was linearly dependent on the
+/// already received useful pieces, or any other negative error code.
+///
+/// # Safety
+/// `decoder` must be null or a pointer returned by `rlnc_decoder_new`, not yet released, and not accessed
+/// concurrently. `piece` must be null or valid for reading `piece_len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rlnc_decoder_decode(decoder: *mut Decoder, piece: *const u8, piece_len: usize) -> i32 {
+    let Some(decoder) = (unsafe { decoder.as_mut() }) else {
+        return RLNC_ERROR_NULL_POINTER;
+    };
+    if piece.is_null() {
+        return RLNC_ERROR_NULL_POINTER;
+    }
+
+    let full_coded_piece = unsafe { slice::from_raw_parts(piece, piece_len) };
+    match decoder.decode_bytes(full_coded_piece) {
+        Ok(()) => RLNC_OK,
+        Err(err) => error_code(err),
+    }
+}
+
+/// Returns whether enough useful pieces are received for recovering the original data, same as
+/// `Decoder::is_already_decoded`. Returns false for a null `decoder`.
+///
+/// # Safety
+/// `decoder` must be null or a pointer returned by `rlnc_decoder_new`, not yet released.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rlnc_decoder_is_decoded(decoder: *const Decoder) -> bool {
+    unsafe { decoder.as_ref() }.is_some_and(Decoder::is_already_decoded)
+}
+
+/// Returns the byte length of the original data, same as `Decoder::original_data_len`, so that the output buffer of
+/// `rlnc_decoder_copy_data` can be sized exactly, or a negative error code, if decoding is not complete yet.
+///
+/// # Safety
+/// `decoder` must be null or a pointer returned by `rlnc_decoder_new`, not yet released.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rlnc_decoder_data_len(decoder: *const Decoder) -> i64 {
+    let Some(decoder) = (unsafe { decoder.as_ref() }) else {
+        return RLNC_ERROR_NULL_POINTER as i64;
+    };
+
+    match decoder.original_data_len() {
+        Some(data_len) => data_len as i64,
+        None if !decoder.is_already_decoded() => RLNC_ERROR_NOT_ALL_PIECES_RECEIVED_YET as i64,
+        None => RLNC_ERROR_INVALID_DECODED_DATA_FORMAT as i64,
+    }
+}
+
+/// Copies the original data, once decoding is complete, into `out`, which must have room for at least
+/// `rlnc_decoder_data_len` bytes, same as `Decoder::write_decoded_data`.
+///
+/// Returns the number of copied bytes, or a negative error code, in which case `out` is left untouched.
+///
+/// # Safety
+/// `decoder` must be null or a pointer returned by `rlnc_decoder_new`, not yet released. `out` must be null or valid
+/// for writing `out_len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rlnc_decoder_copy_data(decoder: *const Decoder, out: *mut u8, out_len: usize) -> i64 {
+    let data_len = unsafe { rlnc_decoder_data_len(decoder) };
+    if data_len < 0 {
+        return data_len;
+    }
+    if out.is_null() {
+        return RLNC_ERROR_NULL_POINTER as i64;
+    }
+    if out_len < data_len as usize {
+        return RLNC_ERROR_BUFFER_TOO_SMALL as i64;
+    }
+
+    let decoder = unsafe { &*decoder };
+    let mut out = unsafe { slice::from_raw_parts_mut(out, out_len) };
+
+    match decoder.write_decoded_data(&mut out) {
+        Ok(num_copied_bytes) => num_copied_bytes as i64,
+        Err(err) => error_code(err) as i64,
+    }
+}
+
+/// Releases a decoder, created using `rlnc_decoder_new`. Passing a null pointer is a no-op.
+///
+/// # Safety
+/// `decoder` must be null or a pointer returned by `rlnc_decoder_new`, not yet released. It must not be used after
+/// this call.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rlnc_decoder_free(decoder: *mut Decoder) {
+    if !decoder.is_null() {
+        drop(unsafe { Box::from_raw(decoder) });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        RLNC_ERROR_BUFFER_TOO_SMALL, RLNC_ERROR_INVALID_PIECE_LENGTH, RLNC_ERROR_NOT_ALL_PIECES_RECEIVED_YET, RLNC_ERROR_NULL_POINTER,
+        RLNC_ERROR_PIECE_NOT_USEFUL, RLNC_ERROR_RECEIVED_ALL_PIECES, RLNC_ERROR_ZERO_CODING_VECTOR, RLNC_OK, rlnc_decoder_copy_data, rlnc_decoder_data_len,
+        rlnc_decoder_decode, rlnc_decoder_free, rlnc_decoder_is_decoded, rlnc_decoder_new,
+    };
+    use crate::full::encoder::Encoder;
+    use core::ptr;
+    use rand::Rng;
+
+    #[test]
+    fn test_capi_decoder_smoke() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for C API test");
+
+        assert!(rlnc_decoder_new(0, piece_count).is_null());
+        assert!(rlnc_decoder_new(encoder.get_piece_byte_len(), 0).is_null());
+
+        let decoder = rlnc_decoder_new(encoder.get_piece_byte_len(), piece_count);
+        assert!(!decoder.is_null());
+
+        unsafe {
+            let coded_piece = encoder.code(&mut rng);
+            let piece = coded_piece.as_bytes();
+
+            assert_eq!(rlnc_decoder_decode(ptr::null_mut(), piece.as_ptr(), piece.len()), RLNC_ERROR_NULL_POINTER);
+            assert_eq!(rlnc_decoder_decode(decoder, ptr::null(), piece.len()), RLNC_ERROR_NULL_POINTER);
+            assert_eq!(rlnc_decoder_decode(decoder, piece.as_ptr(), piece.len() - 1), RLNC_ERROR_INVALID_PIECE_LENGTH);
+
+            let zero_piece = vec![0u8; piece.len()];
+            assert_eq!(
+                rlnc_decoder_decode(decoder, zero_piece.as_ptr(), zero_piece.len()),
+                RLNC_ERROR_ZERO_CODING_VECTOR
+            );
+
+            assert_eq!(rlnc_decoder_decode(decoder, piece.as_ptr(), piece.len()), RLNC_OK);
+            assert_eq!(rlnc_decoder_decode(decoder, piece.as_ptr(), piece.len()), RLNC_ERROR_PIECE_NOT_USEFUL);
+
+            let mut out = vec![0u8; data_byte_len];
+            assert_eq!(rlnc_decoder_data_len(decoder), RLNC_ERROR_NOT_ALL_PIECES_RECEIVED_YET as i64);
+            assert_eq!(
+                rlnc_decoder_copy_data(decoder, out.as_mut_ptr(), out.len()),
+                RLNC_ERROR_NOT_ALL_PIECES_RECEIVED_YET as i64
+            );
+
+            while !rlnc_decoder_is_decoded(decoder) {
+                let coded_piece = encoder.code(&mut rng);
+                let piece = coded_piece.as_bytes();
+
+                match rlnc_decoder_decode(decoder, piece.as_ptr(), piece.len()) {
+                    RLNC_OK | RLNC_ERROR_PIECE_NOT_USEFUL => {}
+                    code => panic!("Unexpected error code during decoding: {code}"),
+                }
+            }
+            assert!(!rlnc_decoder_is_decoded(ptr::null()));
+
+            let piece = encoder.code(&mut rng).into_bytes();
+            assert_eq!(rlnc_decoder_decode(decoder, piece.as_ptr(), piece.len()), RLNC_ERROR_RECEIVED_ALL_PIECES);
+
+            assert_eq!(rlnc_decoder_data_len(decoder), data_byte_len as i64);
+            assert_eq!(
+                rlnc_decoder_copy_data(decoder, out.as_mut_ptr(), out.len() - 1),
+                RLNC_ERROR_BUFFER_TOO_SMALL as i64
+            );
+            assert_eq!(rlnc_decoder_copy_data(decoder, ptr::null_mut(), out.len()), RLNC_ERROR_NULL_POINTER as i64);
+            assert_eq!(rlnc_decoder_copy_data(decoder, out.as_mut_ptr(), out.len()), data_byte_len as i64);
+            assert_eq!(out, data);
+
+            rlnc_decoder_free(decoder);
+            rlnc_decoder_free(ptr::null_mut());
+        }
+    }
+}
@@ -1,8 +1,15 @@
 /// Errors that can occur during RLNC (Random Linear Network Coding) encoding/ recoding/ decoding.
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RLNCError {
-    /// When the coding vector's length does not match the expected dimension during encoding.
-    CodingVectorLengthMismatch,
+    /// When the coding vector's length does not match the expected dimension during encoding, or when a received piece
+    /// was coded together with a number of pieces other than the expected one.
+    CodingVectorLengthMismatch {
+        /// Expected length of the coding vector.
+        expected: usize,
+        /// Actual length of the coding vector.
+        got: usize,
+    },
     /// When the data length does not match the expected block size during encoding.
     DataLengthMismatch,
     /// When the piece count is zero.
@@ -11,14 +18,30 @@ pub enum RLNCError {
     DataLengthZero,
     /// When the piece length is zero.
     PieceLengthZero,
+    /// When the number of source pieces to be combined into a sparse coded piece is zero or exceeds the piece count.
+    DegreeOutOfRange,
+    /// When a piece index is not less than the number of pieces, which can be indexed.
+    PieceIndexOutOfRange,
+    /// When parameters of a degree distribution are out of their valid range.
+    InvalidDegreeDistribution,
+    /// When the byte length of a buffer, needed for the given parameters, overflows `usize` or exceeds `isize::MAX`.
+    ParametersTooLarge,
 
     /// When there are not enough linearly independent pieces available to perform recoding.
     NotEnoughPiecesToRecode,
     /// When the full coded piece byte length is less than or equal to the number of pieces coded together.
     PieceLengthTooShort,
+    /// When a piece added to a tagged recoder carries a generation tag other than the recoder's.
+    GenerationMismatch,
+    /// When the input handed to a bounded recoder implies more received pieces than it is allowed to hold.
+    TooManyPieces,
 
     /// When a received piece does not provide new linearly independent information.
     PieceNotUseful,
+    /// When a received piece's coding vector is entirely zero, which no encoder or recoder produces, so the piece is
+    /// malformed, rather than merely linearly dependent. Also returned when an encoder is explicitly asked to refuse
+    /// coding with an all-zero coding vector.
+    ZeroCodingVector,
     /// When all necessary pieces have already been received, and no further pieces are needed to decode.
     ReceivedAllPieces,
     /// When an attempt is made to retrieve decoded data, but not all required pieces have arrived yet.
@@ -26,31 +49,69 @@ pub enum RLNCError {
     /// When the format or structure of the decoded data is not as expected.
     InvalidDecodedDataFormat,
     /// When the length of a received piece does not match the expected length.
-    InvalidPieceLength,
+    InvalidPieceLength {
+        /// Expected length of the piece.
+        expected: usize,
+        /// Actual length of the piece.
+        got: usize,
+    },
+    /// When a received piece's generation tag does not match the generation tag expected by the decoder.
+    StaleGeneration,
+    /// When a received piece carries a generation tag, while the decoder expects untagged pieces, or the other way around.
+    TaggingMismatch,
+    /// When a received piece doesn't match its checksum, i.e. it got corrupted in transit.
+    ChecksumMismatch,
+    /// When writing the decoded data into a writer fails.
+    DecodedDataWriteFailed,
+    /// When an output buffer is too small to hold the decoded data.
+    OutputBufferTooSmall,
+    /// When two decoders, being merged, are not set up for the same RLNC configuration.
+    IncompatibleDecoders,
+    /// When a frame header is corrupt, or the RLNC configuration doesn't fit in one.
+    InvalidFrameHeader,
+    /// When a received piece has a non-zero coefficient for a source piece, which was already drained from the decoder,
+    /// so that it can't be eliminated anymore, or when the original data is asked for as a whole, after some of its
+    /// pieces were drained.
+    PieceAlreadyDrained,
 }
 
-impl std::fmt::Display for RLNCError {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for RLNCError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         match self {
             // Encoder
-            RLNCError::CodingVectorLengthMismatch => write!(f, "Coding vector length mismatch"),
+            RLNCError::CodingVectorLengthMismatch { expected, got } => write!(f, "Coding vector length mismatch: expected {expected}, got {got}"),
             RLNCError::DataLengthMismatch => write!(f, "Data length mismatch"),
             RLNCError::PieceCountZero => write!(f, "Piece count is zero"),
             RLNCError::DataLengthZero => write!(f, "Data length is zero"),
             RLNCError::PieceLengthZero => write!(f, "Piece length is zero"),
+            RLNCError::DegreeOutOfRange => write!(f, "Degree is out of range"),
+            RLNCError::PieceIndexOutOfRange => write!(f, "Piece index is out of range"),
+            RLNCError::InvalidDegreeDistribution => write!(f, "Invalid degree distribution parameters"),
+            RLNCError::ParametersTooLarge => write!(f, "Parameters are too large"),
 
             // Recoder
             RLNCError::NotEnoughPiecesToRecode => write!(f, "Not enough pieces received to recode"),
             RLNCError::PieceLengthTooShort => write!(f, "Piece length is too short"),
+            RLNCError::GenerationMismatch => write!(f, "Piece generation doesn't match the recoder's"),
+            RLNCError::TooManyPieces => write!(f, "Too many pieces to recode"),
 
             // Decoder
             RLNCError::PieceNotUseful => write!(f, "Received piece is not useful"),
+            RLNCError::ZeroCodingVector => write!(f, "Received piece has all-zero coding vector"),
             RLNCError::ReceivedAllPieces => write!(f, "Received all pieces"),
             RLNCError::NotAllPiecesReceivedYet => write!(f, "Not all pieces are received yet"),
             RLNCError::InvalidDecodedDataFormat => write!(f, "Invalid decoded data format"),
-            RLNCError::InvalidPieceLength => write!(f, "Invalid piece length"),
+            RLNCError::InvalidPieceLength { expected, got } => write!(f, "Invalid piece length: expected {expected}, got {got}"),
+            RLNCError::StaleGeneration => write!(f, "Piece belongs to another generation"),
+            RLNCError::TaggingMismatch => write!(f, "Piece tagging doesn't match the decoder's"),
+            RLNCError::ChecksumMismatch => write!(f, "Piece checksum mismatch"),
+            RLNCError::DecodedDataWriteFailed => write!(f, "Failed to write decoded data"),
+            RLNCError::OutputBufferTooSmall => write!(f, "Output buffer is too small to hold decoded data"),
+            RLNCError::IncompatibleDecoders => write!(f, "Decoders are not set up for the same configuration"),
+            RLNCError::InvalidFrameHeader => write!(f, "Invalid frame header"),
+            RLNCError::PieceAlreadyDrained => write!(f, "Source piece was already drained from the decoder"),
         }
     }
 }
 
-impl std::error::Error for RLNCError {}
+impl core::error::Error for RLNCError {}
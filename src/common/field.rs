@@ -0,0 +1,62 @@
+use core::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Mul, Sub},
+};
+use rand::Rng;
+
+/// Finite field of characteristic 2, over which RLNC coding vectors and coded data are computed.
+///
+/// Elements are stored in byte buffers, each taking `Self::BYTE_LEN` little-endian bytes, so that a vector over
+/// the field is simply a byte slice, whose length is a multiple of `Self::BYTE_LEN`. Vector arithmetic functions
+/// work on such byte slices, which lets an implementation provide SIMD accelerated versions of them, as `Gf256` does.
+pub trait Field: Copy + Default + Debug + PartialEq + Send + Sync + Add<Output = Self> + AddAssign + Sub<Output = Self> + Mul<Output = Self> {
+    /// Number of bytes used for representing one field element.
+    const BYTE_LEN: usize;
+
+    /// Returns the additive identity element (0).
+    fn zero() -> Self;
+
+    /// Returns the multiplicative identity element (1).
+    fn one() -> Self;
+
+    /// Computes the multiplicative inverse of the element. Returns `None` for the zero element.
+    fn inv(self) -> Option<Self>;
+
+    /// Samples a field element, uniformly at random.
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self;
+
+    /// Reads a field element from the first `Self::BYTE_LEN` bytes of `bytes`, in little-endian order.
+    ///
+    /// # Panics
+    /// Panics if `bytes` is shorter than `Self::BYTE_LEN`.
+    fn from_le_bytes(bytes: &[u8]) -> Self;
+
+    /// Writes the field element into the first `Self::BYTE_LEN` bytes of `bytes`, in little-endian order.
+    ///
+    /// # Panics
+    /// Panics if `bytes` is shorter than `Self::BYTE_LEN`.
+    fn write_le_bytes(self, bytes: &mut [u8]);
+
+    /// Multiplies each element of `vec` by `scalar`, in-place.
+    fn inplace_mul_vec_by_scalar(vec: &mut [u8], scalar: Self) {
+        vec.chunks_exact_mut(Self::BYTE_LEN).for_each(|symbol| {
+            (Self::from_le_bytes(symbol) * scalar).write_le_bytes(symbol);
+        });
+    }
+
+    /// Adds `vec_src` into `vec_dst`, element-wise, in-place. Both vectors must be of same length.
+    fn inplace_add_vectors(vec_dst: &mut [u8], vec_src: &[u8]) {
+        vec_dst.iter_mut().zip(vec_src).for_each(|(a, b)| *a ^= b);
+    }
+
+    /// Multiplies each element of `mul_vec` by `scalar`, adding the product into `add_into_vec`, element-wise, in-place.
+    /// Both vectors must be of same length.
+    fn mul_vec_by_scalar_then_add_into_vec(add_into_vec: &mut [u8], mul_vec: &[u8], scalar: Self) {
+        add_into_vec
+            .chunks_exact_mut(Self::BYTE_LEN)
+            .zip(mul_vec.chunks_exact(Self::BYTE_LEN))
+            .for_each(|(dst_symbol, src_symbol)| {
+                (Self::from_le_bytes(dst_symbol) + Self::from_le_bytes(src_symbol) * scalar).write_le_bytes(dst_symbol);
+            });
+    }
+}
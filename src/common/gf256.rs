@@ -1,18 +1,47 @@
 //! Following GF(2**8) logarithm and exponentiation tables are generated using
 //! Python script @ https://gist.github.com/itzmeanjan/0b2ec3f378de2c2e911bd4bb5505d45a.
-
+//! They correspond to `IRREDUCIBLE_POLYNOMIAL`, with 2 as the primitive element.
+
+use super::{
+    field::Field,
+    simd::{gf256_inplace_add_vectors, gf256_inplace_mul_vec_by_scalar, gf256_mul_vec_by_scalar_then_add_into_vec},
+};
+use alloc::vec::Vec;
+use core::{
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, Mul, Neg, Sub},
+};
 use rand::Rng;
 use rand::distr::{Distribution, StandardUniform};
-use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub};
 
 pub const GF256_ORDER: usize = u8::MAX as usize + 1;
 
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+/// Irreducible polynomial x^8 + x^4 + x^3 + x^2 + 1, which GF(2^8) arithmetic of this crate reduces by, with bit i being
+/// the coefficient of x^i. Other GF(2^8) implementations, e.g. Reed-Solomon libraries, are compatible with this crate,
+/// i.e. agree on every product of two field elements, only if they use the same polynomial. Note, it is not the AES
+/// polynomial x^8 + x^4 + x^3 + x + 1, i.e. 0x11b.
+pub const IRREDUCIBLE_POLYNOMIAL: u16 = 0x11d;
+
+/// Number of elements in GF(2^8).
+pub const FIELD_ORDER: usize = GF256_ORDER;
+
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    all(target_arch = "wasm32", target_feature = "simd128")
+))]
 pub const GF256_BIT_WIDTH: usize = u8::BITS as usize;
 
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    all(target_arch = "wasm32", target_feature = "simd128")
+))]
 pub const GF256_HALF_ORDER: usize = 1usize << (GF256_BIT_WIDTH / 2);
 
+#[cfg(not(feature = "compact"))]
 const GF256_LOG_TABLE: [u8; GF256_ORDER] = [
     0, 0, 1, 25, 2, 50, 26, 198, 3, 223, 51, 238, 27, 104, 199, 75, 4, 100, 224, 14, 52, 141, 239, 129, 28, 193, 105, 248, 200, 8, 76, 113, 5, 138, 101, 47,
     225, 36, 15, 33, 53, 147, 142, 218, 240, 18, 130, 69, 29, 181, 194, 125, 106, 39, 249, 185, 201, 154, 9, 120, 77, 228, 114, 166, 6, 191, 139, 98, 102, 221,
@@ -24,6 +53,7 @@ const GF256_LOG_TABLE: [u8; GF256_ORDER] = [
     117, 44, 215, 79, 174, 213, 233, 230, 231, 173, 232, 116, 214, 244, 234, 168, 80, 88, 175,
 ];
 
+#[cfg(not(feature = "compact"))]
 const GF256_EXP_TABLE: [u8; 2 * GF256_ORDER - 2] = [
     1, 2, 4, 8, 16, 32, 64, 128, 29, 58, 116, 232, 205, 135, 19, 38, 76, 152, 45, 90, 180, 117, 234, 201, 143, 3, 6, 12, 24, 48, 96, 192, 157, 39, 78, 156, 37,
     74, 148, 53, 106, 212, 181, 119, 238, 193, 159, 35, 70, 140, 5, 10, 20, 40, 80, 160, 93, 186, 105, 210, 185, 111, 222, 161, 95, 190, 97, 194, 153, 47, 94,
@@ -43,7 +73,112 @@ const GF256_EXP_TABLE: [u8; 2 * GF256_ORDER - 2] = [
     108, 216, 173, 71, 142,
 ];
 
+// `Gf256::mul_const` indexes the exponentiation table by the sum of two logarithms, each in `0..=254`, so that the table must
+// hold at least 509 entries. Regenerating the tables with a smaller size fails compilation, instead of panicking at runtime.
+#[cfg(not(feature = "compact"))]
+const _: () = assert!(GF256_EXP_TABLE.len() >= 2 * (GF256_ORDER - 1) - 1);
+
+/// Multiplicative arithmetic over non-zero GF(2^8) elements, using logarithm and exponentiation tables, so that each
+/// operation is a few table lookups.
+#[cfg(not(feature = "compact"))]
+mod arith {
+    use super::{GF256_EXP_TABLE, GF256_LOG_TABLE, GF256_ORDER};
+
+    pub const fn mul(a: u8, b: u8) -> u8 {
+        GF256_EXP_TABLE[GF256_LOG_TABLE[a as usize] as usize + GF256_LOG_TABLE[b as usize] as usize]
+    }
+
+    pub const fn inv(a: u8) -> u8 {
+        GF256_EXP_TABLE[(GF256_ORDER - 1) - GF256_LOG_TABLE[a as usize] as usize]
+    }
+
+    pub const fn exp(i: u8) -> u8 {
+        GF256_EXP_TABLE[i as usize]
+    }
+
+    pub const fn log(a: u8) -> u8 {
+        GF256_LOG_TABLE[a as usize]
+    }
+
+    /// Expects `exp < GF256_ORDER - 1`.
+    pub const fn pow(a: u8, exp: u32) -> u8 {
+        GF256_EXP_TABLE[((GF256_LOG_TABLE[a as usize] as u32 * exp) % (GF256_ORDER - 1) as u32) as usize]
+    }
+}
+
+/// Multiplicative arithmetic over non-zero GF(2^8) elements, without any table, for the `compact` feature. Multiplication
+/// is bit-serial, i.e. 8 rounds of shift, conditional XOR and reduction, while inversion, exponentiation and logarithm
+/// are built on top of it, so that they cost up to a few hundred multiplications. Both tables, of 766 bytes in total,
+/// are dropped, at the cost of multiplication being roughly an order of magnitude slower than the table-based one.
+#[cfg(feature = "compact")]
+mod arith {
+    use super::{GF256_ORDER, Gf256, IRREDUCIBLE_POLYNOMIAL};
+
+    /// `IRREDUCIBLE_POLYNOMIAL`, with the implicit x^8 term dropped.
+    const REDUCTION_POLYNOMIAL: u8 = IRREDUCIBLE_POLYNOMIAL as u8;
+
+    pub const fn mul(mut a: u8, mut b: u8) -> u8 {
+        let mut res = 0u8;
+        while b != 0 {
+            if b & 1 == 1 {
+                res ^= a;
+            }
+
+            let carry = a & 0x80;
+            a <<= 1;
+            if carry != 0 {
+                a ^= REDUCTION_POLYNOMIAL;
+            }
+
+            b >>= 1;
+        }
+
+        res
+    }
+
+    /// Computes `a^-1 = a^(2^8 - 2)`, following Fermat's little theorem.
+    pub const fn inv(a: u8) -> u8 {
+        pow(a, (GF256_ORDER - 2) as u32)
+    }
+
+    pub const fn exp(i: u8) -> u8 {
+        pow(Gf256::primitive_element().get(), i as u32)
+    }
+
+    /// Walks through the powers of the primitive element, until it meets `a`, which must be non-zero.
+    pub const fn log(a: u8) -> u8 {
+        let mut power = 1u8;
+        let mut i = 0u8;
+
+        while power != a {
+            power = mul(power, Gf256::primitive_element().get());
+            i += 1;
+        }
+
+        i
+    }
+
+    /// Square-and-multiply exponentiation.
+    pub const fn pow(mut a: u8, mut exp: u32) -> u8 {
+        let mut res = 1u8;
+        while exp != 0 {
+            if exp & 1 == 1 {
+                res = mul(res, a);
+            }
+
+            a = mul(a, a);
+            exp >>= 1;
+        }
+
+        res
+    }
+}
+
 /// Gf(2^8) wrapper type.
+///
+/// For arithmetic over whole vectors of GF(2^8) elements, stored as byte slices, use SIMD accelerated functions in
+/// `crate::common::simd`, e.g. `gf256_inplace_mul_vec_by_scalar` for scaling a vector by a raw byte, instead of
+/// operating element by element.
 #[derive(Default, Clone, Copy, Debug)]
 pub struct Gf256 {
     val: u8,
@@ -75,16 +210,14 @@ impl Gf256 {
         Gf256::new(2)
     }
 
-    /// Compile-time executable multiplication of two bytes, over GF(2^8).
+    /// Compile-time executable multiplication of two bytes, over GF(2^8). It uses logarithm and exponentiation tables,
+    /// unless the `compact` feature is enabled, in which case it is bit-serial.
     pub const fn mul_const(a: u8, b: u8) -> u8 {
         if a == 0 || b == 0 {
             return 0;
         }
 
-        let l = GF256_LOG_TABLE[a as usize] as usize;
-        let r = GF256_LOG_TABLE[b as usize] as usize;
-
-        GF256_EXP_TABLE[l + r]
+        arith::mul(a, b)
     }
 
     /// Computes the multiplicative inverse of the element. Returns `None` for the zero element.
@@ -93,9 +226,66 @@ impl Gf256 {
             return None;
         }
 
-        Some(Gf256 {
-            val: GF256_EXP_TABLE[(GF256_ORDER - 1) - GF256_LOG_TABLE[self.val as usize] as usize],
-        })
+        Some(Gf256 { val: arith::inv(self.val) })
+    }
+
+    /// Returns `i` -th power of the primitive element, i.e. `Self::primitive_element().pow(i)`, using the exponentiation
+    /// table, unless the `compact` feature is enabled. Powers of the primitive element cycle with period `GF256_ORDER - 1`, so that `exp(255) == exp(0) == 1`.
+    pub const fn exp(i: u8) -> Self {
+        Gf256::new(arith::exp(i))
+    }
+
+    /// Returns the discrete logarithm of the element, to the base of the primitive element, i.e. `i < 255` such that
+    /// `Self::exp(i) == self`, using the logarithm table. Returns `None` for the zero element, which is no power of the
+    /// primitive element. With the `compact` feature, it walks through up to 254 powers of the primitive element instead.
+    pub const fn log(self) -> Option<u8> {
+        if self.val == 0 {
+            return None;
+        }
+
+        Some(arith::log(self.val))
+    }
+
+    /// Raises the element to the power of `exp`, in O(1) time, irrespective of `exp`, by multiplying its discrete logarithm
+    /// with `exp`, modulo the order of the multiplicative group. With the `compact` feature, it takes up to 16 bit-serial
+    /// multiplications instead, using square-and-multiply. The zero element has no logarithm, so that zero raised
+    /// to any positive power is zero, while anything raised to the power zero, including zero, is one.
+    pub const fn pow(self, exp: u32) -> Self {
+        if exp == 0 {
+            return Gf256::one();
+        }
+        if self.val == 0 {
+            return Gf256::zero();
+        }
+
+        let multiplicative_group_order = (GF256_ORDER - 1) as u32;
+        Gf256::new(arith::pow(self.val, exp % multiplicative_group_order))
+    }
+
+    /// Replaces each element of `elems` by its multiplicative inverse, using Montgomery's batch inversion trick, so that
+    /// only a single inversion is performed, along with 3 multiplications per element. Zero elements, which have no
+    /// inverse, are skipped, staying zero.
+    pub fn batch_inv(elems: &mut [Gf256]) {
+        let mut prefix_products = Vec::with_capacity(elems.len());
+
+        // i-th prefix product is the product of all non-zero elements before the i-th one.
+        let product = elems.iter().fold(Gf256::one(), |acc, &elem| {
+            prefix_products.push(acc);
+            if elem == Gf256::zero() { acc } else { acc * elem }
+        });
+
+        // Product of non-zero elements is non-zero, hence invertible.
+        let mut inv_suffix_product = unsafe { product.inv().unwrap_unchecked() };
+
+        elems.iter_mut().zip(prefix_products).rev().for_each(|(elem, prefix_product)| {
+            if *elem == Gf256::zero() {
+                return;
+            }
+
+            let elem_inv = inv_suffix_product * prefix_product;
+            inv_suffix_product = inv_suffix_product * *elem;
+            *elem = elem_inv;
+        });
     }
 }
 
@@ -139,7 +329,7 @@ impl Sub for Gf256 {
 impl Mul for Gf256 {
     type Output = Self;
 
-    /// Performs multiplication of two Gf256 elements using logarithm and exponentiation tables.
+    /// Performs multiplication of two Gf256 elements, same as `Gf256::mul_const`.
     fn mul(self, rhs: Self) -> Self::Output {
         Gf256 {
             val: Self::mul_const(self.val, rhs.val),
@@ -157,6 +347,38 @@ impl Div for Gf256 {
     }
 }
 
+impl Mul<u8> for Gf256 {
+    type Output = Self;
+
+    /// Multiplies the Gf256 element by a raw byte, interpreted as a Gf256 element.
+    fn mul(self, rhs: u8) -> Self::Output {
+        self * Gf256::new(rhs)
+    }
+}
+
+impl Div<u8> for Gf256 {
+    type Output = Option<Self>;
+
+    /// Divides the Gf256 element by a raw byte, interpreted as a Gf256 element. Returns `None` if dividing by zero.
+    fn div(self, rhs: u8) -> Self::Output {
+        self / Gf256::new(rhs)
+    }
+}
+
+impl Sum for Gf256 {
+    /// Adds up all elements of the iterator, returning zero for an empty iterator.
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Gf256::zero(), |acc, elem| acc + elem)
+    }
+}
+
+impl Product for Gf256 {
+    /// Multiplies all elements of the iterator, returning one for an empty iterator.
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Gf256::one(), |acc, elem| acc * elem)
+    }
+}
+
 impl PartialEq for Gf256 {
     /// Checks for equality between two Gf256 elements.
     fn eq(&self, other: &Self) -> bool {
@@ -171,11 +393,174 @@ impl Distribution<Gf256> for StandardUniform {
     }
 }
 
+impl Field for Gf256 {
+    const BYTE_LEN: usize = 1;
+
+    fn zero() -> Self {
+        Gf256::zero()
+    }
+
+    fn one() -> Self {
+        Gf256::one()
+    }
+
+    fn inv(self) -> Option<Self> {
+        Gf256::inv(self)
+    }
+
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.random()
+    }
+
+    fn from_le_bytes(bytes: &[u8]) -> Self {
+        Gf256::new(bytes[0])
+    }
+
+    fn write_le_bytes(self, bytes: &mut [u8]) {
+        bytes[0] = self.val;
+    }
+
+    /// Dispatches to the SIMD accelerated `gf256_inplace_mul_vec_by_scalar`.
+    fn inplace_mul_vec_by_scalar(vec: &mut [u8], scalar: Self) {
+        gf256_inplace_mul_vec_by_scalar(vec, scalar.val);
+    }
+
+    /// Dispatches to the SIMD accelerated `gf256_inplace_add_vectors`.
+    fn inplace_add_vectors(vec_dst: &mut [u8], vec_src: &[u8]) {
+        gf256_inplace_add_vectors(vec_dst, vec_src);
+    }
+
+    /// Dispatches to the SIMD accelerated `gf256_mul_vec_by_scalar_then_add_into_vec`.
+    fn mul_vec_by_scalar_then_add_into_vec(add_into_vec: &mut [u8], mul_vec: &[u8], scalar: Self) {
+        gf256_mul_vec_by_scalar_then_add_into_vec(add_into_vec, mul_vec, scalar.val);
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::Gf256;
+    use super::{FIELD_ORDER, GF256_ORDER, Gf256, IRREDUCIBLE_POLYNOMIAL};
     use rand::Rng;
 
+    /// Bit-serial, table-free multiplication over GF(2^8), reducing by irreducible polynomial x^8 + x^4 + x^3 + x^2 + 1.
+    /// Serves as an implementation independent of logarithm and exponentiation tables, for cross-checking them.
+    fn bitwise_mul(mut a: u8, mut b: u8) -> u8 {
+        const REDUCTION_POLYNOMIAL: u8 = 0x1d; // x^8 is implicit
+
+        let mut res = 0u8;
+        while b != 0 {
+            if b & 1 == 1 {
+                res ^= a;
+            }
+
+            let carry = a & 0x80;
+            a <<= 1;
+            if carry != 0 {
+                a ^= REDUCTION_POLYNOMIAL;
+            }
+
+            b >>= 1;
+        }
+
+        res
+    }
+
+    /// Computes multiplicative inverse using Fermat's little theorem i.e. a^-1 = a^(2^8 - 2), with bitwise multiplication.
+    fn bitwise_inv(a: u8) -> Option<u8> {
+        if a == 0 {
+            return None;
+        }
+
+        Some((0..(GF256_ORDER - 2)).fold(1u8, |acc, _| bitwise_mul(acc, a)))
+    }
+
+    #[test]
+    fn test_gf256_irreducible_polynomial() {
+        assert_eq!(IRREDUCIBLE_POLYNOMIAL, 0x11d);
+        assert_eq!(FIELD_ORDER, 256);
+
+        // x^8 reduces to the polynomial, with its x^8 term dropped, which pins the tables to the polynomial.
+        assert_eq!(Gf256::exp(8).get(), (IRREDUCIBLE_POLYNOMIAL & 0xff) as u8);
+        assert_eq!(Gf256::new(0x80) * Gf256::new(2), Gf256::new((IRREDUCIBLE_POLYNOMIAL ^ 0x100) as u8));
+    }
+
+    #[test]
+    fn test_gf256_table_based_arithmetic_matches_bitwise_arithmetic() {
+        (0..GF256_ORDER).for_each(|a| {
+            (0..GF256_ORDER).for_each(|b| {
+                let (a, b) = (a as u8, b as u8);
+                assert_eq!((Gf256::new(a) * Gf256::new(b)).get(), bitwise_mul(a, b), "{a} * {b}");
+            });
+
+            let a = a as u8;
+            assert_eq!(Gf256::new(a).inv().map(|inv| inv.get()), bitwise_inv(a), "{a}^-1");
+        });
+    }
+
+    #[test]
+    fn test_gf256_mul_const_matches_bitwise_mul() {
+        // Largest logarithm is 254, for `Gf256::exp(254)`, so that its square indexes the exponentiation table at 508.
+        const LARGEST_LOG_ELEMENT: u8 = Gf256::exp(u8::MAX - 1).get();
+        const LARGEST_LOG_ELEMENT_SQUARED: u8 = Gf256::mul_const(LARGEST_LOG_ELEMENT, LARGEST_LOG_ELEMENT);
+        assert_eq!(LARGEST_LOG_ELEMENT_SQUARED, bitwise_mul(LARGEST_LOG_ELEMENT, LARGEST_LOG_ELEMENT));
+
+        (0..GF256_ORDER).for_each(|a| {
+            (0..GF256_ORDER).for_each(|b| {
+                let (a, b) = (a as u8, b as u8);
+                assert_eq!(Gf256::mul_const(a, b), bitwise_mul(a, b), "{a} * {b}");
+            });
+        });
+    }
+
+    #[test]
+    fn test_gf256_pow_exp_log() {
+        (0..GF256_ORDER).for_each(|a| {
+            let a = a as u8;
+
+            // Powers computed by repeated bitwise multiplication, covering a full period of the multiplicative group.
+            let mut expected = 1u8;
+            (0..(2 * GF256_ORDER as u32)).for_each(|exp| {
+                assert_eq!(Gf256::new(a).pow(exp).get(), expected, "{a}^{exp}");
+                expected = bitwise_mul(expected, a);
+            });
+            // 2^32 - 1 is a positive multiple of 255.
+            assert_eq!(Gf256::new(a).pow(u32::MAX), Gf256::new(a).pow(255), "{a}^{}", u32::MAX);
+
+            match Gf256::new(a).log() {
+                Some(log) => {
+                    assert_ne!(a, 0);
+                    assert!(log < u8::MAX);
+                    assert_eq!(Gf256::exp(log).get(), a);
+                }
+                None => assert_eq!(a, 0),
+            }
+
+            assert_eq!(Gf256::exp(a), Gf256::primitive_element().pow(a as u32), "exp({a})");
+        });
+    }
+
+    #[test]
+    fn prop_test_gf256_batch_inv_matches_inv() {
+        const NUM_TEST_ITERATIONS: usize = 10_000;
+        const MAX_NUM_ELEMENTS: usize = 64;
+
+        let mut rng = rand::rng();
+
+        (0..NUM_TEST_ITERATIONS).for_each(|_| {
+            // Plenty of zero elements, which must be skipped.
+            let num_elems = rng.random_range(0..=MAX_NUM_ELEMENTS);
+            let elems = (0..num_elems)
+                .map(|_| if rng.random_bool(0.25) { Gf256::zero() } else { rng.random() })
+                .collect::<Vec<Gf256>>();
+
+            let mut batch_inverted = elems.clone();
+            Gf256::batch_inv(&mut batch_inverted);
+
+            elems.iter().zip(batch_inverted).for_each(|(&elem, elem_inv)| {
+                assert_eq!(elem_inv, elem.inv().unwrap_or(Gf256::zero()), "{elem:?}^-1");
+            });
+        });
+    }
+
     #[test]
     fn prop_test_gf256_operations() {
         const NUM_TEST_ITERATIONS: usize = 100_000;
@@ -202,6 +587,32 @@ mod test {
             } else {
                 assert_eq!(a, div.unwrap());
             }
+
+            // Scalar multiplication and division by raw bytes
+            assert_eq!(a * b.get(), mul);
+            assert_eq!(a / b.get(), a / b);
+        });
+    }
+
+    #[test]
+    fn prop_test_gf256_sum_product() {
+        const NUM_TEST_ITERATIONS: usize = 10_000;
+        const MAX_NUM_ELEMENTS: usize = 16;
+
+        let mut rng = rand::rng();
+
+        assert_eq!(core::iter::empty::<Gf256>().sum::<Gf256>(), Gf256::zero());
+        assert_eq!(core::iter::empty::<Gf256>().product::<Gf256>(), Gf256::one());
+
+        (0..NUM_TEST_ITERATIONS).for_each(|_| {
+            let num_elems = rng.random_range(1..=MAX_NUM_ELEMENTS);
+            let elems = (0..num_elems).map(|_| rng.random()).collect::<Vec<Gf256>>();
+
+            let expected_sum = Gf256::new(elems.iter().fold(0u8, |acc, elem| acc ^ elem.get()));
+            let expected_product = Gf256::new(elems.iter().fold(1u8, |acc, elem| bitwise_mul(acc, elem.get())));
+
+            assert_eq!(elems.iter().copied().sum::<Gf256>(), expected_sum);
+            assert_eq!(elems.iter().copied().product::<Gf256>(), expected_product);
         });
     }
 }
@@ -0,0 +1,261 @@
+//! GF(2**16) arithmetic, with irreducible polynomial x^16 + x^12 + x^3 + x + 1. Logarithm and exponentiation tables
+//! for this field would take 384kB, so multiplication is carried out bit-serially, trading speed for memory footprint.
+
+use super::field::Field;
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub};
+use rand::Rng;
+use rand::distr::{Distribution, StandardUniform};
+
+pub const GF65536_ORDER: usize = u16::MAX as usize + 1;
+
+/// Lower 16 bits of the irreducible polynomial x^16 + x^12 + x^3 + x + 1, x^16 being implicit.
+const GF65536_REDUCTION_POLYNOMIAL: u16 = 0x100b;
+
+/// Gf(2^16) wrapper type.
+#[derive(Default, Clone, Copy, Debug)]
+pub struct Gf65536 {
+    val: u16,
+}
+
+impl Gf65536 {
+    /// Creates a new Gf65536 element from a u16 value.
+    pub const fn new(val: u16) -> Self {
+        Gf65536 { val }
+    }
+
+    /// Returns the raw u16 value of the Gf65536 element.
+    pub const fn get(&self) -> u16 {
+        self.val
+    }
+
+    /// Returns the additive identity element (0).
+    pub const fn zero() -> Self {
+        Gf65536::new(0)
+    }
+
+    /// Returns the multiplicative identity element (1).
+    pub const fn one() -> Self {
+        Gf65536::new(1)
+    }
+
+    /// Returns primitive element x, for GF(2^16) field with irreducible polynomial x^16 + x^12 + x^3 + x + 1.
+    pub const fn primitive_element() -> Self {
+        Gf65536::new(2)
+    }
+
+    /// Compile-time executable multiplication of two 16 -bit words, over GF(2^16), using shift-and-add.
+    pub const fn mul_const(mut a: u16, mut b: u16) -> u16 {
+        let mut res = 0u16;
+
+        while b != 0 {
+            if b & 1 == 1 {
+                res ^= a;
+            }
+
+            let carry = a & 0x8000;
+            a <<= 1;
+            if carry != 0 {
+                a ^= GF65536_REDUCTION_POLYNOMIAL;
+            }
+
+            b >>= 1;
+        }
+
+        res
+    }
+
+    /// Computes the multiplicative inverse of the element, using Fermat's little theorem i.e. a^-1 = a^(2^16 - 2).
+    /// Returns `None` for the zero element.
+    pub const fn inv(self) -> Option<Self> {
+        if self.val == 0 {
+            return None;
+        }
+
+        // Right-to-left binary exponentiation, scanning bits of 2^16 - 2 = 0b1111_1111_1111_1110.
+        let mut exponent = (GF65536_ORDER - 2) as u16;
+        let mut base = self.val;
+        let mut res = 1u16;
+
+        while exponent != 0 {
+            if exponent & 1 == 1 {
+                res = Self::mul_const(res, base);
+            }
+
+            base = Self::mul_const(base, base);
+            exponent >>= 1;
+        }
+
+        Some(Gf65536 { val: res })
+    }
+}
+
+impl Add for Gf65536 {
+    type Output = Self;
+
+    /// Performs addition (XOR) of two Gf65536 elements.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self::Output {
+        Gf65536 { val: self.val ^ rhs.val }
+    }
+}
+
+impl AddAssign for Gf65536 {
+    /// Performs in-place addition i.e. compound addition operation (XOR) of two Gf65536 elements.
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn add_assign(&mut self, rhs: Self) {
+        self.val ^= rhs.val;
+    }
+}
+
+impl Neg for Gf65536 {
+    type Output = Self;
+
+    /// Computes the additive inverse (itself, as XOR is self-inverse).
+    fn neg(self) -> Self::Output {
+        Gf65536 { val: self.val }
+    }
+}
+
+impl Sub for Gf65536 {
+    type Output = Self;
+
+    /// Performs subtraction (XOR) of two Gf65536 elements.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Gf65536 { val: self.val ^ rhs.val }
+    }
+}
+
+impl Mul for Gf65536 {
+    type Output = Self;
+
+    /// Performs multiplication of two Gf65536 elements.
+    fn mul(self, rhs: Self) -> Self::Output {
+        Gf65536 {
+            val: Self::mul_const(self.val, rhs.val),
+        }
+    }
+}
+
+impl Div for Gf65536 {
+    type Output = Option<Self>;
+
+    /// Performs division of two Gf65536 elements using multiplicative inverse. Returns `None` if dividing by zero.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        rhs.inv().map(|rhs_inv| self * rhs_inv)
+    }
+}
+
+impl PartialEq for Gf65536 {
+    /// Checks for equality between two Gf65536 elements.
+    fn eq(&self, other: &Self) -> bool {
+        self.val == other.val
+    }
+}
+
+impl Distribution<Gf65536> for StandardUniform {
+    /// Samples a random Gf65536 element.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Gf65536 {
+        Gf65536 { val: rng.random() }
+    }
+}
+
+impl Field for Gf65536 {
+    const BYTE_LEN: usize = 2;
+
+    fn zero() -> Self {
+        Gf65536::zero()
+    }
+
+    fn one() -> Self {
+        Gf65536::one()
+    }
+
+    fn inv(self) -> Option<Self> {
+        Gf65536::inv(self)
+    }
+
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.random()
+    }
+
+    fn from_le_bytes(bytes: &[u8]) -> Self {
+        Gf65536::new(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn write_le_bytes(self, bytes: &mut [u8]) {
+        bytes[..Self::BYTE_LEN].copy_from_slice(&self.val.to_le_bytes());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{GF65536_ORDER, Gf65536};
+    use crate::common::field::Field;
+    use rand::Rng;
+
+    #[test]
+    fn test_gf65536_primitive_element_generates_multiplicative_group() {
+        let mut is_generated = vec![false; GF65536_ORDER];
+        let mut power = Gf65536::one();
+
+        (0..(GF65536_ORDER - 1)).for_each(|_| {
+            assert!(!is_generated[power.get() as usize], "{} generated twice", power.get());
+            is_generated[power.get() as usize] = true;
+
+            power = power * Gf65536::primitive_element();
+        });
+
+        assert_eq!(power, Gf65536::one());
+        assert!(!is_generated[0]);
+    }
+
+    #[test]
+    fn test_gf65536_inverse() {
+        assert_eq!(Gf65536::zero().inv(), None);
+
+        (1..GF65536_ORDER).for_each(|a| {
+            let a = Gf65536::new(a as u16);
+            assert_eq!(a * a.inv().unwrap(), Gf65536::one(), "{a:?}^-1");
+        });
+    }
+
+    #[test]
+    fn prop_test_gf65536_operations() {
+        const NUM_TEST_ITERATIONS: usize = 100_000;
+
+        let mut rng = rand::rng();
+
+        (0..NUM_TEST_ITERATIONS).for_each(|_| {
+            let a: Gf65536 = rng.random();
+            let b: Gf65536 = rng.random();
+            let c: Gf65536 = rng.random();
+
+            // Addition, Subtraction, Negation
+            let sum = a + b;
+            let diff = sum - b;
+
+            assert_eq!(diff, a);
+
+            // Distributivity of multiplication over addition
+            assert_eq!(a * (b + c), a * b + a * c);
+
+            // Multiplication, Division, Inversion
+            let mul = a * b;
+            let div = mul / b;
+
+            if b == Gf65536::zero() {
+                assert_eq!(div, None);
+                assert_eq!(mul, Gf65536::zero());
+            } else {
+                assert_eq!(a, div.unwrap());
+            }
+
+            // Byte serialization
+            let mut bytes = [0u8; Gf65536::BYTE_LEN];
+            a.write_le_bytes(&mut bytes);
+            assert_eq!(Gf65536::from_le_bytes(&bytes), a);
+        });
+    }
+}
@@ -1,6 +1,16 @@
 pub mod errors;
+pub mod field;
 pub mod gf256;
+pub mod gf65536;
 pub mod simd;
 
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(
+    not(feature = "compact"),
+    any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "aarch64",
+        all(target_arch = "wasm32", target_feature = "simd128")
+    )
+))]
 mod simd_mul_table;
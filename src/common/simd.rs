@@ -1,30 +1,137 @@
 use super::gf256::Gf256;
+use core::sync::atomic::{AtomicU8, Ordering};
 
-#[cfg(target_arch = "x86")]
-use std::arch::x86::{
-    _mm_and_si128, _mm_lddqu_si128, _mm_set1_epi8, _mm_shuffle_epi8, _mm_srli_epi64, _mm_storeu_si128, _mm_xor_si128, _mm256_and_si256, _mm256_lddqu_si256,
-    _mm256_set1_epi8, _mm256_shuffle_epi8, _mm256_srli_epi64, _mm256_storeu_si256, _mm256_xor_si256,
-};
+/// Checks whether the running CPU supports all of the specified target features. Without `std`, CPU features can't be
+/// detected at runtime, so that only the features enabled at compile-time, e.g. using `-C target-feature`, are reported.
+#[cfg(all(not(feature = "compact"), any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")))]
+macro_rules! is_cpu_feature_detected {
+    ($($feature:tt),+) => {{
+        #[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
+        let is_detected = $(std::arch::is_x86_feature_detected!($feature))&&+;
 
-#[cfg(target_arch = "x86_64")]
-use std::arch::x86_64::{
-    _mm_and_si128, _mm_lddqu_si128, _mm_set1_epi8, _mm_shuffle_epi8, _mm_srli_epi64, _mm_storeu_si128, _mm_xor_si128, _mm256_and_si256, _mm256_lddqu_si256,
-    _mm256_set1_epi8, _mm256_shuffle_epi8, _mm256_srli_epi64, _mm256_storeu_si256, _mm256_xor_si256,
-};
+        #[cfg(all(feature = "std", target_arch = "aarch64"))]
+        let is_detected = $(std::arch::is_aarch64_feature_detected!($feature))&&+;
 
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-use super::{
-    gf256::GF256_HALF_ORDER,
-    simd_mul_table::{GF256_SIMD_MUL_TABLE_HIGH, GF256_SIMD_MUL_TABLE_LOW},
-};
+        #[cfg(not(feature = "std"))]
+        let is_detected = cfg!(all($(target_feature = $feature),+));
+
+        is_detected
+    }};
+}
+
+/// SIMD backend, used by the GF(2^8) vector arithmetic routines of this module.
+///
+/// With the `compact` feature, lookup-table assisted backends, along with their multiplication tables, are compiled out,
+/// so that `SimdBackend::Scalar` is the only one ever used, whatever the running CPU supports.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum SimdBackend {
+    /// 512 -bit wide affine transformation based multiplication, using `x86`/ `x86_64` `gfni`, `avx512f` and `avx512bw`
+    /// features. Only available with the `gfni` feature of this crate, which requires Rust 1.89 or newer.
+    Avx512Gfni,
+    /// 256 -bit wide lookup-table assisted multiplication, using `x86`/ `x86_64` `avx2` feature.
+    Avx2,
+    /// 128 -bit wide lookup-table assisted multiplication, using `x86`/ `x86_64` `ssse3` feature.
+    Ssse3,
+    /// 128 -bit wide lookup-table assisted multiplication, using `aarch64` `neon` feature.
+    Neon,
+    /// 128 -bit wide lookup-table assisted multiplication, using `wasm32` `simd128` feature. WebAssembly offers no
+    /// runtime feature detection, so that this backend is used only when built with `-C target-feature=+simd128`.
+    Simd128,
+    /// Portable scalar fallback.
+    Scalar,
+}
+
+impl SimdBackend {
+    /// Inverse of `backend as u8`, returning `None` for any other value.
+    const fn from_u8(encoded: u8) -> Option<SimdBackend> {
+        match encoded {
+            0 => Some(SimdBackend::Avx512Gfni),
+            1 => Some(SimdBackend::Avx2),
+            2 => Some(SimdBackend::Ssse3),
+            3 => Some(SimdBackend::Neon),
+            4 => Some(SimdBackend::Simd128),
+            5 => Some(SimdBackend::Scalar),
+            _ => None,
+        }
+    }
+}
+
+impl core::fmt::Display for SimdBackend {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            SimdBackend::Avx512Gfni => write!(f, "avx512gfni"),
+            SimdBackend::Avx2 => write!(f, "avx2"),
+            SimdBackend::Ssse3 => write!(f, "ssse3"),
+            SimdBackend::Neon => write!(f, "neon"),
+            SimdBackend::Simd128 => write!(f, "simd128"),
+            SimdBackend::Scalar => write!(f, "scalar"),
+        }
+    }
+}
+
+/// Returns the SIMD backend, the GF(2^8) vector arithmetic routines of this module dispatch to, on the running CPU.
+pub fn active_simd_backend() -> SimdBackend {
+    #[cfg(test)]
+    if let Some(backend) = forced_backend::get() {
+        return backend;
+    }
+
+    detected_simd_backend()
+}
+
+/// Returns the fastest SIMD backend, supported by the running CPU. CPU features are detected only on first call, while
+/// following calls load the cached result, so that dispatching each vector arithmetic routine is a single branch.
+fn detected_simd_backend() -> SimdBackend {
+    const UNDETECTED: u8 = u8::MAX;
+    static DETECTED_SIMD_BACKEND: AtomicU8 = AtomicU8::new(UNDETECTED);
+
+    if let Some(backend) = SimdBackend::from_u8(DETECTED_SIMD_BACKEND.load(Ordering::Relaxed)) {
+        return backend;
+    }
+
+    let backend = detect_simd_backend();
+    DETECTED_SIMD_BACKEND.store(backend as u8, Ordering::Relaxed);
+
+    backend
+}
+
+fn detect_simd_backend() -> SimdBackend {
+    #[cfg(all(feature = "gfni", not(feature = "compact"), any(target_arch = "x86", target_arch = "x86_64")))]
+    if gfni::is_supported() {
+        return SimdBackend::Avx512Gfni;
+    }
+
+    #[cfg(all(not(feature = "compact"), any(target_arch = "x86", target_arch = "x86_64")))]
+    if is_cpu_feature_detected!("avx2") {
+        return SimdBackend::Avx2;
+    }
+
+    #[cfg(all(not(feature = "compact"), any(target_arch = "x86", target_arch = "x86_64")))]
+    if is_cpu_feature_detected!("ssse3") {
+        return SimdBackend::Ssse3;
+    }
+
+    #[cfg(all(not(feature = "compact"), target_arch = "aarch64"))]
+    if is_cpu_feature_detected!("neon") {
+        return SimdBackend::Neon;
+    }
+
+    #[cfg(all(not(feature = "compact"), target_arch = "wasm32", target_feature = "simd128"))]
+    return SimdBackend::Simd128;
+
+    #[cfg(not(all(not(feature = "compact"), target_arch = "wasm32", target_feature = "simd128")))]
+    SimdBackend::Scalar
+}
 
 /// Given a byte array of arbitrary length, this function can be used to multiply each
 /// byte element with a single specific scalar, over GF(2^8), mutating the input vector.
 ///
-/// In case this function runs on `x86_64` with `avx2` or `ssse3` features, it can use
-/// lookup-table assisted SIMD multiplication, inspired from https://github.com/ceph/gf-complete/blob/a6862d10c9db467148f20eef2c6445ac9afd94d8/src/gf_w8.c#L1029-L1037.
+/// In case this function runs on `x86_64` with `avx2` or `ssse3` features, on `aarch64` with `neon` feature, or on
+/// `wasm32` with `simd128` feature, it can use lookup-table assisted SIMD multiplication, inspired from https://github.com/ceph/gf-complete/blob/a6862d10c9db467148f20eef2c6445ac9afd94d8/src/gf_w8.c#L1029-L1037.
 ///
-/// You have to build with `RUSTFLAGS="-C target-cpu=native"` flag to enjoy full benefits of compiler optimization.
+/// Each SIMD implementation is compiled with its own target features enabled, so that it is fully optimized, even
+/// without building with `RUSTFLAGS="-C target-cpu=native"` flag.
 ///
 /// I originally discovered this technique in https://www.snia.org/sites/default/files/files2/files2/SDC2013/presentations/NewThinking/EthanMiller_Screaming_Fast_Galois_Field%20Arithmetic_SIMD%20Instructions.pdf.
 pub fn gf256_inplace_mul_vec_by_scalar(vec: &mut [u8], scalar: u8) {
@@ -39,15 +146,160 @@ pub fn gf256_inplace_mul_vec_by_scalar(vec: &mut [u8], scalar: u8) {
         return;
     }
 
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    if is_x86_feature_detected!("avx2") {
+    match active_simd_backend() {
+        #[cfg(all(feature = "gfni", not(feature = "compact"), any(target_arch = "x86", target_arch = "x86_64")))]
+        SimdBackend::Avx512Gfni => unsafe { gfni::inplace_mul_vec_by_scalar(vec, scalar) },
+        #[cfg(all(not(feature = "compact"), any(target_arch = "x86", target_arch = "x86_64")))]
+        SimdBackend::Avx2 => unsafe { avx2::inplace_mul_vec_by_scalar(vec, scalar) },
+        #[cfg(all(not(feature = "compact"), any(target_arch = "x86", target_arch = "x86_64")))]
+        SimdBackend::Ssse3 => unsafe { ssse3::inplace_mul_vec_by_scalar(vec, scalar) },
+        #[cfg(all(not(feature = "compact"), target_arch = "aarch64"))]
+        SimdBackend::Neon => unsafe { neon::inplace_mul_vec_by_scalar(vec, scalar) },
+        #[cfg(all(not(feature = "compact"), target_arch = "wasm32", target_feature = "simd128"))]
+        SimdBackend::Simd128 => unsafe { simd128::inplace_mul_vec_by_scalar(vec, scalar) },
+        _ => scalar::inplace_mul_vec_by_scalar(vec, scalar),
+    }
+}
+
+/// Given two byte arrays of equal length, this routine performs element-wise
+/// addition over GF(2^8), mutating one of the operand vectors.
+///
+/// Note, addition over GF(2^8) is nothing but XOR-ing two operands. If this function
+/// runs on `x86_64` with `avx2` or `ssse3` features, on `aarch64` with `neon` feature, or on `wasm32` with `simd128`
+/// feature, it can perform fast SIMD addition using vector intrinsics.
+pub fn gf256_inplace_add_vectors(vec_dst: &mut [u8], vec_src: &[u8]) {
+    match active_simd_backend() {
+        #[cfg(all(feature = "gfni", not(feature = "compact"), any(target_arch = "x86", target_arch = "x86_64")))]
+        SimdBackend::Avx512Gfni => unsafe { gfni::inplace_add_vectors(vec_dst, vec_src) },
+        #[cfg(all(not(feature = "compact"), any(target_arch = "x86", target_arch = "x86_64")))]
+        SimdBackend::Avx2 => unsafe { avx2::inplace_add_vectors(vec_dst, vec_src) },
+        #[cfg(all(not(feature = "compact"), any(target_arch = "x86", target_arch = "x86_64")))]
+        SimdBackend::Ssse3 => unsafe { ssse3::inplace_add_vectors(vec_dst, vec_src) },
+        #[cfg(all(not(feature = "compact"), target_arch = "aarch64"))]
+        SimdBackend::Neon => unsafe { neon::inplace_add_vectors(vec_dst, vec_src) },
+        #[cfg(all(not(feature = "compact"), target_arch = "wasm32", target_feature = "simd128"))]
+        SimdBackend::Simd128 => unsafe { simd128::inplace_add_vectors(vec_dst, vec_src) },
+        _ => scalar::inplace_add_vectors(vec_dst, vec_src),
+    }
+}
+
+/// Given a byte array `mul_vec` of arbitrary length, this function can be used to multiply each
+/// byte element with a single specific scalar, over GF(2^8), and then adding each scaled value
+/// to corresponding value in sink vector `add_into_vec`.
+///
+/// In case this function runs on `x86_64` with `avx2` or `ssse3` features, on `aarch64` with `neon` feature, or on
+/// `wasm32` with `simd128` feature, it can use lookup-table assisted SIMD multiplication, inspired from https://github.com/ceph/gf-complete/blob/a6862d10c9db467148f20eef2c6445ac9afd94d8/src/gf_w8.c#L1029-L1037.
+///
+/// This function can be thought of an optimization over, first applying `gf256_inplace_mul_vec_by_scalar`
+/// and then applying `gf256_inplace_add_vectors`.
+pub fn gf256_mul_vec_by_scalar_then_add_into_vec(add_into_vec: &mut [u8], mul_vec: &[u8], scalar: u8) {
+    if add_into_vec.is_empty() {
+        return;
+    }
+    if scalar == 0 {
+        return;
+    }
+    if scalar == 1 {
+        gf256_inplace_add_vectors(add_into_vec, mul_vec);
+        return;
+    }
+
+    match active_simd_backend() {
+        #[cfg(all(feature = "gfni", not(feature = "compact"), any(target_arch = "x86", target_arch = "x86_64")))]
+        SimdBackend::Avx512Gfni => unsafe { gfni::mul_vec_by_scalar_then_add_into_vec(add_into_vec, mul_vec, scalar) },
+        #[cfg(all(not(feature = "compact"), any(target_arch = "x86", target_arch = "x86_64")))]
+        SimdBackend::Avx2 => unsafe { avx2::mul_vec_by_scalar_then_add_into_vec(add_into_vec, mul_vec, scalar) },
+        #[cfg(all(not(feature = "compact"), any(target_arch = "x86", target_arch = "x86_64")))]
+        SimdBackend::Ssse3 => unsafe { ssse3::mul_vec_by_scalar_then_add_into_vec(add_into_vec, mul_vec, scalar) },
+        #[cfg(all(not(feature = "compact"), target_arch = "aarch64"))]
+        SimdBackend::Neon => unsafe { neon::mul_vec_by_scalar_then_add_into_vec(add_into_vec, mul_vec, scalar) },
+        #[cfg(all(not(feature = "compact"), target_arch = "wasm32", target_feature = "simd128"))]
+        SimdBackend::Simd128 => unsafe { simd128::mul_vec_by_scalar_then_add_into_vec(add_into_vec, mul_vec, scalar) },
+        _ => scalar::mul_vec_by_scalar_then_add_into_vec(add_into_vec, mul_vec, scalar),
+    }
+}
+
+/// Given a vector `vec` of `n` bytes and a row-major matrix `mat` of `n` rows, each of `out.len()` bytes, this function
+/// computes the vector-matrix product `vec x mat`, over GF(2^8), writing it into `out`. In other words, `out` becomes the
+/// linear combination of the rows of `mat`, using elements of `vec` as coefficients, which is how a recoder computes the
+/// coding vector of a recoded piece, from the coding vectors of the pieces it received.
+///
+/// Each row is accumulated using `gf256_mul_vec_by_scalar_then_add_into_vec`, so this function benefits from the same
+/// SIMD acceleration, which matters when `out.len()` i.e. number of pieces coded together is large.
+///
+/// # Panics
+/// Panics if `mat.len()` is not `vec.len() * out.len()`.
+pub fn gf256_vec_mat_mul(vec: &[u8], mat: &[u8], out: &mut [u8]) {
+    assert_eq!(mat.len(), vec.len() * out.len());
+
+    out.fill(0);
+    if out.is_empty() {
+        return;
+    }
+
+    mat.chunks_exact(out.len())
+        .zip(vec)
+        .for_each(|(row, &scalar)| gf256_mul_vec_by_scalar_then_add_into_vec(out, row, scalar));
+}
+
+/// Portable GF(2^8) vector arithmetic, used when no SIMD backend is supported by the running CPU. These functions are
+/// also used for the trailing bytes, not filling a whole SIMD register, by the SIMD backends.
+mod scalar {
+    use super::Gf256;
+
+    pub fn inplace_mul_vec_by_scalar(vec: &mut [u8], scalar: u8) {
+        vec.iter_mut().for_each(|src_symbol| {
+            *src_symbol = Gf256::mul_const(*src_symbol, scalar);
+        });
+    }
+
+    pub fn inplace_add_vectors(vec_dst: &mut [u8], vec_src: &[u8]) {
+        vec_dst.iter_mut().zip(vec_src).for_each(|(a, b)| {
+            *a ^= b;
+        });
+    }
+
+    pub fn mul_vec_by_scalar_then_add_into_vec(add_into_vec: &mut [u8], mul_vec: &[u8], scalar: u8) {
+        add_into_vec
+            .iter_mut()
+            .zip(mul_vec.iter().map(|&src_symbol| Gf256::mul_const(src_symbol, scalar)))
+            .for_each(|(res, scaled)| *res ^= scaled);
+    }
+}
+
+/// GF(2^8) vector arithmetic using 256 -bit wide registers. These functions must only be called if the running CPU
+/// supports `avx2` feature.
+#[cfg(all(not(feature = "compact"), any(target_arch = "x86", target_arch = "x86_64")))]
+mod avx2 {
+    use super::{
+        super::{
+            gf256::GF256_HALF_ORDER,
+            simd_mul_table::{GF256_SIMD_MUL_TABLE_HIGH, GF256_SIMD_MUL_TABLE_LOW},
+        },
+        scalar,
+    };
+
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::{
+        _mm256_and_si256, _mm256_lddqu_si256, _mm256_set1_epi8, _mm256_shuffle_epi8, _mm256_srli_epi64, _mm256_storeu_si256, _mm256_xor_si256,
+    };
+
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::{
+        _mm256_and_si256, _mm256_lddqu_si256, _mm256_set1_epi8, _mm256_shuffle_epi8, _mm256_srli_epi64, _mm256_storeu_si256, _mm256_xor_si256,
+    };
+
+    const LANE_BYTE_LEN: usize = 2 * GF256_HALF_ORDER;
+
+    #[target_feature(enable = "avx2")]
+    pub unsafe fn inplace_mul_vec_by_scalar(vec: &mut [u8], scalar: u8) {
+        let mut iter = vec.chunks_exact_mut(LANE_BYTE_LEN);
+
         unsafe {
             let l_tbl = _mm256_lddqu_si256(GF256_SIMD_MUL_TABLE_LOW[scalar as usize].as_ptr() as *const _);
             let h_tbl = _mm256_lddqu_si256(GF256_SIMD_MUL_TABLE_HIGH[scalar as usize].as_ptr() as *const _);
             let l_mask = _mm256_set1_epi8(0x0f);
 
-            let mut iter = vec.chunks_exact_mut(2 * GF256_HALF_ORDER);
-
             for chunk in iter.by_ref() {
                 let chunk_simd = _mm256_lddqu_si256(chunk.as_ptr() as *const _);
 
@@ -61,24 +313,91 @@ pub fn gf256_inplace_mul_vec_by_scalar(vec: &mut [u8], scalar: u8) {
                 let res = _mm256_xor_si256(chunk_simd_lo, chunk_simd_hi);
                 _mm256_storeu_si256(chunk.as_mut_ptr() as *mut _, res);
             }
+        }
+
+        scalar::inplace_mul_vec_by_scalar(iter.into_remainder(), scalar);
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub unsafe fn inplace_add_vectors(vec_dst: &mut [u8], vec_src: &[u8]) {
+        let mut iter_dst = vec_dst.chunks_exact_mut(LANE_BYTE_LEN);
+        let mut iter_src = vec_src.chunks_exact(LANE_BYTE_LEN);
 
-            iter.into_remainder().iter_mut().for_each(|symbol| {
-                *symbol = Gf256::mul_const(*symbol, scalar);
-            });
+        for (chunk_dst, chunk_src) in iter_dst.by_ref().zip(iter_src.by_ref()) {
+            unsafe {
+                let chunk_dst_simd = _mm256_lddqu_si256(chunk_dst.as_ptr() as *const _);
+                let chunk_src_simd = _mm256_lddqu_si256(chunk_src.as_ptr() as *const _);
+                let chunk_result = _mm256_xor_si256(chunk_dst_simd, chunk_src_simd);
+
+                _mm256_storeu_si256(chunk_dst.as_mut_ptr() as *mut _, chunk_result);
+            }
         }
 
-        return;
+        scalar::inplace_add_vectors(iter_dst.into_remainder(), iter_src.remainder());
     }
 
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    if is_x86_feature_detected!("ssse3") {
+    #[target_feature(enable = "avx2")]
+    pub unsafe fn mul_vec_by_scalar_then_add_into_vec(add_into_vec: &mut [u8], mul_vec: &[u8], scalar: u8) {
+        let mut add_vec_iter = add_into_vec.chunks_exact_mut(LANE_BYTE_LEN);
+        let mut mul_vec_iter = mul_vec.chunks_exact(LANE_BYTE_LEN);
+
+        unsafe {
+            let l_tbl = _mm256_lddqu_si256(GF256_SIMD_MUL_TABLE_LOW[scalar as usize].as_ptr() as *const _);
+            let h_tbl = _mm256_lddqu_si256(GF256_SIMD_MUL_TABLE_HIGH[scalar as usize].as_ptr() as *const _);
+            let l_mask = _mm256_set1_epi8(0x0f);
+
+            for (add_vec_chunk, mul_vec_chunk) in add_vec_iter.by_ref().zip(mul_vec_iter.by_ref()) {
+                let mul_vec_chunk_simd = _mm256_lddqu_si256(mul_vec_chunk.as_ptr() as *const _);
+
+                let chunk_simd_lo = _mm256_and_si256(mul_vec_chunk_simd, l_mask);
+                let chunk_simd_lo = _mm256_shuffle_epi8(l_tbl, chunk_simd_lo);
+
+                let chunk_simd_hi = _mm256_srli_epi64(mul_vec_chunk_simd, 4);
+                let chunk_simd_hi = _mm256_and_si256(chunk_simd_hi, l_mask);
+                let chunk_simd_hi = _mm256_shuffle_epi8(h_tbl, chunk_simd_hi);
+
+                let scaled_res = _mm256_xor_si256(chunk_simd_lo, chunk_simd_hi);
+
+                let add_vec_chunk_simd = _mm256_lddqu_si256(add_vec_chunk.as_ptr() as *const _);
+                let accum_res = _mm256_xor_si256(add_vec_chunk_simd, scaled_res);
+
+                _mm256_storeu_si256(add_vec_chunk.as_mut_ptr() as *mut _, accum_res);
+            }
+        }
+
+        scalar::mul_vec_by_scalar_then_add_into_vec(add_vec_iter.into_remainder(), mul_vec_iter.remainder(), scalar);
+    }
+}
+
+/// GF(2^8) vector arithmetic using 128 -bit wide registers. These functions must only be called if the running CPU
+/// supports `ssse3` feature.
+#[cfg(all(not(feature = "compact"), any(target_arch = "x86", target_arch = "x86_64")))]
+mod ssse3 {
+    use super::{
+        super::{
+            gf256::GF256_HALF_ORDER,
+            simd_mul_table::{GF256_SIMD_MUL_TABLE_HIGH, GF256_SIMD_MUL_TABLE_LOW},
+        },
+        scalar,
+    };
+
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::{_mm_and_si128, _mm_lddqu_si128, _mm_set1_epi8, _mm_shuffle_epi8, _mm_srli_epi64, _mm_storeu_si128, _mm_xor_si128};
+
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::{_mm_and_si128, _mm_lddqu_si128, _mm_set1_epi8, _mm_shuffle_epi8, _mm_srli_epi64, _mm_storeu_si128, _mm_xor_si128};
+
+    const LANE_BYTE_LEN: usize = GF256_HALF_ORDER;
+
+    #[target_feature(enable = "ssse3")]
+    pub unsafe fn inplace_mul_vec_by_scalar(vec: &mut [u8], scalar: u8) {
+        let mut iter = vec.chunks_exact_mut(LANE_BYTE_LEN);
+
         unsafe {
             let l_tbl = _mm_lddqu_si128(GF256_SIMD_MUL_TABLE_LOW[scalar as usize].as_ptr() as *const _);
             let h_tbl = _mm_lddqu_si128(GF256_SIMD_MUL_TABLE_HIGH[scalar as usize].as_ptr() as *const _);
             let l_mask = _mm_set1_epi8(0x0f);
 
-            let mut iter = vec.chunks_exact_mut(GF256_HALF_ORDER);
-
             for chunk in iter.by_ref() {
                 let chunk_simd = _mm_lddqu_si128(chunk.as_ptr() as *const _);
 
@@ -92,190 +411,539 @@ pub fn gf256_inplace_mul_vec_by_scalar(vec: &mut [u8], scalar: u8) {
                 let res = _mm_xor_si128(chunk_simd_lo, chunk_simd_hi);
                 _mm_storeu_si128(chunk.as_mut_ptr() as *mut _, res);
             }
+        }
 
-            iter.into_remainder().iter_mut().for_each(|symbol| {
-                *symbol = Gf256::mul_const(*symbol, scalar);
-            });
+        scalar::inplace_mul_vec_by_scalar(iter.into_remainder(), scalar);
+    }
+
+    #[target_feature(enable = "ssse3")]
+    pub unsafe fn inplace_add_vectors(vec_dst: &mut [u8], vec_src: &[u8]) {
+        let mut iter_dst = vec_dst.chunks_exact_mut(LANE_BYTE_LEN);
+        let mut iter_src = vec_src.chunks_exact(LANE_BYTE_LEN);
+
+        for (chunk_dst, chunk_src) in iter_dst.by_ref().zip(iter_src.by_ref()) {
+            unsafe {
+                let chunk_dst_simd = _mm_lddqu_si128(chunk_dst.as_ptr() as *const _);
+                let chunk_src_simd = _mm_lddqu_si128(chunk_src.as_ptr() as *const _);
+                let chunk_result = _mm_xor_si128(chunk_dst_simd, chunk_src_simd);
+
+                _mm_storeu_si128(chunk_dst.as_mut_ptr() as *mut _, chunk_result);
+            }
         }
 
-        return;
+        scalar::inplace_add_vectors(iter_dst.into_remainder(), iter_src.remainder());
     }
 
-    vec.iter_mut().for_each(|src_symbol| {
-        *src_symbol = Gf256::mul_const(*src_symbol, scalar);
-    });
+    #[target_feature(enable = "ssse3")]
+    pub unsafe fn mul_vec_by_scalar_then_add_into_vec(add_into_vec: &mut [u8], mul_vec: &[u8], scalar: u8) {
+        let mut add_vec_iter = add_into_vec.chunks_exact_mut(LANE_BYTE_LEN);
+        let mut mul_vec_iter = mul_vec.chunks_exact(LANE_BYTE_LEN);
+
+        unsafe {
+            let l_tbl = _mm_lddqu_si128(GF256_SIMD_MUL_TABLE_LOW[scalar as usize].as_ptr() as *const _);
+            let h_tbl = _mm_lddqu_si128(GF256_SIMD_MUL_TABLE_HIGH[scalar as usize].as_ptr() as *const _);
+            let l_mask = _mm_set1_epi8(0x0f);
+
+            for (add_vec_chunk, mul_vec_chunk) in add_vec_iter.by_ref().zip(mul_vec_iter.by_ref()) {
+                let mul_vec_chunk_simd = _mm_lddqu_si128(mul_vec_chunk.as_ptr() as *const _);
+
+                let chunk_simd_lo = _mm_and_si128(mul_vec_chunk_simd, l_mask);
+                let chunk_simd_lo = _mm_shuffle_epi8(l_tbl, chunk_simd_lo);
+
+                let chunk_simd_hi = _mm_srli_epi64(mul_vec_chunk_simd, 4);
+                let chunk_simd_hi = _mm_and_si128(chunk_simd_hi, l_mask);
+                let chunk_simd_hi = _mm_shuffle_epi8(h_tbl, chunk_simd_hi);
+
+                let scaled_res = _mm_xor_si128(chunk_simd_lo, chunk_simd_hi);
+
+                let add_vec_chunk_simd = _mm_lddqu_si128(add_vec_chunk.as_ptr() as *const _);
+                let accum_res = _mm_xor_si128(add_vec_chunk_simd, scaled_res);
+
+                _mm_storeu_si128(add_vec_chunk.as_mut_ptr() as *mut _, accum_res);
+            }
+        }
+
+        scalar::mul_vec_by_scalar_then_add_into_vec(add_vec_iter.into_remainder(), mul_vec_iter.remainder(), scalar);
+    }
 }
 
-/// Given two byte arrays of equal length, this routine performs element-wise
-/// addition over GF(2^8), mutating one of the operand vectors.
-///
-/// Note, addition over GF(2^8) is nothing but XOR-ing two operands. If this function
-/// runs on `x86_64` with `avx2` or `ssse3` features, it can perform fast SIMD addition
-/// using vector intrinsics.
-///
-/// You have to compile with `RUSTFLAGS="-C target-cpu=native` flag to hint the compiler
-/// so that it generates best code.
-pub fn gf256_inplace_add_vectors(vec_dst: &mut [u8], vec_src: &[u8]) {
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    if is_x86_feature_detected!("avx2") {
+/// GF(2^8) vector arithmetic using 128 -bit wide registers, with the same lookup tables as the `ssse3` backend. These
+/// functions must only be called if the running CPU supports `neon` feature.
+#[cfg(all(not(feature = "compact"), target_arch = "aarch64"))]
+mod neon {
+    use super::{
+        super::{
+            gf256::GF256_HALF_ORDER,
+            simd_mul_table::{GF256_SIMD_MUL_TABLE_HIGH, GF256_SIMD_MUL_TABLE_LOW},
+        },
+        scalar,
+    };
+    use core::arch::aarch64::{vandq_u8, vdupq_n_u8, veorq_u8, vld1q_u8, vqtbl1q_u8, vshrq_n_u8, vst1q_u8};
+
+    const LANE_BYTE_LEN: usize = GF256_HALF_ORDER;
+
+    #[target_feature(enable = "neon")]
+    pub unsafe fn inplace_mul_vec_by_scalar(vec: &mut [u8], scalar: u8) {
+        let mut iter = vec.chunks_exact_mut(LANE_BYTE_LEN);
+
         unsafe {
-            let mut iter_dst = vec_dst.chunks_exact_mut(2 * GF256_HALF_ORDER);
-            let mut iter_src = vec_src.chunks_exact(2 * GF256_HALF_ORDER);
+            let l_tbl = vld1q_u8(GF256_SIMD_MUL_TABLE_LOW[scalar as usize].as_ptr());
+            let h_tbl = vld1q_u8(GF256_SIMD_MUL_TABLE_HIGH[scalar as usize].as_ptr());
+            let l_mask = vdupq_n_u8(0x0f);
 
-            for (chunk_dst, chunk_src) in iter_dst.by_ref().zip(iter_src.by_ref()) {
-                let chunk_dst_simd = _mm256_lddqu_si256(chunk_dst.as_ptr() as *const _);
-                let chunk_src_simd = _mm256_lddqu_si256(chunk_src.as_ptr() as *const _);
-                let chunk_result = _mm256_xor_si256(chunk_dst_simd, chunk_src_simd);
+            for chunk in iter.by_ref() {
+                let chunk_simd = vld1q_u8(chunk.as_ptr());
 
-                _mm256_storeu_si256(chunk_dst.as_mut_ptr() as *mut _, chunk_result);
+                let chunk_simd_lo = vandq_u8(chunk_simd, l_mask);
+                let chunk_simd_lo = vqtbl1q_u8(l_tbl, chunk_simd_lo);
+
+                let chunk_simd_hi = vshrq_n_u8::<4>(chunk_simd);
+                let chunk_simd_hi = vqtbl1q_u8(h_tbl, chunk_simd_hi);
+
+                let res = veorq_u8(chunk_simd_lo, chunk_simd_hi);
+                vst1q_u8(chunk.as_mut_ptr(), res);
             }
+        }
 
-            let remainder_dst = iter_dst.into_remainder();
-            let remainder_src = iter_src.remainder();
+        scalar::inplace_mul_vec_by_scalar(iter.into_remainder(), scalar);
+    }
+
+    #[target_feature(enable = "neon")]
+    pub unsafe fn inplace_add_vectors(vec_dst: &mut [u8], vec_src: &[u8]) {
+        let mut iter_dst = vec_dst.chunks_exact_mut(LANE_BYTE_LEN);
+        let mut iter_src = vec_src.chunks_exact(LANE_BYTE_LEN);
+
+        for (chunk_dst, chunk_src) in iter_dst.by_ref().zip(iter_src.by_ref()) {
+            unsafe {
+                let chunk_dst_simd = vld1q_u8(chunk_dst.as_ptr());
+                let chunk_src_simd = vld1q_u8(chunk_src.as_ptr());
+                let chunk_result = veorq_u8(chunk_dst_simd, chunk_src_simd);
 
-            remainder_dst.iter_mut().zip(remainder_src).for_each(|(a, b)| {
-                *a ^= b;
-            });
+                vst1q_u8(chunk_dst.as_mut_ptr(), chunk_result);
+            }
         }
 
-        return;
+        scalar::inplace_add_vectors(iter_dst.into_remainder(), iter_src.remainder());
     }
 
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    if is_x86_feature_detected!("ssse3") {
+    #[target_feature(enable = "neon")]
+    pub unsafe fn mul_vec_by_scalar_then_add_into_vec(add_into_vec: &mut [u8], mul_vec: &[u8], scalar: u8) {
+        let mut add_vec_iter = add_into_vec.chunks_exact_mut(LANE_BYTE_LEN);
+        let mut mul_vec_iter = mul_vec.chunks_exact(LANE_BYTE_LEN);
+
         unsafe {
-            let mut iter_dst = vec_dst.chunks_exact_mut(GF256_HALF_ORDER);
-            let mut iter_src = vec_src.chunks_exact(GF256_HALF_ORDER);
+            let l_tbl = vld1q_u8(GF256_SIMD_MUL_TABLE_LOW[scalar as usize].as_ptr());
+            let h_tbl = vld1q_u8(GF256_SIMD_MUL_TABLE_HIGH[scalar as usize].as_ptr());
+            let l_mask = vdupq_n_u8(0x0f);
 
-            for (chunk_dst, chunk_src) in iter_dst.by_ref().zip(iter_src.by_ref()) {
-                let chunk_dst_simd = _mm_lddqu_si128(chunk_dst.as_ptr() as *const _);
-                let chunk_src_simd = _mm_lddqu_si128(chunk_src.as_ptr() as *const _);
-                let chunk_result = _mm_xor_si128(chunk_dst_simd, chunk_src_simd);
+            for (add_vec_chunk, mul_vec_chunk) in add_vec_iter.by_ref().zip(mul_vec_iter.by_ref()) {
+                let mul_vec_chunk_simd = vld1q_u8(mul_vec_chunk.as_ptr());
 
-                _mm_storeu_si128(chunk_dst.as_mut_ptr() as *mut _, chunk_result);
+                let chunk_simd_lo = vandq_u8(mul_vec_chunk_simd, l_mask);
+                let chunk_simd_lo = vqtbl1q_u8(l_tbl, chunk_simd_lo);
+
+                let chunk_simd_hi = vshrq_n_u8::<4>(mul_vec_chunk_simd);
+                let chunk_simd_hi = vqtbl1q_u8(h_tbl, chunk_simd_hi);
+
+                let scaled_res = veorq_u8(chunk_simd_lo, chunk_simd_hi);
+
+                let add_vec_chunk_simd = vld1q_u8(add_vec_chunk.as_ptr());
+                let accum_res = veorq_u8(add_vec_chunk_simd, scaled_res);
+
+                vst1q_u8(add_vec_chunk.as_mut_ptr(), accum_res);
             }
+        }
 
-            let remainder_dst = iter_dst.into_remainder();
-            let remainder_src = iter_src.remainder();
+        scalar::mul_vec_by_scalar_then_add_into_vec(add_vec_iter.into_remainder(), mul_vec_iter.remainder(), scalar);
+    }
+}
+
+/// GF(2^8) vector arithmetic using 128 -bit wide registers, with the same lookup tables as the `ssse3` backend, where
+/// `i8x16_swizzle` does the nibble-table lookup. WebAssembly offers no runtime feature detection, so that this module is
+/// compiled only when `simd128` feature is enabled at compile-time, in which case any runtime executing the module
+/// supports it.
+#[cfg(all(not(feature = "compact"), target_arch = "wasm32", target_feature = "simd128"))]
+mod simd128 {
+    use super::{
+        super::{
+            gf256::GF256_HALF_ORDER,
+            simd_mul_table::{GF256_SIMD_MUL_TABLE_HIGH, GF256_SIMD_MUL_TABLE_LOW},
+        },
+        scalar,
+    };
+    use core::arch::wasm32::{i8x16_swizzle, u8x16_shr, u8x16_splat, v128, v128_and, v128_load, v128_store, v128_xor};
+
+    const LANE_BYTE_LEN: usize = GF256_HALF_ORDER;
+
+    #[target_feature(enable = "simd128")]
+    pub unsafe fn inplace_mul_vec_by_scalar(vec: &mut [u8], scalar: u8) {
+        let mut iter = vec.chunks_exact_mut(LANE_BYTE_LEN);
+
+        unsafe {
+            let l_tbl = v128_load(GF256_SIMD_MUL_TABLE_LOW[scalar as usize].as_ptr().cast::<v128>());
+            let h_tbl = v128_load(GF256_SIMD_MUL_TABLE_HIGH[scalar as usize].as_ptr().cast::<v128>());
+            let l_mask = u8x16_splat(0x0f);
 
-            remainder_dst.iter_mut().zip(remainder_src).for_each(|(a, b)| {
-                *a ^= b;
-            });
+            for chunk in iter.by_ref() {
+                let chunk_simd = v128_load(chunk.as_ptr().cast::<v128>());
+
+                let chunk_simd_lo = v128_and(chunk_simd, l_mask);
+                let chunk_simd_lo = i8x16_swizzle(l_tbl, chunk_simd_lo);
+
+                let chunk_simd_hi = u8x16_shr(chunk_simd, 4);
+                let chunk_simd_hi = i8x16_swizzle(h_tbl, chunk_simd_hi);
+
+                let res = v128_xor(chunk_simd_lo, chunk_simd_hi);
+                v128_store(chunk.as_mut_ptr().cast::<v128>(), res);
+            }
         }
 
-        return;
+        scalar::inplace_mul_vec_by_scalar(iter.into_remainder(), scalar);
     }
 
-    vec_dst.iter_mut().zip(vec_src).for_each(|(a, b)| {
-        *a ^= b;
-    });
+    #[target_feature(enable = "simd128")]
+    pub unsafe fn inplace_add_vectors(vec_dst: &mut [u8], vec_src: &[u8]) {
+        let mut iter_dst = vec_dst.chunks_exact_mut(LANE_BYTE_LEN);
+        let mut iter_src = vec_src.chunks_exact(LANE_BYTE_LEN);
+
+        for (chunk_dst, chunk_src) in iter_dst.by_ref().zip(iter_src.by_ref()) {
+            unsafe {
+                let chunk_dst_simd = v128_load(chunk_dst.as_ptr().cast::<v128>());
+                let chunk_src_simd = v128_load(chunk_src.as_ptr().cast::<v128>());
+                let chunk_result = v128_xor(chunk_dst_simd, chunk_src_simd);
+
+                v128_store(chunk_dst.as_mut_ptr().cast::<v128>(), chunk_result);
+            }
+        }
+
+        scalar::inplace_add_vectors(iter_dst.into_remainder(), iter_src.remainder());
+    }
+
+    #[target_feature(enable = "simd128")]
+    pub unsafe fn mul_vec_by_scalar_then_add_into_vec(add_into_vec: &mut [u8], mul_vec: &[u8], scalar: u8) {
+        let mut add_vec_iter = add_into_vec.chunks_exact_mut(LANE_BYTE_LEN);
+        let mut mul_vec_iter = mul_vec.chunks_exact(LANE_BYTE_LEN);
+
+        unsafe {
+            let l_tbl = v128_load(GF256_SIMD_MUL_TABLE_LOW[scalar as usize].as_ptr().cast::<v128>());
+            let h_tbl = v128_load(GF256_SIMD_MUL_TABLE_HIGH[scalar as usize].as_ptr().cast::<v128>());
+            let l_mask = u8x16_splat(0x0f);
+
+            for (add_vec_chunk, mul_vec_chunk) in add_vec_iter.by_ref().zip(mul_vec_iter.by_ref()) {
+                let mul_vec_chunk_simd = v128_load(mul_vec_chunk.as_ptr().cast::<v128>());
+
+                let chunk_simd_lo = v128_and(mul_vec_chunk_simd, l_mask);
+                let chunk_simd_lo = i8x16_swizzle(l_tbl, chunk_simd_lo);
+
+                let chunk_simd_hi = u8x16_shr(mul_vec_chunk_simd, 4);
+                let chunk_simd_hi = i8x16_swizzle(h_tbl, chunk_simd_hi);
+
+                let scaled_res = v128_xor(chunk_simd_lo, chunk_simd_hi);
+
+                let add_vec_chunk_simd = v128_load(add_vec_chunk.as_ptr().cast::<v128>());
+                let accum_res = v128_xor(add_vec_chunk_simd, scaled_res);
+
+                v128_store(add_vec_chunk.as_mut_ptr().cast::<v128>(), accum_res);
+            }
+        }
+
+        scalar::mul_vec_by_scalar_then_add_into_vec(add_vec_iter.into_remainder(), mul_vec_iter.remainder(), scalar);
+    }
 }
 
-/// Given a byte array `mul_vec` of arbitrary length, this function can be used to multiply each
-/// byte element with a single specific scalar, over GF(2^8), and then adding each scaled value
-/// to corresponding value in sink vector `add_into_vec`.
-///
-/// In case this function runs on `x86_64` with `avx2` or `ssse3` features, it can use
-/// lookup-table assisted SIMD multiplication, inspired from https://github.com/ceph/gf-complete/blob/a6862d10c9db467148f20eef2c6445ac9afd94d8/src/gf_w8.c#L1029-L1037.
-///
-/// You have to build with `RUSTFLAGS="-C target-cpu=native"` flag to enjoy full benefits of compiler optimization.
+/// GF(2^8) vector arithmetic using 512 -bit wide registers, where multiplication by a scalar is a single `GF2P8AFFINEQB`
+/// instruction, applying the 8x8 bit matrix of multiplication by that scalar to each byte. These functions must only be
+/// called after `is_supported` returns true.
 ///
-/// This function can be thought of an optimization over, first applying `gf256_inplace_mul_vec_by_scalar`
-/// and then applying `gf256_inplace_add_vectors`.
-pub fn gf256_mul_vec_by_scalar_then_add_into_vec(add_into_vec: &mut [u8], mul_vec: &[u8], scalar: u8) {
-    if add_into_vec.is_empty() {
-        return;
-    }
-    if scalar == 0 {
-        return;
+/// AVX-512 and GFNI intrinsics are stable since Rust 1.89, while MSRV of this crate is lower, which is why this module
+/// is gated behind the opt-in `gfni` feature.
+#[cfg(all(feature = "gfni", not(feature = "compact"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[clippy::msrv = "1.89.0"]
+mod gfni {
+    use super::{super::simd_mul_table::GF256_GFNI_AFFINE_MATRICES, scalar};
+
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::{_mm512_gf2p8affine_epi64_epi8, _mm512_loadu_si512, _mm512_set1_epi64, _mm512_storeu_si512, _mm512_xor_si512};
+
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::{_mm512_gf2p8affine_epi64_epi8, _mm512_loadu_si512, _mm512_set1_epi64, _mm512_storeu_si512, _mm512_xor_si512};
+
+    const LANE_BYTE_LEN: usize = 64;
+
+    pub fn is_supported() -> bool {
+        is_cpu_feature_detected!("gfni", "avx512f", "avx512bw")
     }
-    if scalar == 1 {
-        gf256_inplace_add_vectors(add_into_vec, mul_vec);
-        return;
+
+    #[target_feature(enable = "gfni,avx512f,avx512bw")]
+    pub unsafe fn inplace_mul_vec_by_scalar(vec: &mut [u8], scalar: u8) {
+        let matrix = _mm512_set1_epi64(GF256_GFNI_AFFINE_MATRICES[scalar as usize] as i64);
+
+        let mut iter = vec.chunks_exact_mut(LANE_BYTE_LEN);
+
+        for chunk in iter.by_ref() {
+            unsafe {
+                let chunk_simd = _mm512_loadu_si512(chunk.as_ptr() as *const _);
+                let res = _mm512_gf2p8affine_epi64_epi8::<0>(chunk_simd, matrix);
+                _mm512_storeu_si512(chunk.as_mut_ptr() as *mut _, res);
+            }
+        }
+
+        scalar::inplace_mul_vec_by_scalar(iter.into_remainder(), scalar);
     }
 
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    if is_x86_feature_detected!("avx2") {
-        unsafe {
-            let l_tbl = _mm256_lddqu_si256(GF256_SIMD_MUL_TABLE_LOW[scalar as usize].as_ptr() as *const _);
-            let h_tbl = _mm256_lddqu_si256(GF256_SIMD_MUL_TABLE_HIGH[scalar as usize].as_ptr() as *const _);
-            let l_mask = _mm256_set1_epi8(0x0f);
+    #[target_feature(enable = "gfni,avx512f,avx512bw")]
+    pub unsafe fn inplace_add_vectors(vec_dst: &mut [u8], vec_src: &[u8]) {
+        let mut iter_dst = vec_dst.chunks_exact_mut(LANE_BYTE_LEN);
+        let mut iter_src = vec_src.chunks_exact(LANE_BYTE_LEN);
 
-            let mut add_vec_iter = add_into_vec.chunks_exact_mut(2 * GF256_HALF_ORDER);
-            let mut mul_vec_iter = mul_vec.chunks_exact(2 * GF256_HALF_ORDER);
+        for (chunk_dst, chunk_src) in iter_dst.by_ref().zip(iter_src.by_ref()) {
+            unsafe {
+                let chunk_dst_simd = _mm512_loadu_si512(chunk_dst.as_ptr() as *const _);
+                let chunk_src_simd = _mm512_loadu_si512(chunk_src.as_ptr() as *const _);
+                let chunk_result = _mm512_xor_si512(chunk_dst_simd, chunk_src_simd);
 
-            for (add_vec_chunk, mul_vec_chunk) in add_vec_iter.by_ref().zip(mul_vec_iter.by_ref()) {
-                let mul_vec_chunk_simd = _mm256_lddqu_si256(mul_vec_chunk.as_ptr() as *const _);
+                _mm512_storeu_si512(chunk_dst.as_mut_ptr() as *mut _, chunk_result);
+            }
+        }
 
-                let chunk_simd_lo = _mm256_and_si256(mul_vec_chunk_simd, l_mask);
-                let chunk_simd_lo = _mm256_shuffle_epi8(l_tbl, chunk_simd_lo);
+        scalar::inplace_add_vectors(iter_dst.into_remainder(), iter_src.remainder());
+    }
 
-                let chunk_simd_hi = _mm256_srli_epi64(mul_vec_chunk_simd, 4);
-                let chunk_simd_hi = _mm256_and_si256(chunk_simd_hi, l_mask);
-                let chunk_simd_hi = _mm256_shuffle_epi8(h_tbl, chunk_simd_hi);
+    #[target_feature(enable = "gfni,avx512f,avx512bw")]
+    pub unsafe fn mul_vec_by_scalar_then_add_into_vec(add_into_vec: &mut [u8], mul_vec: &[u8], scalar: u8) {
+        let matrix = _mm512_set1_epi64(GF256_GFNI_AFFINE_MATRICES[scalar as usize] as i64);
 
-                let scaled_res = _mm256_xor_si256(chunk_simd_lo, chunk_simd_hi);
+        let mut add_vec_iter = add_into_vec.chunks_exact_mut(LANE_BYTE_LEN);
+        let mut mul_vec_iter = mul_vec.chunks_exact(LANE_BYTE_LEN);
 
-                let add_vec_chunk_simd = _mm256_lddqu_si256(add_vec_chunk.as_ptr() as *const _);
-                let accum_res = _mm256_xor_si256(add_vec_chunk_simd, scaled_res);
+        for (add_vec_chunk, mul_vec_chunk) in add_vec_iter.by_ref().zip(mul_vec_iter.by_ref()) {
+            unsafe {
+                let mul_vec_chunk_simd = _mm512_loadu_si512(mul_vec_chunk.as_ptr() as *const _);
+                let scaled_res = _mm512_gf2p8affine_epi64_epi8::<0>(mul_vec_chunk_simd, matrix);
 
-                _mm256_storeu_si256(add_vec_chunk.as_mut_ptr() as *mut _, accum_res);
+                let add_vec_chunk_simd = _mm512_loadu_si512(add_vec_chunk.as_ptr() as *const _);
+                let accum_res = _mm512_xor_si512(add_vec_chunk_simd, scaled_res);
+
+                _mm512_storeu_si512(add_vec_chunk.as_mut_ptr() as *mut _, accum_res);
             }
+        }
+
+        scalar::mul_vec_by_scalar_then_add_into_vec(add_vec_iter.into_remainder(), mul_vec_iter.remainder(), scalar);
+    }
+}
+
+/// Test-only hook for forcing the GF(2^8) vector arithmetic routines to dispatch to a specific SIMD backend, which
+/// is supported by the running CPU, disabling the higher ones. It can be used for checking that all backends
+/// produce byte-for-byte identical results.
+///
+/// The backend can be forced for the whole test run, by setting `RLNC_FORCE_SIMD_BACKEND` environment variable
+/// to one of `avx512gfni`, `avx2`, `ssse3`, `neon` or `scalar`. Forcing is process-wide, so that it also applies to `rayon` worker threads.
+/// For the same reason, tests force a backend only through a `Guard`, obtained using `exclusive`, so that they don't
+/// interleave, when run on parallel threads.
+#[cfg(test)]
+pub(crate) mod forced_backend {
+    use super::SimdBackend;
+    use std::sync::{
+        Mutex, MutexGuard, PoisonError,
+        atomic::{AtomicU8, Ordering},
+    };
+
+    const FORCE_SIMD_BACKEND_ENV_VAR: &str = "RLNC_FORCE_SIMD_BACKEND";
+
+    const UNINITIALIZED: u8 = u8::MAX;
+    const NOT_FORCED: u8 = u8::MAX - 1;
+
+    static FORCED_SIMD_BACKEND: AtomicU8 = AtomicU8::new(UNINITIALIZED);
+
+    /// Serializes tests forcing a SIMD backend.
+    static FORCING_LOCK: Mutex<()> = Mutex::new(());
+
+    /// Exclusive right to force the SIMD backend, held by a test for as long as it forces backends. The backend forced
+    /// before taking it is restored once it's dropped, including when the test panics.
+    pub struct Guard {
+        previously_forced_backend: Option<SimdBackend>,
+        _lock: MutexGuard<'static, ()>,
+    }
 
-            add_vec_iter
-                .into_remainder()
-                .iter_mut()
-                .zip(mul_vec_iter.remainder().iter().map(|&src_symbol| Gf256::mul_const(src_symbol, scalar)))
-                .for_each(|(res, scaled)| {
-                    *res ^= scaled;
-                });
+    impl Guard {
+        /// Forces the specified SIMD backend, or lifts the restriction, if `None` is passed.
+        ///
+        /// # Panics
+        /// Panics if the running CPU doesn't support the specified backend.
+        pub fn set(&self, backend: Option<SimdBackend>) {
+            set(backend);
         }
+    }
 
-        return;
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            set(self.previously_forced_backend);
+        }
     }
 
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    if is_x86_feature_detected!("ssse3") {
-        unsafe {
-            let l_tbl = _mm_lddqu_si128(GF256_SIMD_MUL_TABLE_LOW[scalar as usize].as_ptr() as *const _);
-            let h_tbl = _mm_lddqu_si128(GF256_SIMD_MUL_TABLE_HIGH[scalar as usize].as_ptr() as *const _);
-            let l_mask = _mm_set1_epi8(0x0f);
+    /// Waits until no other test forces a SIMD backend, returning the right to force one. A test, which panicked while
+    /// holding it, doesn't prevent others from taking it, as its guard restores the backend while unwinding.
+    pub fn exclusive() -> Guard {
+        let lock = FORCING_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
 
-            let mut add_vec_iter = add_into_vec.chunks_exact_mut(GF256_HALF_ORDER);
-            let mut mul_vec_iter = mul_vec.chunks_exact(GF256_HALF_ORDER);
+        Guard {
+            previously_forced_backend: get(),
+            _lock: lock,
+        }
+    }
 
-            for (add_vec_chunk, mul_vec_chunk) in add_vec_iter.by_ref().zip(mul_vec_iter.by_ref()) {
-                let mul_vec_chunk_simd = _mm_lddqu_si128(mul_vec_chunk.as_ptr() as *const _);
+    /// Returns all SIMD backends supported by the running CPU, fastest first.
+    pub fn available() -> Vec<SimdBackend> {
+        let mut backends = Vec::with_capacity(6);
 
-                let chunk_simd_lo = _mm_and_si128(mul_vec_chunk_simd, l_mask);
-                let chunk_simd_lo = _mm_shuffle_epi8(l_tbl, chunk_simd_lo);
+        #[cfg(all(feature = "gfni", not(feature = "compact"), any(target_arch = "x86", target_arch = "x86_64")))]
+        if super::gfni::is_supported() {
+            backends.push(SimdBackend::Avx512Gfni);
+        }
 
-                let chunk_simd_hi = _mm_srli_epi64(mul_vec_chunk_simd, 4);
-                let chunk_simd_hi = _mm_and_si128(chunk_simd_hi, l_mask);
-                let chunk_simd_hi = _mm_shuffle_epi8(h_tbl, chunk_simd_hi);
+        #[cfg(all(not(feature = "compact"), any(target_arch = "x86", target_arch = "x86_64")))]
+        if is_cpu_feature_detected!("avx2") {
+            backends.push(SimdBackend::Avx2);
+        }
 
-                let scaled_res = _mm_xor_si128(chunk_simd_lo, chunk_simd_hi);
+        #[cfg(all(not(feature = "compact"), any(target_arch = "x86", target_arch = "x86_64")))]
+        if is_cpu_feature_detected!("ssse3") {
+            backends.push(SimdBackend::Ssse3);
+        }
 
-                let add_vec_chunk_simd = _mm_lddqu_si128(add_vec_chunk.as_ptr() as *const _);
-                let accum_res = _mm_xor_si128(add_vec_chunk_simd, scaled_res);
+        #[cfg(all(not(feature = "compact"), target_arch = "aarch64"))]
+        if is_cpu_feature_detected!("neon") {
+            backends.push(SimdBackend::Neon);
+        }
 
-                _mm_storeu_si128(add_vec_chunk.as_mut_ptr() as *mut _, accum_res);
+        #[cfg(all(not(feature = "compact"), target_arch = "wasm32", target_feature = "simd128"))]
+        backends.push(SimdBackend::Simd128);
+
+        backends.push(SimdBackend::Scalar);
+        backends
+    }
+
+    /// Returns the forced SIMD backend, if any.
+    pub fn get() -> Option<SimdBackend> {
+        match FORCED_SIMD_BACKEND.load(Ordering::Relaxed) {
+            UNINITIALIZED => {
+                let backend = from_env();
+                set(backend);
+                backend
             }
+            encoded => decode(encoded),
+        }
+    }
 
-            add_vec_iter
-                .into_remainder()
-                .iter_mut()
-                .zip(mul_vec_iter.remainder().iter().map(|&src_symbol| Gf256::mul_const(src_symbol, scalar)))
-                .for_each(|(res, scaled)| {
-                    *res ^= scaled;
-                });
+    /// Forces the specified SIMD backend, or lifts the restriction, if `None` is passed.
+    ///
+    /// # Panics
+    /// Panics if the running CPU doesn't support the specified backend.
+    fn set(backend: Option<SimdBackend>) {
+        if let Some(backend) = backend {
+            assert!(available().contains(&backend), "SIMD backend `{backend}` is not supported by the running CPU");
         }
 
-        return;
+        FORCED_SIMD_BACKEND.store(encode(backend), Ordering::Relaxed);
     }
 
-    add_into_vec
-        .iter_mut()
-        .zip(mul_vec.iter().map(|&src_symbol| Gf256::mul_const(src_symbol, scalar)))
-        .for_each(|(res, scaled)| *res ^= scaled);
+    fn from_env() -> Option<SimdBackend> {
+        let backend = std::env::var(FORCE_SIMD_BACKEND_ENV_VAR).ok()?;
+
+        match backend.as_str() {
+            "avx512gfni" => Some(SimdBackend::Avx512Gfni),
+            "avx2" => Some(SimdBackend::Avx2),
+            "ssse3" => Some(SimdBackend::Ssse3),
+            "neon" => Some(SimdBackend::Neon),
+            "simd128" => Some(SimdBackend::Simd128),
+            "scalar" => Some(SimdBackend::Scalar),
+            _ => panic!("Unknown SIMD backend `{backend}` in {FORCE_SIMD_BACKEND_ENV_VAR}, expected one of avx512gfni, avx2, ssse3, neon, simd128 or scalar"),
+        }
+    }
+
+    fn encode(backend: Option<SimdBackend>) -> u8 {
+        backend.map_or(NOT_FORCED, |backend| backend as u8)
+    }
+
+    fn decode(encoded: u8) -> Option<SimdBackend> {
+        SimdBackend::from_u8(encoded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{forced_backend, gf256_inplace_add_vectors, gf256_inplace_mul_vec_by_scalar, gf256_mul_vec_by_scalar_then_add_into_vec, gf256_vec_mat_mul};
+    use crate::common::gf256::Gf256;
+    use rand::Rng;
+
+    #[test]
+    fn prop_test_simd_backends_match_scalar_arithmetic() {
+        const NUM_TEST_ITERATIONS: usize = 1000;
+        const MAX_VEC_BYTE_LEN: usize = 1024;
+
+        let mut rng = rand::rng();
+        let forced_backend_guard = forced_backend::exclusive();
+
+        (0..NUM_TEST_ITERATIONS).for_each(|_| {
+            // Arbitrary lengths exercise both full SIMD lanes and the scalar remainder.
+            let vec_byte_len = rng.random_range(0..=MAX_VEC_BYTE_LEN);
+            let scalar = rng.random::<u8>();
+
+            let vec_a = (0..vec_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+            let vec_b = (0..vec_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+
+            let expected_mul = vec_a.iter().map(|&a| Gf256::mul_const(a, scalar)).collect::<Vec<u8>>();
+            let expected_add = vec_a.iter().zip(&vec_b).map(|(&a, &b)| a ^ b).collect::<Vec<u8>>();
+            let expected_mul_add = vec_a.iter().zip(&vec_b).map(|(&a, &b)| a ^ Gf256::mul_const(b, scalar)).collect::<Vec<u8>>();
+
+            for backend in forced_backend::available() {
+                forced_backend_guard.set(Some(backend));
+
+                let mut computed = vec_a.clone();
+                gf256_inplace_mul_vec_by_scalar(&mut computed, scalar);
+                assert_eq!(computed, expected_mul, "multiplication by scalar on {backend}");
+
+                let mut computed = vec_a.clone();
+                gf256_inplace_add_vectors(&mut computed, &vec_b);
+                assert_eq!(computed, expected_add, "addition on {backend}");
+
+                let mut computed = vec_a.clone();
+                gf256_mul_vec_by_scalar_then_add_into_vec(&mut computed, &vec_b, scalar);
+                assert_eq!(computed, expected_mul_add, "multiplication by scalar then addition on {backend}");
+            }
+        });
+    }
+
+    #[test]
+    fn prop_test_gf256_vec_mat_mul() {
+        const NUM_TEST_ITERATIONS: usize = 1000;
+        const MAX_NUM_ROWS: usize = 64;
+        const MAX_NUM_COLS: usize = 256;
+
+        let mut rng = rand::rng();
+
+        (0..NUM_TEST_ITERATIONS).for_each(|_| {
+            let num_rows = rng.random_range(0..=MAX_NUM_ROWS);
+            let num_cols = rng.random_range(0..=MAX_NUM_COLS);
+
+            let vec = (0..num_rows).map(|_| rng.random()).collect::<Vec<u8>>();
+            let mat = (0..(num_rows * num_cols)).map(|_| rng.random()).collect::<Vec<u8>>();
+
+            let expected = (0..num_cols)
+                .map(|col_idx| {
+                    (0..num_rows)
+                        .fold(Gf256::zero(), |acc, row_idx| {
+                            acc + Gf256::new(vec[row_idx]) * Gf256::new(mat[row_idx * num_cols + col_idx])
+                        })
+                        .get()
+                })
+                .collect::<Vec<u8>>();
+
+            // Garbage in the output buffer must be overwritten.
+            let mut computed = (0..num_cols).map(|_| rng.random()).collect::<Vec<u8>>();
+            gf256_vec_mat_mul(&vec, &mat, &mut computed);
+
+            assert_eq!(computed, expected);
+        });
+    }
 }
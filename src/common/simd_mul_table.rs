@@ -1,4 +1,4 @@
-//! Compile-time executable function, helps you in generating lookup tables, so that you can perform AVX2 and SSSE3
+//! Compile-time executable function, helps you in generating lookup tables, so that you can perform AVX2, SSSE3 and NEON
 //! optimized SIMD vector x single-scalar multiplication over GF(2^8), during RLNC erasure-coding. These table generation
 //! logic is from https://github.com/ceph/gf-complete/blob/a6862d10c9db467148f20eef2c6445ac9afd94d8/src/gf_w8.c#L1100-L1105.
 //!
@@ -36,7 +36,7 @@ const fn generate_gf256_simd_mul_table(is_low_part: bool) -> [[u8; 2 * GF256_HAL
 
     let mut row_idx = 0;
     while row_idx < GF256_ORDER {
-        // First 16 elements should be used for SSSE3 SIMD implementation
+        // First 16 elements should be used for SSSE3 and NEON SIMD implementation
         // of vector multiplication by single scalar over GF(2^8).
 
         let mut col_idx = 0;
@@ -73,3 +73,45 @@ pub const GF256_SIMD_MUL_TABLE_LOW: [[u8; 2 * GF256_HALF_ORDER]; GF256_ORDER] =
 /// https://github.com/ceph/gf-complete/blob/a6862d10c9db467148f20eef2c6445ac9afd94d8/src/gf_w8.c#L1100-L1105.
 /// This table holds `htd->high` part, described in above link.
 pub const GF256_SIMD_MUL_TABLE_HIGH: [[u8; 2 * GF256_HALF_ORDER]; GF256_ORDER] = generate_gf256_simd_mul_table(false);
+
+/// Multiplication by a fixed scalar is linear over GF(2), so that it can be expressed as an 8x8 bit matrix, which is
+/// what the GFNI `GF2P8AFFINEQB` instruction multiplies each byte with. Unlike `GF2P8MULB`, which is hardwired to the
+/// AES polynomial x^8 + x^4 + x^3 + x + 1, this works for the irreducible polynomial used by this crate.
+///
+/// Bit `i` of the product is the parity of `x` masked by byte `7 - i` of the matrix, so that byte `7 - i` must have
+/// bit `j` set iff bit `i` of `scalar * x^j` is set.
+#[cfg(all(feature = "gfni", any(target_arch = "x86", target_arch = "x86_64")))]
+const fn generate_gf256_gfni_affine_matrices() -> [u64; GF256_ORDER] {
+    let mut matrices = [0u64; GF256_ORDER];
+
+    let mut scalar = 0;
+    while scalar < GF256_ORDER {
+        let mut matrix = 0u64;
+
+        let mut bit_idx = 0;
+        while bit_idx < u8::BITS as usize {
+            let product = Gf256::mul_const(scalar as u8, 1u8 << bit_idx);
+
+            let mut row_idx = 0;
+            while row_idx < u8::BITS as usize {
+                if (product >> row_idx) & 1 == 1 {
+                    matrix |= 1u64 << (8 * (7 - row_idx) + bit_idx);
+                }
+
+                row_idx += 1;
+            }
+
+            bit_idx += 1;
+        }
+
+        matrices[scalar] = matrix;
+        scalar += 1;
+    }
+
+    matrices
+}
+
+/// GFNI optimized SIMD multiplication over GF(2^8) uses this table, holding the 8x8 bit matrix of multiplication by
+/// each scalar, in the layout expected by `GF2P8AFFINEQB`.
+#[cfg(all(feature = "gfni", any(target_arch = "x86", target_arch = "x86_64")))]
+pub const GF256_GFNI_AFFINE_MATRICES: [u64; GF256_ORDER] = generate_gf256_gfni_affine_matrices();
@@ -0,0 +1,45 @@
+//! CRC-32 checksum, as used by `Encoder::code_checked` and `Decoder::decode_checked`, for detecting coded pieces
+//! corrupted in transit, when the link layer doesn't already guarantee integrity.
+
+/// Reversed form of the CRC-32 (IEEE 802.3) generator polynomial 0x04C11DB7.
+const CRC32_REVERSED_POLYNOMIAL: u32 = 0xedb8_8320;
+
+/// Lookup table for byte-at-a-time CRC-32 computation, generated at compile-time.
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+
+    let mut byte = 0;
+    while byte < table.len() {
+        let mut crc = byte as u32;
+
+        let mut bit_idx = 0;
+        while bit_idx < u8::BITS {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ CRC32_REVERSED_POLYNOMIAL } else { crc >> 1 };
+            bit_idx += 1;
+        }
+
+        table[byte] = crc;
+        byte += 1;
+    }
+
+    table
+};
+
+/// Computes CRC-32 (IEEE 802.3, same as the one used by zlib, gzip and PNG) checksum of `bytes`.
+pub fn crc32(bytes: &[u8]) -> u32 {
+    !bytes
+        .iter()
+        .fold(u32::MAX, |crc, &byte| (crc >> 8) ^ CRC32_TABLE[((crc ^ byte as u32) & 0xff) as usize])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::crc32;
+
+    #[test]
+    fn test_crc32_known_answers() {
+        assert_eq!(crc32(&[]), 0);
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(crc32(b"The quick brown fox jumps over the lazy dog"), 0x414f_a339);
+    }
+}
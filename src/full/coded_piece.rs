@@ -0,0 +1,362 @@
+use super::consts::GENERATION_TAG_BYTE_LEN;
+use crate::RLNCError;
+use alloc::vec::Vec;
+
+/// A full coded piece i.e. coding vector followed by the coded data, optionally prefixed by a generation tag,
+/// along with the RLNC configuration it was produced with.
+///
+/// Carrying the configuration along with the bytes lets the decoder reject a piece, produced by a differently
+/// parameterized encoder or recoder, by looking at its type, instead of slicing the bytes with wrong lengths.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(try_from = "UncheckedCodedPiece"))]
+pub struct CodedPiece {
+    bytes: Vec<u8>,
+    num_pieces_coded_together: usize,
+    piece_byte_len: usize,
+    tagged: bool,
+}
+
+impl CodedPiece {
+    /// Wraps bytes, which are already known to be of valid length, as produced by `Encoder` or `Recoder`.
+    pub(crate) fn new_unchecked(bytes: Vec<u8>, num_pieces_coded_together: usize, piece_byte_len: usize, tagged: bool) -> CodedPiece {
+        CodedPiece {
+            bytes,
+            num_pieces_coded_together,
+            piece_byte_len,
+            tagged,
+        }
+    }
+
+    /// Parses bytes of an untagged full coded piece, e.g. received over the network, for the RLNC configuration
+    /// where `num_pieces_coded_together` pieces of `piece_byte_len` bytes each are coded together.
+    ///
+    /// # Returns
+    /// Returns `Ok(CodedPiece)` on success.
+    /// Returns `Err(RLNCError::PieceCountZero)` if `num_pieces_coded_together` is zero.
+    /// Returns `Err(RLNCError::PieceLengthZero)` if `piece_byte_len` is zero.
+    /// Returns `Err(RLNCError::InvalidPieceLength)` if `bytes` is not `num_pieces_coded_together + piece_byte_len` bytes long.
+    pub fn from_bytes(bytes: Vec<u8>, num_pieces_coded_together: usize, piece_byte_len: usize) -> Result<CodedPiece, RLNCError> {
+        Self::parse(bytes, num_pieces_coded_together, piece_byte_len, false)
+    }
+
+    /// Same as `Self::from_bytes`, but for a full coded piece prefixed by a generation tag, as produced by an
+    /// encoder created using `Encoder::new_tagged`.
+    ///
+    /// # Returns
+    /// Same as `Self::from_bytes`, but `bytes` must be `GENERATION_TAG_BYTE_LEN` bytes longer.
+    pub fn from_tagged_bytes(bytes: Vec<u8>, num_pieces_coded_together: usize, piece_byte_len: usize) -> Result<CodedPiece, RLNCError> {
+        Self::parse(bytes, num_pieces_coded_together, piece_byte_len, true)
+    }
+
+    fn parse(bytes: Vec<u8>, num_pieces_coded_together: usize, piece_byte_len: usize, tagged: bool) -> Result<CodedPiece, RLNCError> {
+        if num_pieces_coded_together == 0 {
+            return Err(RLNCError::PieceCountZero);
+        }
+        if piece_byte_len == 0 {
+            return Err(RLNCError::PieceLengthZero);
+        }
+
+        let generation_tag_byte_len = if tagged { GENERATION_TAG_BYTE_LEN } else { 0 };
+        let expected_byte_len = generation_tag_byte_len + num_pieces_coded_together + piece_byte_len;
+        if bytes.len() != expected_byte_len {
+            return Err(RLNCError::InvalidPieceLength {
+                expected: expected_byte_len,
+                got: bytes.len(),
+            });
+        }
+
+        Ok(Self::new_unchecked(bytes, num_pieces_coded_together, piece_byte_len, tagged))
+    }
+
+    /// Number of pieces coded together, which is same as the length of the coding vector.
+    pub fn get_num_pieces_coded_together(&self) -> usize {
+        self.num_pieces_coded_together
+    }
+
+    /// Byte length of the coded data.
+    pub fn get_piece_byte_len(&self) -> usize {
+        self.piece_byte_len
+    }
+
+    /// Generation tag, the piece is prefixed with, if any.
+    pub fn generation_tag(&self) -> Option<u32> {
+        self.tagged.then(|| {
+            let mut generation_tag = [0u8; GENERATION_TAG_BYTE_LEN];
+            generation_tag.copy_from_slice(&self.bytes[..GENERATION_TAG_BYTE_LEN]);
+
+            u32::from_le_bytes(generation_tag)
+        })
+    }
+
+    /// Coding vector, the coded data was produced with.
+    pub fn coding_vector(&self) -> &[u8] {
+        &self.untagged_bytes()[..self.num_pieces_coded_together]
+    }
+
+    /// Coded data i.e. linear combination of source pieces, as specified by the coding vector.
+    pub fn data(&self) -> &[u8] {
+        &self.untagged_bytes()[self.num_pieces_coded_together..]
+    }
+
+    /// Coding vector followed by the coded data, without the generation tag, if any.
+    pub(crate) fn untagged_bytes(&self) -> &[u8] {
+        if self.tagged { &self.bytes[GENERATION_TAG_BYTE_LEN..] } else { &self.bytes }
+    }
+
+    /// Checks that the piece was produced for the RLNC configuration, where `num_pieces_coded_together` pieces, each of
+    /// `piece_byte_len` bytes, are coded together, and that it carries a generation tag, if and only if `tagged` is set.
+    ///
+    /// # Returns
+    /// Returns `Err(RLNCError::TaggingMismatch)` if the piece is tagged, while `tagged` is not set, or the other way around.
+    /// Returns `Err(RLNCError::CodingVectorLengthMismatch)` if the piece was coded together with another number of pieces.
+    /// Returns `Err(RLNCError::InvalidPieceLength)`, carrying the full coded piece byte lengths, including the generation
+    /// tag, if any, if the piece was coded with another piece byte length.
+    pub(crate) fn check_configuration(&self, num_pieces_coded_together: usize, piece_byte_len: usize, tagged: bool) -> Result<(), RLNCError> {
+        if self.tagged != tagged {
+            return Err(RLNCError::TaggingMismatch);
+        }
+        if self.num_pieces_coded_together != num_pieces_coded_together {
+            return Err(RLNCError::CodingVectorLengthMismatch {
+                expected: num_pieces_coded_together,
+                got: self.num_pieces_coded_together,
+            });
+        }
+        if self.piece_byte_len != piece_byte_len {
+            let generation_tag_byte_len = if tagged { GENERATION_TAG_BYTE_LEN } else { 0 };
+            return Err(RLNCError::InvalidPieceLength {
+                expected: generation_tag_byte_len + num_pieces_coded_together + piece_byte_len,
+                got: self.bytes.len(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Serialized form of the full coded piece, to be sent over the network, which can be parsed back using
+    /// `Self::from_bytes` or `Self::from_tagged_bytes`.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Same as `Self::as_bytes`, but consumes the coded piece, without copying.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+/// Error for `byte_len` bytes of concatenated pieces, e.g. full coded pieces or frames, which is not a multiple of
+/// `piece_byte_len`, reporting `byte_len` along with the multiple of `piece_byte_len` nearest to it, so that both a
+/// truncated last piece and a few trailing bytes are told apart at a glance.
+pub(crate) fn concatenated_pieces_length_error(byte_len: usize, piece_byte_len: usize) -> RLNCError {
+    let trailing_byte_len = byte_len % piece_byte_len;
+    let expected = if trailing_byte_len < piece_byte_len - trailing_byte_len {
+        byte_len - trailing_byte_len
+    } else {
+        byte_len.saturating_add(piece_byte_len - trailing_byte_len)
+    };
+
+    RLNCError::InvalidPieceLength { expected, got: byte_len }
+}
+
+impl IntoIterator for CodedPiece {
+    type Item = u8;
+    type IntoIter = alloc::vec::IntoIter<u8>;
+
+    /// Iterates over the serialized form of the full coded piece.
+    fn into_iter(self) -> Self::IntoIter {
+        self.bytes.into_iter()
+    }
+}
+
+/// A borrowed, untagged, full coded piece i.e. coding vector followed by the coded data, for allocation-free inspection
+/// of pieces which are merely passing through, e.g. at a pure forwarding relay, which forwards them unchanged.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CodedPieceRef<'a> {
+    bytes: &'a [u8],
+    num_pieces_coded_together: usize,
+}
+
+impl<'a> CodedPieceRef<'a> {
+    /// Borrows bytes of an untagged full coded piece, whose coding vector is `num_pieces_coded_together` bytes long,
+    /// followed by at least one byte of coded data.
+    ///
+    /// # Returns
+    /// Returns `Ok(CodedPieceRef)` on success.
+    /// Returns `Err(RLNCError::PieceCountZero)` if `num_pieces_coded_together` is zero.
+    /// Returns `Err(RLNCError::PieceLengthTooShort)` if `bytes` is not longer than `num_pieces_coded_together`.
+    pub fn new(bytes: &'a [u8], num_pieces_coded_together: usize) -> Result<CodedPieceRef<'a>, RLNCError> {
+        if num_pieces_coded_together == 0 {
+            return Err(RLNCError::PieceCountZero);
+        }
+        if bytes.len() <= num_pieces_coded_together {
+            return Err(RLNCError::PieceLengthTooShort);
+        }
+
+        Ok(CodedPieceRef {
+            bytes,
+            num_pieces_coded_together,
+        })
+    }
+
+    /// Number of pieces coded together, which is same as the length of the coding vector.
+    pub fn get_num_pieces_coded_together(&self) -> usize {
+        self.num_pieces_coded_together
+    }
+
+    /// Coding vector, the coded data was produced with.
+    pub fn coding_vector(&self) -> &'a [u8] {
+        &self.bytes[..self.num_pieces_coded_together]
+    }
+
+    /// Coded data i.e. linear combination of source pieces, as specified by the coding vector.
+    pub fn data(&self) -> &'a [u8] {
+        &self.bytes[self.num_pieces_coded_together..]
+    }
+
+    /// Whether the coding vector is entirely zero, which no encoder or recoder produces, so that such a piece is
+    /// useless to any decoder, and not worth forwarding.
+    pub fn is_zero_vector(&self) -> bool {
+        self.coding_vector().iter().all(|&symbol| symbol == 0)
+    }
+
+    /// Borrowed bytes of the full coded piece, to be forwarded unchanged.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl<'a> From<&'a CodedPiece> for CodedPieceRef<'a> {
+    /// Borrows an owned coded piece, without its generation tag, if any.
+    fn from(coded_piece: &'a CodedPiece) -> Self {
+        CodedPieceRef {
+            bytes: coded_piece.untagged_bytes(),
+            num_pieces_coded_together: coded_piece.num_pieces_coded_together,
+        }
+    }
+}
+
+/// Deserialized, but not yet validated, `CodedPiece`.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct UncheckedCodedPiece {
+    bytes: Vec<u8>,
+    num_pieces_coded_together: usize,
+    piece_byte_len: usize,
+    tagged: bool,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<UncheckedCodedPiece> for CodedPiece {
+    type Error = RLNCError;
+
+    fn try_from(piece: UncheckedCodedPiece) -> Result<Self, Self::Error> {
+        Self::parse(piece.bytes, piece.num_pieces_coded_together, piece.piece_byte_len, piece.tagged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CodedPiece, CodedPieceRef, concatenated_pieces_length_error};
+    use crate::RLNCError;
+
+    #[test]
+    fn test_coded_piece_from_bytes() {
+        let coded_piece = CodedPiece::from_bytes(vec![1, 2, 3, 4, 5], 2, 3).expect("Parsing valid coded piece must not fail");
+        assert_eq!(coded_piece.coding_vector(), [1, 2]);
+        assert_eq!(coded_piece.data(), [3, 4, 5]);
+        assert_eq!(coded_piece.generation_tag(), None);
+        assert_eq!(coded_piece.as_bytes(), [1, 2, 3, 4, 5]);
+
+        let tagged_coded_piece =
+            CodedPiece::from_tagged_bytes(vec![0xef, 0xbe, 0xad, 0xde, 1, 2, 3, 4, 5], 2, 3).expect("Parsing valid coded piece must not fail");
+        assert_eq!(tagged_coded_piece.coding_vector(), [1, 2]);
+        assert_eq!(tagged_coded_piece.data(), [3, 4, 5]);
+        assert_eq!(tagged_coded_piece.generation_tag(), Some(0xdeadbeef));
+        assert_eq!(tagged_coded_piece.into_bytes(), [0xef, 0xbe, 0xad, 0xde, 1, 2, 3, 4, 5]);
+
+        assert_eq!(
+            CodedPiece::from_bytes(vec![1, 2, 3, 4], 2, 3),
+            Err(RLNCError::InvalidPieceLength { expected: 5, got: 4 })
+        );
+        assert_eq!(
+            CodedPiece::from_tagged_bytes(vec![1, 2, 3, 4, 5], 2, 3),
+            Err(RLNCError::InvalidPieceLength { expected: 9, got: 5 })
+        );
+        assert_eq!(CodedPiece::from_bytes(vec![1, 2, 3], 0, 3), Err(RLNCError::PieceCountZero));
+        assert_eq!(CodedPiece::from_bytes(vec![1, 2], 2, 0), Err(RLNCError::PieceLengthZero));
+    }
+    #[test]
+    fn test_coded_piece_ref() {
+        let bytes = [1u8, 2, 3, 4, 5];
+
+        let coded_piece_ref = CodedPieceRef::new(&bytes, 2).expect("Borrowing valid coded piece must not fail");
+        assert_eq!(coded_piece_ref.get_num_pieces_coded_together(), 2);
+        assert_eq!(coded_piece_ref.coding_vector(), [1, 2]);
+        assert_eq!(coded_piece_ref.data(), [3, 4, 5]);
+        assert_eq!(coded_piece_ref.as_bytes(), bytes);
+        assert!(!coded_piece_ref.is_zero_vector());
+
+        let zero_vector_bytes = [0u8, 0, 3, 4, 5];
+        assert!(
+            CodedPieceRef::new(&zero_vector_bytes, 2)
+                .expect("Borrowing valid coded piece must not fail")
+                .is_zero_vector()
+        );
+
+        assert_eq!(CodedPieceRef::new(&bytes, 0), Err(RLNCError::PieceCountZero));
+        assert_eq!(CodedPieceRef::new(&bytes, 5), Err(RLNCError::PieceLengthTooShort));
+
+        let tagged_coded_piece =
+            CodedPiece::from_tagged_bytes(vec![0xef, 0xbe, 0xad, 0xde, 1, 2, 3, 4, 5], 2, 3).expect("Parsing valid coded piece must not fail");
+        assert_eq!(CodedPieceRef::from(&tagged_coded_piece), coded_piece_ref);
+    }
+
+    #[test]
+    fn test_concatenated_pieces_length_error() {
+        // Test case 1: Few trailing bytes, after whole pieces
+        assert_eq!(
+            concatenated_pieces_length_error(3 * 17 + 1, 17),
+            RLNCError::InvalidPieceLength { expected: 51, got: 52 }
+        );
+
+        // Test case 2: Truncated last piece
+        assert_eq!(
+            concatenated_pieces_length_error(3 * 17 - 1, 17),
+            RLNCError::InvalidPieceLength { expected: 51, got: 50 }
+        );
+
+        // Test case 3: Less than half a piece
+        assert_eq!(concatenated_pieces_length_error(8, 17), RLNCError::InvalidPieceLength { expected: 0, got: 8 });
+    }
+
+    #[test]
+    fn test_coded_piece_check_configuration() {
+        let coded_piece = CodedPiece::from_bytes(vec![1, 2, 3, 4, 5], 2, 3).expect("Parsing valid coded piece must not fail");
+        let tagged_coded_piece =
+            CodedPiece::from_tagged_bytes(vec![0xef, 0xbe, 0xad, 0xde, 1, 2, 3, 4, 5], 2, 3).expect("Parsing valid coded piece must not fail");
+
+        // Test case 1: Matching configuration
+        assert_eq!(coded_piece.check_configuration(2, 3, false), Ok(()));
+        assert_eq!(tagged_coded_piece.check_configuration(2, 3, true), Ok(()));
+
+        // Test case 2: Tagged piece for untagged configuration, or the other way around
+        assert_eq!(coded_piece.check_configuration(2, 3, true), Err(RLNCError::TaggingMismatch));
+        assert_eq!(tagged_coded_piece.check_configuration(2, 3, false), Err(RLNCError::TaggingMismatch));
+
+        // Test case 3: Same full coded piece byte length, but split differently between coding vector and coded data
+        assert_eq!(
+            coded_piece.check_configuration(3, 2, false),
+            Err(RLNCError::CodingVectorLengthMismatch { expected: 3, got: 2 })
+        );
+
+        // Test case 4: Different piece byte length is reported using full coded piece byte lengths
+        assert_eq!(
+            coded_piece.check_configuration(2, 4, false),
+            Err(RLNCError::InvalidPieceLength { expected: 6, got: 5 })
+        );
+        assert_eq!(
+            tagged_coded_piece.check_configuration(2, 4, true),
+            Err(RLNCError::InvalidPieceLength { expected: 10, got: 9 })
+        );
+    }
+}
@@ -2,4 +2,28 @@
 /// zeros to make all data chunks equal sized. At decoding time, we can use this boundary marker to determine how far is the original data.
 /// Once this boundary marker is encountered, there could be zero or more zero bytes following it. The number of zero bytes is determined by the
 /// length of the original data and number of chunks.
+///
+/// Original data may itself contain, or end with, this byte, possibly followed by zeros, without any ambiguity. As only zero bytes
+/// are ever appended after the boundary marker, the last occurrence of the boundary marker in padded data is always the one which
+/// was appended by the encoder.
 pub const BOUNDARY_MARKER: u8 = 0x81;
+
+/// Byte length of the generation tag, prepended to each full coded piece, when coding with generation tagging enabled.
+/// The tag is a `u32`, serialized in little-endian byte order.
+pub const GENERATION_TAG_BYTE_LEN: usize = u32::BITS as usize / 8;
+
+/// Byte length of the checksum, appended to each full coded piece, when coding with `Encoder::code_checked`. The checksum
+/// is a CRC-32 of the full coded piece, serialized in little-endian byte order.
+pub const CHECKSUM_BYTE_LEN: usize = u32::BITS as usize / 8;
+
+/// Byte length of the window base index, prepended to each full coded piece, produced by a sliding window encoder.
+/// The index is a `u64`, serialized in little-endian byte order.
+pub const WINDOW_BASE_INDEX_BYTE_LEN: usize = u64::BITS as usize / 8;
+
+/// Number of distinct, non-zero, GF(2^8) evaluation points, which is also the maximum number of coded pieces, produced
+/// by `Encoder::code_vandermonde`, any `piece_count` of which are guaranteed to be linearly independent.
+pub const VANDERMONDE_EVALUATION_POINT_COUNT: usize = u8::MAX as usize;
+
+/// Upper bound on the byte length of any buffer, holding pieces, which is also the largest allocation Rust permits. Parameters
+/// needing a larger buffer are rejected with `RLNCError::ParametersTooLarge`, instead of overflowing `usize` arithmetic.
+pub const MAX_BUFFER_BYTE_LEN: usize = isize::MAX as usize;
@@ -1,23 +1,47 @@
-use super::consts::BOUNDARY_MARKER;
-use crate::{RLNCError, full::decoder_matrix::DecoderMatrix};
+use super::{
+    checksum::crc32,
+    coded_piece::{CodedPiece, concatenated_pieces_length_error},
+    consts::{BOUNDARY_MARKER, CHECKSUM_BYTE_LEN, GENERATION_TAG_BYTE_LEN},
+    encoder::{Encoder, checked_buffer_byte_len_within},
+    padding::unpad,
+    recoder::Recoder,
+    seed::coding_vector_from_seed,
+};
+use crate::{
+    RLNCError,
+    common::{gf256::Gf256, simd::gf256_inplace_mul_vec_by_scalar},
+};
+
+pub use crate::full::decoder_matrix::{DecoderMatrix, MatrixLayout};
+use alloc::{vec, vec::Vec};
 
 /// Random Linear Network Code (RLNC) Decoder.
 ///
 /// This struct manages the received coded pieces and performs Gaussian
 /// elimination to recover the original data.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(try_from = "UncheckedDecoder"))]
 pub struct Decoder {
     /// Stores the coefficient matrix and coded data rows concatenated.
     /// Each row is a coded piece: `[coefficients | data_piece]`.
-    pub matrix: DecoderMatrix,
+    pub(crate) matrix: DecoderMatrix,
     /// The byte length of each original data piece.
     piece_byte_len: usize,
     /// The minimum number of useful coded pieces required to decode.
     required_piece_count: usize,
     /// The total number of coded pieces received so far.
     received_piece_count: usize,
-    /// The number of linearly independent pieces received so far.
+    /// The number of linearly independent pieces received so far, including rows merged in from other decoders.
     useful_piece_count: usize,
+    /// The number of linearly independent rows merged in from other decoders, using `Self::merge`, so far.
+    merged_piece_count: usize,
+    /// The number of pieces received in a row, since the last useful one, none of which was useful.
+    useless_streak: usize,
+    /// The generation tag, each received piece must be prefixed with, if the decoder is tagged.
+    expected_generation_tag: Option<u32>,
+    /// Whether each source piece is already handed over by `Self::drain_solved_pieces`, in which case its row is
+    /// removed from the matrix, while the coefficients of its column are zero in every remaining row.
+    drained_pieces: Vec<bool>,
 }
 
 impl Decoder {
@@ -32,8 +56,15 @@ impl Decoder {
     }
 
     /// Each full coded piece consists of `self.get_num_pieces_coded_together()` random coefficients, appended by corresponding encoded piece of `self.get_piece_byte_len()` bytes.
+    /// If the decoder is tagged, the full coded piece is additionally prefixed by a `GENERATION_TAG_BYTE_LEN` -bytes generation tag.
     pub fn get_full_coded_piece_byte_len(&self) -> usize {
-        self.get_num_pieces_coded_together() + self.get_piece_byte_len()
+        let generation_tag_byte_len = if self.expected_generation_tag.is_some() { GENERATION_TAG_BYTE_LEN } else { 0 };
+        generation_tag_byte_len + self.get_num_pieces_coded_together() + self.get_piece_byte_len()
+    }
+
+    /// Generation tag each received piece must be prefixed with, if the decoder was created using `Self::new_tagged`.
+    pub fn get_expected_generation_tag(&self) -> Option<u32> {
+        self.expected_generation_tag
     }
 
     /// Total number of pieces received by the decoder so far.
@@ -41,16 +72,95 @@ impl Decoder {
         self.received_piece_count
     }
 
-    /// Number of useful pieces received by the decoder so far.
+    /// Number of useful pieces received by the decoder so far, including rows merged in from other decoders, using `Self::merge`.
     pub fn get_useful_piece_count(&self) -> usize {
         self.useful_piece_count
     }
 
+    /// Number of consecutive pieces, received since the last useful one, none of which increased the rank of the decoder's
+    /// matrix i.e. all of them got rejected with `RLNCError::PieceNotUseful`. Resets to zero on every useful piece.
+    ///
+    /// A long streak means the peer(s) we are receiving from only hold a stale subspace, so that it's time to request
+    /// pieces from a different peer, e.g. after 20 useless pieces in a row.
+    pub fn useless_streak(&self) -> usize {
+        self.useless_streak
+    }
+
+    /// Current rank of the decoder's matrix, i.e. number of linearly independent pieces received so far, including
+    /// source pieces already drained using `Self::drain_solved_pieces`, which reaches
+    /// `self.get_num_pieces_coded_together()` once decoding is complete. Handy for progress reporting.
+    pub fn rank(&self) -> usize {
+        self.matrix.rank() + self.num_drained_pieces()
+    }
+
+    /// Number of source pieces already handed over by `Self::drain_solved_pieces`, whose rows got removed from the matrix.
+    fn num_drained_pieces(&self) -> usize {
+        self.drained_pieces.iter().filter(|&&is_drained| is_drained).count()
+    }
+
+    /// Checks whether a coding vector has a non-zero coefficient for any source piece, already drained using
+    /// `Self::drain_solved_pieces`.
+    fn references_drained_piece(&self, coding_vector: &[u8]) -> bool {
+        coding_vector
+            .iter()
+            .zip(&self.drained_pieces)
+            .any(|(&coefficient, &is_drained)| is_drained && coefficient != 0)
+    }
+
+    /// Memory layout of the decoder's matrix, as chosen when creating the decoder, using `Self::with_matrix_layout`.
+    pub fn get_matrix_layout(&self) -> MatrixLayout {
+        self.matrix.layout()
+    }
+
     /// Number of pieces remaining to be received by the decoder for successful decoding.
     pub fn get_remaining_piece_count(&self) -> usize {
         self.get_num_pieces_coded_together() - self.get_useful_piece_count()
     }
 
+    /// Fraction of the useful pieces, needed for decoding, received so far, i.e. `self.get_useful_piece_count()` divided
+    /// by `self.get_num_pieces_coded_together()`, ranging from 0.0, for a fresh decoder, to 1.0, once decoding is complete.
+    pub fn progress(&self) -> f64 {
+        self.get_useful_piece_count() as f64 / self.get_num_pieces_coded_together() as f64
+    }
+
+    /// Fraction of the received pieces, which turned out to be useful, i.e. `self.get_useful_piece_count()`, less rows
+    /// merged in using `Self::merge`, divided by `self.get_received_piece_count()`, ranging from 0.0, when none of them
+    /// was useful, to 1.0, when all of them were. It is 1.0 for a fresh decoder, as no received piece is wasted yet.
+    pub fn coding_efficiency(&self) -> f64 {
+        if self.get_received_piece_count() == 0 {
+            return 1.0;
+        }
+
+        (self.get_useful_piece_count() - self.merged_piece_count) as f64 / self.get_received_piece_count() as f64
+    }
+
+    /// Estimated number of pieces, which still need to be received for successful decoding, i.e.
+    /// `self.get_remaining_piece_count()` scaled up by the observed uselessness rate, assuming that the future pieces
+    /// turn out to be useful as often as the received ones did. It is 0.0 once decoding is complete, while it is infinite
+    /// if none of the received pieces was useful.
+    pub fn estimated_remaining_piece_count(&self) -> f64 {
+        if self.is_already_decoded() {
+            return 0.0;
+        }
+
+        self.get_remaining_piece_count() as f64 / self.coding_efficiency()
+    }
+
+    /// Probability that the next coded piece, with a uniformly random GF(2^8) coding vector, turns out to be useful, i.e.
+    /// `1 - 256^-(k - r)`, for `k = self.get_num_pieces_coded_together()` and `r = self.rank()`. It stays very close to
+    /// 1.0 until the very last piece, where it drops to 255/256, and it is 0.0 once decoding is complete.
+    ///
+    /// Unlike `Self::coding_efficiency`, it doesn't depend on the pieces received so far, only on the field size, which
+    /// makes it a handy baseline for rate control, e.g. when deciding how many pieces to request from a peer.
+    pub fn expected_usefulness(&self) -> f64 {
+        // Beyond 7 missing pieces, 256^-(k - r) is below half the f64 epsilon, so that the probability rounds to 1.0.
+        const MAX_SIGNIFICANT_MISSING_RANK: usize = 7;
+        const FIELD_SIZE: f64 = 256.0;
+
+        let missing_rank = self.get_remaining_piece_count().min(MAX_SIGNIFICANT_MISSING_RANK + 1);
+        1.0 - (0..missing_rank).fold(1.0, |acc, _| acc / FIELD_SIZE)
+    }
+
     /// Creates a new `Decoder` instance.
     ///
     /// # Arguments
@@ -62,7 +172,20 @@ impl Decoder {
     /// Returns `Ok(Decoder)` on successful creation.
     /// Returns `Err(RLNCError::PieceLengthZero)` if `piece_byte_len` is zero.
     /// Returns `Err(RLNCError::PieceCountZero)` if `required_piece_count` is zero.
+    /// Returns `Err(RLNCError::ParametersTooLarge)` if the decoder's matrix, at full rank, would be longer than `isize::MAX` bytes.
     pub fn new(piece_byte_len: usize, required_piece_count: usize) -> Result<Decoder, RLNCError> {
+        Self::with_matrix_layout(piece_byte_len, required_piece_count, MatrixLayout::default())
+    }
+
+    /// Creates a new `Decoder` instance, same as `Self::new`, but storing received pieces in the specified
+    /// `MatrixLayout`, instead of the default one. Layout only affects decoding performance, not its outcome.
+    ///
+    /// # Returns
+    /// Returns `Ok(Decoder)` on successful creation.
+    /// Returns `Err(RLNCError::PieceLengthZero)` if `piece_byte_len` is zero.
+    /// Returns `Err(RLNCError::PieceCountZero)` if `required_piece_count` is zero.
+    /// Returns `Err(RLNCError::ParametersTooLarge)` if the decoder's matrix, at full rank, would be longer than `isize::MAX` bytes.
+    pub fn with_matrix_layout(piece_byte_len: usize, required_piece_count: usize, layout: MatrixLayout) -> Result<Decoder, RLNCError> {
         if piece_byte_len == 0 {
             return Err(RLNCError::PieceLengthZero);
         }
@@ -71,278 +194,2696 @@ impl Decoder {
         }
 
         Ok(Decoder {
-            matrix: DecoderMatrix::new(required_piece_count, piece_byte_len),
+            matrix: DecoderMatrix::with_layout(required_piece_count, piece_byte_len, layout)?,
             piece_byte_len,
             required_piece_count,
             received_piece_count: 0,
             useful_piece_count: 0,
+            merged_piece_count: 0,
+            useless_streak: 0,
+            expected_generation_tag: None,
+            drained_pieces: vec![false; required_piece_count],
         })
     }
 
+    /// Creates a new `Decoder` instance, same as `Self::new`, but expecting each full coded piece to be prefixed by
+    /// `expected_generation_tag`, serialized as `GENERATION_TAG_BYTE_LEN` little-endian bytes, as produced by an
+    /// encoder created using `Encoder::new_tagged`. Pieces carrying any other tag are rejected, before touching
+    /// the decoder's matrix.
+    ///
+    /// # Returns
+    /// Returns `Ok(Decoder)` on successful creation.
+    /// Returns `Err(RLNCError::PieceLengthZero)` if `piece_byte_len` is zero.
+    /// Returns `Err(RLNCError::PieceCountZero)` if `required_piece_count` is zero.
+    /// Returns `Err(RLNCError::ParametersTooLarge)` if the decoder's matrix, at full rank, would be longer than `isize::MAX` bytes.
+    pub fn new_tagged(piece_byte_len: usize, required_piece_count: usize, expected_generation_tag: u32) -> Result<Decoder, RLNCError> {
+        let mut decoder = Self::new(piece_byte_len, required_piece_count)?;
+        decoder.expected_generation_tag = Some(expected_generation_tag);
+
+        Ok(decoder)
+    }
+
+    /// Creates a new `Decoder` instance, same as `Self::new` does, but refusing parameters, which would make the decoder's
+    /// matrix, at full rank, longer than `max_buffer_byte_len` bytes, before allocating it, so that a decoder configured
+    /// by an untrusted peer, e.g. using `frame::decode_header`, can't be made to allocate unbounded memory.
+    ///
+    /// # Returns
+    /// Same as `Self::new`, while `Err(RLNCError::ParametersTooLarge)` is returned if the decoder's matrix, at full rank,
+    /// would be longer than `max_buffer_byte_len` bytes.
+    pub fn new_bounded(piece_byte_len: usize, required_piece_count: usize, max_buffer_byte_len: usize) -> Result<Decoder, RLNCError> {
+        if piece_byte_len == 0 {
+            return Err(RLNCError::PieceLengthZero);
+        }
+        if required_piece_count == 0 {
+            return Err(RLNCError::PieceCountZero);
+        }
+
+        let full_coded_piece_byte_len = required_piece_count.checked_add(piece_byte_len).ok_or(RLNCError::ParametersTooLarge)?;
+        checked_buffer_byte_len_within(required_piece_count, full_coded_piece_byte_len, max_buffer_byte_len)?;
+
+        Self::new(piece_byte_len, required_piece_count)
+    }
+
+    /// Creates a new `Decoder` instance, set up for decoding pieces coded by `encoder`, reading both the piece byte length
+    /// and the piece count off it, so that a decoder can't end up configured using parameters of two different encoders.
+    /// If `encoder` is tagged, the decoder expects its generation tag, same as one created using `Self::new_tagged` does.
+    ///
+    /// Note, decoders on the receiving side usually learn these parameters from the wire, e.g. using `frame::decode_header`.
+    ///
+    /// # Returns
+    /// Returns `Ok(Decoder)` on successful creation.
+    /// Returns `Err(RLNCError::ParametersTooLarge)` if the decoder's matrix, at full rank, would be longer than `isize::MAX` bytes.
+    pub fn matching(encoder: &Encoder) -> Result<Decoder, RLNCError> {
+        match encoder.get_generation_tag() {
+            Some(generation_tag) => Self::new_tagged(encoder.get_piece_byte_len(), encoder.get_piece_count(), generation_tag),
+            None => Self::new(encoder.get_piece_byte_len(), encoder.get_piece_count()),
+        }
+    }
+
     /// Decodes a single full coded piece and adds it to the decoder's matrix.
     ///
     /// Performs Gaussian elimination to reduce the matrix and checks if the
     /// added piece was linearly independent of the existing ones.
     ///
     /// # Arguments
-    /// * `full_coded_piece` - A slice containing the coefficients followed by
-    ///   the coded data for one piece. Its length must be `required_piece_count + piece_byte_len`.
+    /// * `full_coded_piece` - Coefficients followed by the coded data for one piece, as produced by an encoder or a
+    ///   recoder with the same configuration as this decoder. If the decoder is tagged, it must carry the expected
+    ///   generation tag.
     ///
     /// # Returns
     /// Returns `Ok(())` if the piece was useful and added successfully.
     /// Returns `Err(RLNCError::ReceivedAllPieces)` if decoding is already complete.
     /// Returns `Err(RLNCError::PieceNotUseful)` if the piece was linearly
     /// dependent on the already received useful pieces.
-    /// Returns `Err(RLNCError::InvalidPieceLength)` if the `full_coded_piece` has an unexpected length.
-    pub fn decode(&mut self, full_coded_piece: &[u8]) -> Result<(), RLNCError> {
+    /// Returns `Err(RLNCError::CodingVectorLengthMismatch)` if the `full_coded_piece` was coded together with a different
+    /// number of pieces, or `Err(RLNCError::InvalidPieceLength)` if it was coded with a different piece byte length.
+    /// Returns `Err(RLNCError::TaggingMismatch)` if the `full_coded_piece` is tagged, while the decoder is not, or the
+    /// other way around.
+    /// Returns `Err(RLNCError::StaleGeneration)` if the `full_coded_piece` carries an unexpected generation tag.
+    /// Returns `Err(RLNCError::ZeroCodingVector)` if the coding vector of `full_coded_piece` is entirely zero. This check
+    /// runs before the piece is reduced against the decoder's matrix, so that a malformed piece, e.g. sent by a malicious
+    /// or buggy peer, is told apart from a legitimately linearly dependent one. Such a piece is not counted as received.
+    pub fn decode(&mut self, full_coded_piece: &CodedPiece) -> Result<(), RLNCError> {
+        if self.is_already_decoded() {
+            return Err(RLNCError::ReceivedAllPieces);
+        }
+        full_coded_piece.check_configuration(
+            self.get_num_pieces_coded_together(),
+            self.get_piece_byte_len(),
+            self.expected_generation_tag.is_some(),
+        )?;
+
+        self.decode_full_coded_piece(full_coded_piece.as_bytes())
+    }
+
+    /// Same as `Self::decode`, but decoding a serialized full coded piece, e.g. received over the C ABI, without
+    /// wrapping it in a `CodedPiece`.
+    #[cfg(feature = "capi")]
+    pub(crate) fn decode_bytes(&mut self, full_coded_piece: &[u8]) -> Result<(), RLNCError> {
         if self.is_already_decoded() {
             return Err(RLNCError::ReceivedAllPieces);
         }
         if full_coded_piece.len() != self.get_full_coded_piece_byte_len() {
-            return Err(RLNCError::InvalidPieceLength);
+            return Err(RLNCError::InvalidPieceLength {
+                expected: self.get_full_coded_piece_byte_len(),
+                got: full_coded_piece.len(),
+            });
         }
 
-        let rank_before = self.matrix.rank();
+        self.decode_full_coded_piece(full_coded_piece)
+    }
 
-        unsafe { self.matrix.add_row(full_coded_piece).unwrap_unchecked().rref() };
-        self.received_piece_count += 1;
+    /// Creates a new `Decoder`, just like `Self::new` does, and decodes a batch of previously received full coded pieces,
+    /// e.g. read back from disk, concatenated in `pieces`, stopping early, once full rank is reached. Pieces which are not
+    /// useful, including ones with an all-zero coding vector, are skipped.
+    ///
+    /// # Arguments
+    /// * `piece_byte_len` - The byte length of each original data piece.
+    /// * `required_piece_count` - The minimum number of useful coded pieces required for decoding.
+    /// * `pieces` - Concatenated untagged full coded pieces, each of `required_piece_count + piece_byte_len` bytes.
+    ///
+    /// # Returns
+    /// Returns `Ok((Decoder, usize))`, the decoder and the number of useful pieces it absorbed, on success.
+    /// Returns `Err(RLNCError::PieceLengthZero)` if `piece_byte_len` is zero.
+    /// Returns `Err(RLNCError::PieceCountZero)` if `required_piece_count` is zero.
+    /// Returns `Err(RLNCError::ParametersTooLarge)` if the decoder's matrix, at full rank, would be longer than `isize::MAX` bytes.
+    /// Returns `Err(RLNCError::InvalidPieceLength)` if length of `pieces` is not a multiple of the full coded piece byte length.
+    pub fn from_pieces(piece_byte_len: usize, required_piece_count: usize, pieces: &[u8]) -> Result<(Decoder, usize), RLNCError> {
+        let mut decoder = Decoder::new(piece_byte_len, required_piece_count)?;
+
+        let full_coded_piece_byte_len = decoder.get_full_coded_piece_byte_len();
+        if pieces.len() % full_coded_piece_byte_len != 0 {
+            return Err(concatenated_pieces_length_error(pieces.len(), full_coded_piece_byte_len));
+        }
 
-        let rank_after = self.matrix.rank();
+        for full_coded_piece in pieces.chunks_exact(full_coded_piece_byte_len) {
+            if decoder.is_already_decoded() {
+                break;
+            }
 
-        // If the rank didn't increase, the piece was not useful.
-        if rank_before == rank_after {
-            Err(RLNCError::PieceNotUseful)
-        } else {
-            self.useful_piece_count = rank_after;
-            Ok(())
+            match decoder.decode_full_coded_piece(full_coded_piece) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) | Err(RLNCError::ZeroCodingVector) => {}
+                Err(e) => return Err(e),
+            }
         }
+
+        let useful_piece_count = decoder.get_useful_piece_count();
+        Ok((decoder, useful_piece_count))
     }
 
-    /// Checks if the decoder has received enough linearly independent pieces
-    /// to recover the original data.
-    pub fn is_already_decoded(&self) -> bool {
-        self.matrix.rank() == self.required_piece_count
+    /// Decodes a full coded piece followed by its checksum, as produced by `Encoder::code_checked`, verifying the
+    /// checksum before the piece gets anywhere near the decoder's matrix. The checksum is stripped, before the piece
+    /// is decoded just like `Self::decode` does.
+    ///
+    /// # Arguments
+    /// * `checked_coded_piece` - Full coded piece, possibly prefixed by the generation tag, followed by `CHECKSUM_BYTE_LEN`
+    ///   -bytes CRC-32 checksum of it. Its length must be `self.get_full_coded_piece_byte_len() + CHECKSUM_BYTE_LEN`.
+    ///
+    /// # Returns
+    /// Returns `Err(RLNCError::ChecksumMismatch)` if the checksum doesn't match the piece, which is then not counted as received.
+    /// Otherwise, same as `Self::decode`.
+    pub fn decode_checked(&mut self, checked_coded_piece: &[u8]) -> Result<(), RLNCError> {
+        if self.is_already_decoded() {
+            return Err(RLNCError::ReceivedAllPieces);
+        }
+        if checked_coded_piece.len() != self.get_full_coded_piece_byte_len() + CHECKSUM_BYTE_LEN {
+            return Err(RLNCError::InvalidPieceLength {
+                expected: self.get_full_coded_piece_byte_len() + CHECKSUM_BYTE_LEN,
+                got: checked_coded_piece.len(),
+            });
+        }
+
+        let (full_coded_piece, checksum) = checked_coded_piece.split_at(self.get_full_coded_piece_byte_len());
+        if checksum != crc32(full_coded_piece).to_le_bytes() {
+            return Err(RLNCError::ChecksumMismatch);
+        }
+
+        self.decode_full_coded_piece(full_coded_piece)
     }
 
-    /// Recovers and returns the original data byte vector if decoding is complete.
+    /// Same as `Self::decode`, but invoking `on_solved(source_piece_index, source_piece)`
+    /// for each source piece, which became uniquely determined by adding this piece, in ascending order of source index,
+    /// so that it can be piped out, e.g. to disk, without polling `Self::recovered_piece_indices` after each piece.
     ///
-    /// Assumes the matrix is in Reduced Row Echelon Form (RREF) and extracts
-    /// the original data pieces corresponding to the identity matrix part
-    /// of the coefficient matrix. It also handles the boundary marker to
-    /// determine the original data length and trims padding.
+    /// Source pieces, which were already solved before this call, are never reported again, while the boundary marker
+    /// and padding are not stripped from the last source piece, same as `Self::solvable_pieces`. It doesn't interact with
+    /// `Self::drain_solved_pieces`, which keeps handing over the same pieces.
+    ///
+    /// # Arguments
+    /// * `full_coded_piece` - Full coded piece, validated the same way `Self::decode` does. If the decoder is tagged, it
+    ///   must carry the expected generation tag.
+    /// * `on_solved` - Invoked with the index of each newly solved source piece, along with its `self.get_piece_byte_len()` bytes.
     ///
     /// # Returns
-    /// Returns `Ok(Vec<u8>)` containing the decoded data if successful.
-    /// Returns `Err(RLNCError::NotAllPiecesReceivedYet)` if not enough useful
-    /// pieces have been received.
-    /// Returns `Err(RLNCError::InvalidDecodedDataFormat)` if the extracted data
-    /// does not follow the expected format (e.g., boundary marker issues).
-    pub fn get_decoded_data(self) -> Result<Vec<u8>, RLNCError> {
-        if !self.is_already_decoded() {
-            return Err(RLNCError::NotAllPiecesReceivedYet);
+    /// Same as `Self::decode`. `on_solved` is invoked only on success.
+    pub fn decode_with_callback<F: FnMut(usize, &[u8])>(&mut self, full_coded_piece: &CodedPiece, mut on_solved: F) -> Result<(), RLNCError> {
+        if self.is_already_decoded() {
+            return Err(RLNCError::ReceivedAllPieces);
         }
+        full_coded_piece.check_configuration(
+            self.get_num_pieces_coded_together(),
+            self.get_piece_byte_len(),
+            self.expected_generation_tag.is_some(),
+        )?;
 
-        let full_coded_piece_len = self.required_piece_count + self.piece_byte_len;
-        let mut decoded_data = Vec::with_capacity(self.piece_byte_len * self.required_piece_count);
+        let solved_before = self.recovered_piece_indices();
+        self.decode_full_coded_piece(full_coded_piece.as_bytes())?;
 
-        // Iterate over the useful rows (which should be the decoded original pieces)
-        // and extract the data part from each row.
-        self.matrix.extract_data().chunks_exact(full_coded_piece_len).for_each(|full_decoded_piece| {
-            // The data part of the row starts after the coefficient columns.
-            let decoded_piece = &full_decoded_piece[self.required_piece_count..];
-            decoded_data.extend_from_slice(decoded_piece);
+        // Rows are sorted by their pivot column, so that newly solved pieces are visited in ascending order of source index.
+        (0..self.matrix.rank()).for_each(|row_idx| {
+            if let Some(piece_idx) = self.solved_piece_index_of_row(row_idx) {
+                if solved_before.binary_search(&piece_idx).is_err() {
+                    on_solved(piece_idx, &self.solved_piece_of_row(row_idx, piece_idx));
+                }
+            }
         });
 
-        // Find the boundary marker to trim padding.
-        let last_index_of_decoded_data = decoded_data.len() - 1;
-        let boundary_marker_rev_index = decoded_data
+        Ok(())
+    }
+
+    /// Decodes a full coded piece, of valid length, possibly prefixed by the generation tag, checking the tag and
+    /// rejecting an all-zero coding vector, before adding it to the decoder's matrix.
+    fn decode_full_coded_piece(&mut self, full_coded_piece: &[u8]) -> Result<(), RLNCError> {
+        let untagged_full_coded_piece = self.strip_generation_tag(full_coded_piece)?;
+        if untagged_full_coded_piece[..self.get_num_pieces_coded_together()]
             .iter()
-            .rev()
-            .position(|&byte| byte == BOUNDARY_MARKER)
-            .unwrap_or(last_index_of_decoded_data);
-        let boundary_marker_index = last_index_of_decoded_data - boundary_marker_rev_index;
+            .all(|&coefficient| coefficient == 0)
+        {
+            return Err(RLNCError::ZeroCodingVector);
+        }
 
-        if boundary_marker_index == 0 {
-            return Err(RLNCError::InvalidDecodedDataFormat);
+        self.add_full_coded_piece(untagged_full_coded_piece)
+    }
+
+    /// Checks whether a full coded piece would be useful, i.e. linearly independent of the pieces received so far,
+    /// without adding it to the decoder's matrix. Only a copy of its coding vector is reduced against the pivots
+    /// of the decoder's matrix, so that this is much cheaper than `Self::decode`.
+    ///
+    /// # Arguments
+    /// * `full_coded_piece` - Full coded piece, validated the same way `Self::decode` does. If the decoder is tagged, it
+    ///   must carry the expected generation tag.
+    ///
+    /// # Returns
+    /// Returns `Ok(true)` if decoding the piece would increase the rank of the decoder's matrix.
+    /// Returns `Ok(false)` if the piece is linearly dependent on the already received useful pieces, including
+    /// when decoding is already complete.
+    /// Returns `Err(RLNCError::CodingVectorLengthMismatch)` if the `full_coded_piece` was coded together with a different
+    /// number of pieces, or `Err(RLNCError::InvalidPieceLength)` if it was coded with a different piece byte length.
+    /// Returns `Err(RLNCError::TaggingMismatch)` if the `full_coded_piece` is tagged, while the decoder is not, or the
+    /// other way around.
+    /// Returns `Err(RLNCError::StaleGeneration)` if the `full_coded_piece` carries an unexpected generation tag.
+    /// Returns `Err(RLNCError::PieceAlreadyDrained)` if the piece has a non-zero coefficient for a drained source piece,
+    /// in which case `Self::decode` would reject it too.
+    pub fn is_useful(&self, full_coded_piece: &CodedPiece) -> Result<bool, RLNCError> {
+        full_coded_piece.check_configuration(
+            self.get_num_pieces_coded_together(),
+            self.get_piece_byte_len(),
+            self.expected_generation_tag.is_some(),
+        )?;
+
+        let full_coded_piece = self.strip_generation_tag(full_coded_piece.as_bytes())?;
+        if self.is_already_decoded() {
+            return Ok(false);
         }
-        if decoded_data[(boundary_marker_index + 1)..].iter().any(|&byte| byte != 0) {
-            return Err(RLNCError::InvalidDecodedDataFormat);
+
+        let coding_vector = &full_coded_piece[..self.get_num_pieces_coded_together()];
+        if self.references_drained_piece(coding_vector) {
+            return Err(RLNCError::PieceAlreadyDrained);
         }
 
-        decoded_data.truncate(boundary_marker_index);
-        Ok(decoded_data)
+        Ok(self.matrix.is_independent(coding_vector))
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::{Decoder, RLNCError};
-    use crate::full::encoder::Encoder;
-    use rand::Rng;
+    /// Explains why a full coded piece is not useful, by expressing its coding vector as a linear combination of the
+    /// coding vectors of the decoder's matrix, i.e. rows of `Self::coefficient_matrix`, without mutating the decoder.
+    ///
+    /// # Arguments
+    /// * `full_coded_piece` - Full coded piece, validated the same way `Self::decode` does. If the decoder is tagged, it
+    ///   must carry the expected generation tag.
+    ///
+    /// # Returns
+    /// Returns `Ok(Some(multipliers))`, holding a coefficient per row of `Self::coefficient_matrix`, if the piece is
+    /// linearly dependent on the already received useful pieces. The i-th coefficient is the multiplier of the i-th row
+    /// of `Self::coefficient_matrix`, whose pivot column is `self.pivot_columns()[i]`. A zero multiplier means the row
+    /// does not take part in the combination.
+    /// Returns `Ok(None)` if the piece is useful.
+    /// Returns any error returned by `Self::is_useful`, e.g. `Err(RLNCError::InvalidPieceLength)`, for a piece which
+    /// `Self::decode` would reject as malformed.
+    pub fn dependency_coefficients(&self, full_coded_piece: &CodedPiece) -> Result<Option<Vec<u8>>, RLNCError> {
+        full_coded_piece.check_configuration(
+            self.get_num_pieces_coded_together(),
+            self.get_piece_byte_len(),
+            self.expected_generation_tag.is_some(),
+        )?;
 
-    #[test]
-    fn test_decoder_new_invalid_inputs() {
-        // Test case 1: piece_byte_len is zero
-        let piece_byte_len_zero = 0;
-        let required_piece_count_non_zero = 10;
+        let full_coded_piece = self.strip_generation_tag(full_coded_piece.as_bytes())?;
+        let coding_vector = &full_coded_piece[..self.get_num_pieces_coded_together()];
+        if self.references_drained_piece(coding_vector) {
+            return Err(RLNCError::PieceAlreadyDrained);
+        }
 
-        let result_piece_len_zero = Decoder::new(piece_byte_len_zero, required_piece_count_non_zero);
-        assert!(result_piece_len_zero.is_err());
-        assert_eq!(result_piece_len_zero.expect_err("Expected PieceLengthZero error"), RLNCError::PieceLengthZero);
+        Ok(self.matrix.dependency_coefficients(coding_vector))
+    }
 
-        // Test case 2: required_piece_count is zero
-        let piece_byte_len_non_zero = 10;
-        let required_piece_count_zero = 0;
+    /// Decodes a single coded piece, produced by `Encoder::code_with_seed`, regenerating its coding vector from
+    /// `seed`, using `seed::coding_vector_from_seed`, before adding it to the decoder's matrix.
+    ///
+    /// # Arguments
+    /// * `seed` - The seed, the coded piece was produced with.
+    /// * `coded_piece` - The coded data for one piece, without coding vector. Its length must be `self.get_piece_byte_len()`.
+    ///   If the decoder is tagged, it must be prefixed by the expected generation tag.
+    ///
+    /// # Returns
+    /// Same as `Self::decode`.
+    pub fn decode_with_seed(&mut self, seed: u64, coded_piece: &[u8]) -> Result<(), RLNCError> {
+        if self.is_already_decoded() {
+            return Err(RLNCError::ReceivedAllPieces);
+        }
+        if coded_piece.len() != self.get_full_coded_piece_byte_len() - self.get_num_pieces_coded_together() {
+            return Err(RLNCError::InvalidPieceLength {
+                expected: self.get_full_coded_piece_byte_len() - self.get_num_pieces_coded_together(),
+                got: coded_piece.len(),
+            });
+        }
 
-        let result_piece_count_zero = Decoder::new(piece_byte_len_non_zero, required_piece_count_zero);
-        assert!(result_piece_count_zero.is_err());
-        assert_eq!(result_piece_count_zero.expect_err("Expected PieceCountZero error"), RLNCError::PieceCountZero);
+        let coded_piece = self.strip_generation_tag(coded_piece)?;
 
-        // Test case 3: Both piece_byte_len and required_piece_count are zero
-        let piece_byte_len_both_zero = 0;
-        let required_piece_count_both_zero = 0;
+        let mut full_coded_piece = coding_vector_from_seed(seed, self.get_num_pieces_coded_together());
+        if full_coded_piece.iter().all(|&coefficient| coefficient == 0) {
+            return Err(RLNCError::ZeroCodingVector);
+        }
 
-        let result_both_zero = Decoder::new(piece_byte_len_both_zero, required_piece_count_both_zero);
-        assert!(result_both_zero.is_err());
-        assert_eq!(
-            result_both_zero.expect_err("Expected PieceLengthZero error for both zero inputs"),
-            RLNCError::PieceLengthZero
-        );
+        full_coded_piece.extend_from_slice(coded_piece);
 
-        // Test case 4: Valid input
-        let piece_byte_len_valid = 10;
-        let required_piece_count_valid = 5;
-        let result_valid = Decoder::new(piece_byte_len_valid, required_piece_count_valid);
-        assert!(result_valid.is_ok());
+        self.add_full_coded_piece(&full_coded_piece)
     }
 
-    #[test]
-    fn test_decoder_decode_invalid_piece_length() {
-        let mut rng = rand::rng();
-
-        let data_byte_len = 1024usize;
-        let piece_count = 32usize;
-        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
-        let encoder = Encoder::new(data, piece_count).expect("Failed to create Encoder for decode invalid length test");
-
-        let piece_byte_len = encoder.get_piece_byte_len();
-        let required_piece_count = encoder.get_piece_count();
-        let full_coded_piece_byte_len = encoder.get_full_coded_piece_byte_len();
+    /// Decodes a single coded piece, whose coding vector covers only a prefix of the source pieces, e.g. sent by a sender
+    /// which only knows about the first `coding_vector.len()` source pieces yet, in a layered or generational scheme.
+    /// Coefficients of the remaining source pieces are implicitly zero, so the coding vector is zero-extended to
+    /// `self.get_num_pieces_coded_together()` coefficients, before the piece is added to the decoder's matrix.
+    ///
+    /// # Arguments
+    /// * `coding_vector` - Coefficients of the first `coding_vector.len()` source pieces, which must be at most
+    ///   `self.get_num_pieces_coded_together()`.
+    /// * `data` - The coded data for one piece, without coding vector. Its length must be `self.get_piece_byte_len()`.
+    ///   If the decoder is tagged, it must be prefixed by the expected generation tag.
+    ///
+    /// # Returns
+    /// Same as `Self::decode`, and
+    /// Returns `Err(RLNCError::CodingVectorLengthMismatch)` if `coding_vector` is longer than `self.get_num_pieces_coded_together()`.
+    pub fn decode_partial(&mut self, coding_vector: &[u8], data: &[u8]) -> Result<(), RLNCError> {
+        if self.is_already_decoded() {
+            return Err(RLNCError::ReceivedAllPieces);
+        }
+        if coding_vector.len() > self.get_num_pieces_coded_together() {
+            return Err(RLNCError::CodingVectorLengthMismatch {
+                expected: self.get_num_pieces_coded_together(),
+                got: coding_vector.len(),
+            });
+        }
+        if data.len() != self.get_full_coded_piece_byte_len() - self.get_num_pieces_coded_together() {
+            return Err(RLNCError::InvalidPieceLength {
+                expected: self.get_full_coded_piece_byte_len() - self.get_num_pieces_coded_together(),
+                got: data.len(),
+            });
+        }
 
-        let mut decoder = Decoder::new(piece_byte_len, required_piece_count).expect("Failed to create Decoder for decode invalid length test");
+        let data = self.strip_generation_tag(data)?;
+        if coding_vector.iter().all(|&coefficient| coefficient == 0) {
+            return Err(RLNCError::ZeroCodingVector);
+        }
 
-        // Test case 1: Piece length is shorter than expected
-        let short_piece_len = full_coded_piece_byte_len - 1;
-        let short_coded_piece: Vec<u8> = (0..short_piece_len).map(|_| rng.random()).collect();
-        let result_short = decoder.decode(&short_coded_piece);
-        assert!(result_short.is_err());
-        assert_eq!(
-            result_short.expect_err("Expected InvalidPieceLength error for short piece"),
-            RLNCError::InvalidPieceLength
-        );
+        let mut full_coded_piece = Vec::with_capacity(self.get_num_pieces_coded_together() + data.len());
+        full_coded_piece.extend_from_slice(coding_vector);
+        full_coded_piece.resize(self.get_num_pieces_coded_together(), 0);
+        full_coded_piece.extend_from_slice(data);
 
-        // Test case 2: Piece length is longer than expected
-        let long_piece_len = full_coded_piece_byte_len + 1;
-        let long_coded_piece: Vec<u8> = (0..long_piece_len).map(|_| rng.random()).collect();
-        let result_long = decoder.decode(&long_coded_piece);
-        assert!(result_long.is_err());
-        assert_eq!(
-            result_long.expect_err("Expected InvalidPieceLength error for long piece"),
-            RLNCError::InvalidPieceLength
-        );
+        self.add_full_coded_piece(&full_coded_piece)
+    }
 
-        // Test case 3: Piece length is zero
-        let zero_piece: Vec<u8> = Vec::new();
-        let result_zero = decoder.decode(&zero_piece);
-        assert!(result_zero.is_err());
-        assert_eq!(
-            result_zero.expect_err("Expected InvalidPieceLength error for zero-length piece"),
-            RLNCError::InvalidPieceLength
-        );
+    /// Checks the generation tag, if the decoder is tagged, returning the rest of the piece.
+    fn strip_generation_tag<'a>(&self, piece: &'a [u8]) -> Result<&'a [u8], RLNCError> {
+        match self.expected_generation_tag {
+            Some(expected_generation_tag) => {
+                let (generation_tag, rest) = piece.split_at(GENERATION_TAG_BYTE_LEN);
+                if generation_tag != expected_generation_tag.to_le_bytes() {
+                    return Err(RLNCError::StaleGeneration);
+                }
 
-        // Ensure decoder state is unchanged after invalid decode attempts
-        assert_eq!(decoder.get_received_piece_count(), 0);
-        assert_eq!(decoder.get_useful_piece_count(), 0);
-        assert!(!decoder.is_already_decoded());
+                Ok(rest)
+            }
+            None => Ok(piece),
+        }
+    }
 
-        // Test case 4: Valid coded piece - check if state changes
-        let correct_coded_piece = encoder.code(&mut rng);
-        let result_correct = decoder.decode(&correct_coded_piece);
-        assert!(result_correct.is_ok() || matches!(result_correct, Err(RLNCError::PieceNotUseful)));
+    /// Adds an untagged full coded piece, of valid length, to the decoder's matrix, reducing it. A piece having a non-zero
+    /// coefficient for a drained source piece is rejected, without being counted as received, as eliminating that
+    /// coefficient takes the drained piece itself.
+    fn add_full_coded_piece(&mut self, full_coded_piece: &[u8]) -> Result<(), RLNCError> {
+        if self.references_drained_piece(&full_coded_piece[..self.get_num_pieces_coded_together()]) {
+            return Err(RLNCError::PieceAlreadyDrained);
+        }
 
-        // After a valid decode attempt, received_piece_count must increase
-        assert_eq!(decoder.get_received_piece_count(), 1);
+        let is_useful = unsafe { self.matrix.add_row_and_reduce(full_coded_piece).unwrap_unchecked() };
+        self.received_piece_count += 1;
 
-        // If the piece was useful, useful_piece_count will be 1. Otherwise, it remains 0.
-        // Given the small piece_count in this test, it's very likely to be useful.
-        if result_correct.is_ok() {
-            assert_eq!(decoder.get_useful_piece_count(), 1);
-            assert!(!decoder.is_already_decoded()); // Unless piece_count was 1
+        // If the rank didn't increase, the piece was not useful.
+        if is_useful {
+            self.useful_piece_count = self.rank();
+            self.useless_streak = 0;
+            Ok(())
         } else {
-            assert_eq!(decoder.get_useful_piece_count(), 0);
+            self.useless_streak += 1;
+            Err(RLNCError::PieceNotUseful)
         }
     }
 
-    #[test]
-    fn test_decoder_getters() {
-        let mut rng = rand::rng();
+    /// Merges decoding progress of `other` decoder, for the same generation, into this one, so that two peers, each
+    /// having collected a subset of coded pieces, can pool them, without retransmitting every received piece.
+    ///
+    /// Each row of `other`'s matrix, being in reduced row echelon form, is itself a valid full coded piece, so they are
+    /// added to this decoder's matrix one after another, until this decoder reaches full rank. `other` is left as is.
+    /// Merged rows were never received from the network, so they don't count towards `Self::get_received_piece_count`
+    /// nor `Self::useless_streak`, while the useful ones do count towards `Self::get_useful_piece_count`. Rows having
+    /// a non-zero coefficient for a source piece, already drained from this decoder, can't be merged, so they are skipped.
+    ///
+    /// # Returns
+    /// Returns `Ok(usize)`, the number of rows of `other` which increased the rank of this decoder, on success.
+    /// Returns `Err(RLNCError::IncompatibleDecoders)` if the decoders don't share the same piece byte length and
+    /// number of pieces coded together.
+    /// Returns `Err(RLNCError::StaleGeneration)` if both decoders are tagged, but expect different generation tags.
+    pub fn merge(&mut self, other: &Decoder) -> Result<usize, RLNCError> {
+        if self.piece_byte_len != other.piece_byte_len || self.required_piece_count != other.required_piece_count {
+            return Err(RLNCError::IncompatibleDecoders);
+        }
+        if let (Some(expected_generation_tag), Some(other_generation_tag)) = (self.expected_generation_tag, other.expected_generation_tag) {
+            if expected_generation_tag != other_generation_tag {
+                return Err(RLNCError::StaleGeneration);
+            }
+        }
+
+        let mut num_useful_rows = 0;
+        let mut full_coded_piece = Vec::with_capacity(self.required_piece_count + self.piece_byte_len);
+
+        for row_idx in 0..other.matrix.rank() {
+            if self.is_already_decoded() {
+                break;
+            }
+
+            if self.references_drained_piece(other.matrix.coefficients(row_idx)) {
+                continue;
+            }
+
+            full_coded_piece.clear();
+            full_coded_piece.extend_from_slice(other.matrix.coefficients(row_idx));
+            full_coded_piece.extend_from_slice(other.matrix.payload(row_idx));
+
+            if unsafe { self.matrix.add_row_and_reduce(&full_coded_piece).unwrap_unchecked() } {
+                num_useful_rows += 1;
+            }
+        }
+
+        self.useful_piece_count = self.rank();
+        self.merged_piece_count += num_useful_rows;
+        Ok(num_useful_rows)
+    }
+
+    /// Decodes full coded pieces, as they are yielded by `stream`, until the decoder has received enough linearly
+    /// independent pieces to recover the original data. Pieces are pulled from the stream one at a time, only
+    /// after the previous one got decoded, so a slow decoder naturally applies backpressure on the producer.
+    ///
+    /// Pieces which are not useful, including ones with an all-zero coding vector, are silently skipped. The returned
+    /// future doesn't take ownership of the decoder, so that decoded data can be extracted, once it completes.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` once the decoder has reached full rank, including when it was already decoded.
+    /// Returns `Err(RLNCError::NotAllPiecesReceivedYet)` if the stream ends before the decoder reaches full rank.
+    /// Returns any other error returned by `Self::decode`, e.g. `Err(RLNCError::InvalidPieceLength)`, as soon as it happens.
+    #[cfg(feature = "async")]
+    pub async fn decode_stream<S: futures::Stream<Item = CodedPiece>>(&mut self, stream: S) -> Result<(), RLNCError> {
+        use futures::StreamExt;
+
+        let mut stream = core::pin::pin!(stream);
+
+        while !self.is_already_decoded() {
+            let Some(full_coded_piece) = stream.next().await else {
+                return Err(RLNCError::NotAllPiecesReceivedYet);
+            };
+
+            match self.decode(&full_coded_piece) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) | Err(RLNCError::ZeroCodingVector) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Resets the decoder to its freshly constructed state, forgetting all pieces received so far, so that
+    /// it can be reused for decoding another generation of pieces, coded with the same configuration.
+    ///
+    /// Memory already allocated for the decoder's matrix is retained, avoiding reallocation per generation.
+    pub fn reset(&mut self) {
+        self.matrix.clear();
+        self.received_piece_count = 0;
+        self.useful_piece_count = 0;
+        self.merged_piece_count = 0;
+        self.useless_streak = 0;
+        self.drained_pieces.fill(false);
+    }
+
+    /// Resets the decoder, same as `Self::reset` does, while reconfiguring it for decoding another generation of pieces,
+    /// `piece_count` many pieces of `piece_byte_len` bytes each, e.g. the final generation of a stream, which is often
+    /// shorter than the others, so that a single decoder can be reused across all generations of the stream. Memory
+    /// layout and generation tag, if any, are kept as is.
+    ///
+    /// Memory already allocated for the decoder's matrix is retained, so that nothing gets reallocated when the new
+    /// generation is no larger than the ones decoded before, e.g. when sizes match.
+    ///
+    /// ```
+    /// use rlnc::full::{decoder::Decoder, encoder::Encoder};
+    ///
+    /// let mut rng = rand::rng();
+    /// let stream = (0..10_000).map(|byte| byte as u8).collect::<Vec<u8>>();
+    ///
+    /// let mut decoder = Decoder::new(1, 1).expect("Failed to create decoder");
+    /// for generation in stream.chunks(4096) {
+    ///     let encoder = Encoder::new(generation.to_vec(), 16).expect("Failed to create encoder");
+    ///     decoder.reset_with(encoder.get_piece_byte_len(), encoder.get_piece_count()).expect("Failed to reset decoder");
+    ///
+    ///     while !decoder.is_already_decoded() {
+    ///         let _ = decoder.decode(&encoder.code(&mut rng));
+    ///     }
+    ///     assert_eq!(decoder.clone().get_decoded_data().expect("Failed to decode"), generation);
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    /// Returns `Ok(())` once the decoder is reset and reconfigured.
+    /// Returns `Err(RLNCError::PieceLengthZero)` if `piece_byte_len` is zero.
+    /// Returns `Err(RLNCError::PieceCountZero)` if `piece_count` is zero.
+    /// Returns `Err(RLNCError::ParametersTooLarge)` if the decoder's matrix, at full rank, would be longer than `isize::MAX` bytes.
+    /// The decoder is left untouched, on error.
+    pub fn reset_with(&mut self, piece_byte_len: usize, piece_count: usize) -> Result<(), RLNCError> {
+        if piece_byte_len == 0 {
+            return Err(RLNCError::PieceLengthZero);
+        }
+        if piece_count == 0 {
+            return Err(RLNCError::PieceCountZero);
+        }
+
+        self.matrix.reconfigure(piece_count, piece_byte_len)?;
+        self.piece_byte_len = piece_byte_len;
+        self.required_piece_count = piece_count;
+
+        self.reset();
+        self.drained_pieces.resize(piece_count, false);
+
+        Ok(())
+    }
+
+    /// Checks if the decoder has received enough linearly independent pieces
+    /// to recover the original data.
+    pub fn is_already_decoded(&self) -> bool {
+        self.rank() == self.required_piece_count
+    }
+
+    /// Returns the coding vector of each row of the decoder's matrix, in reduced row echelon form, i.e. its
+    /// coefficient matrix, of `self.rank()` rows, less the drained source pieces, whose rows are removed, and
+    /// `self.get_num_pieces_coded_together()` columns. Useful for inspecting the decoding progress, e.g. for debugging
+    /// or visualization.
+    pub fn coefficient_matrix(&self) -> Vec<Vec<u8>> {
+        (0..self.matrix.rank()).map(|row_idx| self.matrix.coefficients(row_idx).to_vec()).collect()
+    }
+
+    /// Returns the pivot column, i.e. the leading non-zero coefficient column, of each row of the decoder's matrix,
+    /// in ascending order. Same as the row indices of `Self::coefficient_matrix`, where the leading coefficient is 1.
+    pub fn pivot_columns(&self) -> Vec<usize> {
+        self.matrix.pivot_cols().to_vec()
+    }
+
+    /// Returns every source piece which is already uniquely determined by the pieces received so far,
+    /// even though the decoder may not yet have reached full rank.
+    ///
+    /// A source piece at index `i` is uniquely determined if and only if the unit vector `e_i` lies in the
+    /// row space of the coefficient matrix, which in reduced row echelon form means some row has exactly one
+    /// non-zero coefficient, at column `i`. The data portion of such a row, scaled by the inverse of that
+    /// coefficient, is the source piece itself.
+    ///
+    /// Unlike `Self::get_decoded_data`, this doesn't require full rank, doesn't strip the boundary marker or
+    /// padding from the last source piece, and returns pieces in ascending order of their source index.
+    /// Once the decoder is fully decoded, all `self.get_num_pieces_coded_together()` pieces are returned, except the
+    /// ones already drained using `Self::drain_solved_pieces`.
+    ///
+    /// # Returns
+    /// A vector of `(source_piece_index, source_piece)` tuples, where each source piece is of `self.get_piece_byte_len()` bytes.
+    pub fn solvable_pieces(&self) -> Vec<(usize, Vec<u8>)> {
+        let mut solved_pieces = (0..self.matrix.rank())
+            .filter_map(|row_idx| {
+                let piece_idx = self.solved_piece_index_of_row(row_idx)?;
+                Some((piece_idx, self.solved_piece_of_row(row_idx, piece_idx)))
+            })
+            .collect::<Vec<(usize, Vec<u8>)>>();
+
+        solved_pieces.sort_unstable_by_key(|&(piece_idx, _)| piece_idx);
+        solved_pieces
+    }
+
+    /// Same as `Self::solvable_pieces`, but returns only the source pieces which got solved since the last call, so that
+    /// each source piece is handed over exactly once, e.g. to a progressive-download pipeline, which writes each piece
+    /// out, as soon as it's recovered, and drops it.
+    ///
+    /// Rows of drained pieces are removed from the decoder's matrix, releasing their memory, so that the decoder only
+    /// keeps the rows still needed to finish decoding. As a solved piece's row is a unit vector, every other row is zero
+    /// at its column, so that nothing else is lost. Drained pieces keep counting towards `Self::rank` and
+    /// `Self::get_useful_piece_count`, while pieces received later, having a non-zero coefficient for a drained piece,
+    /// are rejected with `RLNCError::PieceAlreadyDrained`, as eliminating that coefficient takes the drained piece itself.
+    /// Hence draining suits senders which stop coding over a source piece, once the receiver reports it as solved, e.g.
+    /// in a sliding window scheme. Once some piece is drained, the original data can't be recovered as a whole anymore,
+    /// using `Self::get_decoded_data` and friends. `Self::reset` forgets drained pieces.
+    ///
+    /// # Returns
+    /// A vector of `(source_piece_index, source_piece)` tuples, for newly solved source pieces, in ascending order of
+    /// their source index, where each source piece is of `self.get_piece_byte_len()` bytes.
+    pub fn drain_solved_pieces(&mut self) -> Vec<(usize, Vec<u8>)> {
+        // Rows are sorted by their pivot column, which is the source index of a solved piece, so that newly solved pieces
+        // are collected in ascending order of source index.
+        let (solved_row_idxs, newly_solved_pieces): (Vec<usize>, Vec<(usize, Vec<u8>)>) = (0..self.matrix.rank())
+            .filter_map(|row_idx| {
+                let piece_idx = self.solved_piece_index_of_row(row_idx)?;
+                Some((row_idx, (piece_idx, self.solved_piece_of_row(row_idx, piece_idx))))
+            })
+            .unzip();
+
+        if !solved_row_idxs.is_empty() {
+            newly_solved_pieces.iter().for_each(|&(piece_idx, _)| self.drained_pieces[piece_idx] = true);
+            self.matrix.remove_rows(|row_idx| solved_row_idxs.binary_search(&row_idx).is_ok());
+        }
+
+        newly_solved_pieces
+    }
+
+    /// Returns indices of every source piece which is already uniquely determined by the pieces received so far,
+    /// in ascending order. Same as the indices returned by `Self::solvable_pieces`, without copying the pieces.
+    pub fn recovered_piece_indices(&self) -> Vec<usize> {
+        let mut piece_indices = (0..self.matrix.rank())
+            .filter_map(|row_idx| self.solved_piece_index_of_row(row_idx))
+            .collect::<Vec<usize>>();
+
+        piece_indices.sort_unstable();
+        piece_indices
+    }
+
+    /// Returns the source piece at `index`, if it's already uniquely determined by the pieces received so far.
+    /// Just like `Self::solvable_pieces`, the boundary marker and padding are not stripped from the last source piece.
+    ///
+    /// # Returns
+    /// Returns `Some(source_piece)` of `self.get_piece_byte_len()` bytes, if the source piece is recovered.
+    /// Returns `None` if it's not recovered yet, if it's already drained using `Self::drain_solved_pieces`, or if `index`
+    /// is not less than `self.get_num_pieces_coded_together()`.
+    pub fn get_piece(&self, index: usize) -> Option<Vec<u8>> {
+        (0..self.matrix.rank())
+            .find(|&row_idx| self.solved_piece_index_of_row(row_idx) == Some(index))
+            .map(|row_idx| self.solved_piece_of_row(row_idx, index))
+    }
+
+    /// Returns the index of the source piece stored at `row_idx` -th row of the matrix, if that row has exactly one
+    /// non-zero coefficient.
+    fn solved_piece_index_of_row(&self, row_idx: usize) -> Option<usize> {
+        let mut non_zero_cols = (0..self.required_piece_count).filter(|&col_idx| self.matrix.coefficient(row_idx, col_idx) != Gf256::zero());
+
+        let pivot_col_idx = non_zero_cols.next()?;
+        if non_zero_cols.next().is_some() {
+            return None;
+        }
+
+        Some(pivot_col_idx)
+    }
+
+    /// Returns the source piece stored at `row_idx` -th row of the matrix, whose only non-zero coefficient is at
+    /// column `piece_idx`, scaling it by the inverse of that coefficient.
+    fn solved_piece_of_row(&self, row_idx: usize, piece_idx: usize) -> Vec<u8> {
+        let mut solved_piece = self.matrix.payload(row_idx).to_vec();
+
+        let pivot = self.matrix.coefficient(row_idx, piece_idx);
+        if pivot != Gf256::one() {
+            let pivot_inv = unsafe { pivot.inv().unwrap_unchecked().get() };
+            gf256_inplace_mul_vec_by_scalar(&mut solved_piece, pivot_inv);
+        }
+
+        solved_piece
+    }
+
+    /// Recovers and returns the original data byte vector if decoding is complete.
+    ///
+    /// Assumes the matrix is in Reduced Row Echelon Form (RREF) and extracts
+    /// the original data pieces corresponding to the identity matrix part
+    /// of the coefficient matrix. It also handles the boundary marker to
+    /// determine the original data length and trims padding.
+    ///
+    /// # Returns
+    /// Returns `Ok(Vec<u8>)` containing the decoded data if successful.
+    /// Returns `Err(RLNCError::NotAllPiecesReceivedYet)` if not enough useful
+    /// pieces have been received.
+    /// Returns `Err(RLNCError::InvalidDecodedDataFormat)` if the extracted data
+    /// does not follow the expected format (e.g., boundary marker issues).
+    /// Returns `Err(RLNCError::PieceAlreadyDrained)` if some source pieces were
+    /// drained using `Self::drain_solved_pieces`.
+    pub fn get_decoded_data(self) -> Result<Vec<u8>, RLNCError> {
+        self.get_decoded_data_ref()
+    }
+
+    /// Same as `Self::get_decoded_data`, but borrows the decoder, instead of consuming it, so that its statistics
+    /// can still be inspected, and late-arriving pieces can still be fed to it, after the decoded data is recovered.
+    ///
+    /// # Returns
+    /// Same as `Self::get_decoded_data`.
+    pub fn get_decoded_data_ref(&self) -> Result<Vec<u8>, RLNCError> {
+        self.check_decoded_data_available()?;
+
+        let mut decoded_data = Vec::with_capacity(self.piece_byte_len * self.required_piece_count);
+
+        // Iterate over the useful rows (which should be the decoded original pieces)
+        // and extract the data part from each row.
+        (0..self.matrix.rank()).for_each(|row_idx| {
+            decoded_data.extend_from_slice(self.matrix.payload(row_idx));
+        });
+
+        unpad(&mut decoded_data)?;
+        Ok(decoded_data)
+    }
+
+    /// Same as `Self::get_decoded_data_ref`, but streams the decoded data into `out`, piece by piece, instead of
+    /// concatenating all pieces into a freshly allocated vector, so that a large object can be written to a file or
+    /// a socket, without ever materializing it in memory. Boundary marker and padding are trimmed from the last piece.
+    ///
+    /// # Returns
+    /// Returns `Ok(usize)`, the number of bytes written i.e. the byte length of the original data, on success.
+    /// Returns `Err(RLNCError::NotAllPiecesReceivedYet)` if not enough useful pieces have been received.
+    /// Returns `Err(RLNCError::PieceAlreadyDrained)` if some source pieces were drained using `Self::drain_solved_pieces`.
+    /// Returns `Err(RLNCError::InvalidDecodedDataFormat)` if the decoded data does not follow the expected format,
+    /// in which case nothing is written.
+    /// Returns `Err(RLNCError::DecodedDataWriteFailed)` if writing into `out` fails, in which case some prefix of the
+    /// decoded data may have already been written.
+    #[cfg(feature = "std")]
+    pub fn write_decoded_data<W: std::io::Write>(&self, out: &mut W) -> Result<usize, RLNCError> {
+        self.check_decoded_data_available()?;
+
+        let decoded_data_byte_len = self.decoded_data_byte_len()?;
+        let mut num_bytes_to_write = decoded_data_byte_len;
+
+        for row_idx in 0..self.matrix.rank() {
+            if num_bytes_to_write == 0 {
+                break;
+            }
+
+            let piece = self.matrix.payload(row_idx);
+            let num_bytes_in_piece = num_bytes_to_write.min(piece.len());
+
+            out.write_all(&piece[..num_bytes_in_piece]).map_err(|_| RLNCError::DecodedDataWriteFailed)?;
+            num_bytes_to_write -= num_bytes_in_piece;
+        }
+
+        Ok(decoded_data_byte_len)
+    }
+
+    /// Writes the decoded data straight into `out`, e.g. a memory-mapped output file, each source piece at offset
+    /// `index * self.get_piece_byte_len()`, without assembling the original data into an intermediate vector. Boundary
+    /// marker and padding are trimmed from the last piece, so that `out` needs room for the original data only, while
+    /// bytes of `out` beyond the original data are left untouched.
+    ///
+    /// The decoder is reset afterwards, same as `Self::reset` does, so that it is ready for the next generation.
+    ///
+    /// # Returns
+    /// Returns `Ok(usize)`, the number of bytes written i.e. the byte length of the original data, on success.
+    /// Returns `Err(RLNCError::NotAllPiecesReceivedYet)` if not enough useful pieces have been received.
+    /// Returns `Err(RLNCError::PieceAlreadyDrained)` if some source pieces were drained using `Self::drain_solved_pieces`.
+    /// Returns `Err(RLNCError::InvalidDecodedDataFormat)` if the decoded data does not follow the expected format.
+    /// Returns `Err(RLNCError::OutputBufferTooSmall)` if `out` is shorter than the original data.
+    /// On error, nothing is written, and the decoder is left as it is.
+    pub fn take_into(&mut self, out: &mut [u8]) -> Result<usize, RLNCError> {
+        self.check_decoded_data_available()?;
+
+        let decoded_data_byte_len = self.decoded_data_byte_len()?;
+        if out.len() < decoded_data_byte_len {
+            return Err(RLNCError::OutputBufferTooSmall);
+        }
+
+        // At full rank, the matrix is the identity matrix, so that the row at index i holds the source piece at index i.
+        out[..decoded_data_byte_len]
+            .chunks_mut(self.piece_byte_len)
+            .enumerate()
+            .for_each(|(piece_idx, out_piece)| {
+                out_piece.copy_from_slice(&self.matrix.payload(piece_idx)[..out_piece.len()]);
+            });
+
+        self.reset();
+        Ok(decoded_data_byte_len)
+    }
+
+    /// Returns the byte length of the original data, same as `Encoder::original_data_len`, recovered from the position
+    /// of the boundary marker, so that the exact receive buffer can be allocated before calling
+    /// `Self::write_decoded_data`.
+    ///
+    /// # Returns
+    /// Returns `Some(usize)` once decoding is complete.
+    /// Returns `None` if not enough useful pieces have been received yet, if some source pieces were drained, or if the
+    /// decoded data does not follow the expected format, i.e. when `Self::get_decoded_data_ref` would fail.
+    pub fn original_data_len(&self) -> Option<usize> {
+        self.check_decoded_data_available().ok()?;
+        self.decoded_data_byte_len().ok()
+    }
+
+    /// Checks that decoding is complete, and that none of the source pieces got drained, so that the decoder's matrix is
+    /// the identity matrix, holding the original data as a whole.
+    fn check_decoded_data_available(&self) -> Result<(), RLNCError> {
+        if !self.is_already_decoded() {
+            return Err(RLNCError::NotAllPiecesReceivedYet);
+        }
+        if self.num_drained_pieces() != 0 {
+            return Err(RLNCError::PieceAlreadyDrained);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the byte length of the original data, by locating the boundary marker, which must be the last non-zero
+    /// byte of the decoded pieces, scanning them backwards, without concatenating them. Must be called only after
+    /// decoding is complete.
+    fn decoded_data_byte_len(&self) -> Result<usize, RLNCError> {
+        let padded_data_byte_len = self.matrix.rank() * self.piece_byte_len;
+
+        let (boundary_marker_rev_index, &last_non_zero_byte) = (0..self.matrix.rank())
+            .rev()
+            .flat_map(|row_idx| self.matrix.payload(row_idx).iter().rev())
+            .enumerate()
+            .find(|&(_, &byte)| byte != 0)
+            .ok_or(RLNCError::InvalidDecodedDataFormat)?;
+        let boundary_marker_index = padded_data_byte_len - 1 - boundary_marker_rev_index;
+
+        if last_non_zero_byte != BOUNDARY_MARKER || boundary_marker_index == 0 {
+            return Err(RLNCError::InvalidDecodedDataFormat);
+        }
+
+        Ok(boundary_marker_index)
+    }
+
+    /// Returns the `row_idx` -th linearly independent piece held by the decoder, i.e. the `row_idx` -th row of its matrix,
+    /// in reduced row echelon form, which is itself a valid full coded piece, e.g. for forwarding it to another peer, in
+    /// peer-assisted decoding. If the decoder is tagged, the piece is prefixed by its expected generation tag, so that
+    /// tagged decoders downstream accept it.
+    ///
+    /// # Returns
+    /// Returns `Some(CodedPiece)` if `row_idx` is less than the number of rows of `Self::coefficient_matrix`, otherwise `None`.
+    pub fn basis_piece(&self, row_idx: usize) -> Option<CodedPiece> {
+        if row_idx >= self.matrix.rank() {
+            return None;
+        }
+
+        let mut full_coded_piece = Vec::with_capacity(self.get_full_coded_piece_byte_len());
+        if let Some(generation_tag) = self.expected_generation_tag {
+            full_coded_piece.extend_from_slice(&generation_tag.to_le_bytes());
+        }
+        full_coded_piece.extend_from_slice(self.matrix.coefficients(row_idx));
+        full_coded_piece.extend_from_slice(self.matrix.payload(row_idx));
+
+        Some(CodedPiece::new_unchecked(
+            full_coded_piece,
+            self.get_num_pieces_coded_together(),
+            self.get_piece_byte_len(),
+            self.expected_generation_tag.is_some(),
+        ))
+    }
+
+    /// Returns all linearly independent pieces held by the decoder, as `Self::basis_piece` does, in order of
+    /// their rows, e.g. for building a `Recoder` from the decoder's current knowledge, while keeping the decoder around.
+    pub fn basis_pieces(&self) -> impl Iterator<Item = CodedPiece> + '_ {
+        (0..self.matrix.rank()).filter_map(|row_idx| self.basis_piece(row_idx))
+    }
+
+    /// Converts this decoder into a `Recoder`, which recodes from the linearly independent pieces
+    /// already held by the decoder, so that a node receiving pieces can turn itself into a relay.
+    ///
+    /// Each row of the decoder's matrix, in reduced row echelon form, is itself a valid full coded piece,
+    /// so the resulting recoder works over a compact basis of everything received so far, without ever
+    /// having to keep the original received pieces around. Note that pieces produced by the recoder don't carry
+    /// any generation tag, even if this decoder is tagged.
+    ///
+    /// # Returns
+    /// Returns `Ok(Recoder)` on success.
+    /// Returns `Err(RLNCError::NotEnoughPiecesToRecode)` if the decoder hasn't yet received a single useful piece.
+    pub fn into_recoder(self) -> Result<Recoder, RLNCError> {
+        if self.matrix.rank() == 0 {
+            return Err(RLNCError::NotEnoughPiecesToRecode);
+        }
+
+        let full_coded_piece_byte_len = self.get_num_pieces_coded_together() + self.get_piece_byte_len();
+        let num_pieces_coded_together = self.get_num_pieces_coded_together();
+
+        Recoder::new(self.matrix.extract_data(), full_coded_piece_byte_len, num_pieces_coded_together)
+    }
+}
+
+/// Deserialized, but not yet validated, `Decoder`.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct UncheckedDecoder {
+    matrix: DecoderMatrix,
+    piece_byte_len: usize,
+    required_piece_count: usize,
+    received_piece_count: usize,
+    useful_piece_count: usize,
+    merged_piece_count: usize,
+    useless_streak: usize,
+    expected_generation_tag: Option<u32>,
+    drained_pieces: Vec<bool>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<UncheckedDecoder> for Decoder {
+    type Error = &'static str;
+
+    /// Accepts only a decoder whose matrix is set up for its configuration, and whose counters agree with the rank
+    /// of its matrix, so that a resumed decoder behaves identically to the one which got serialized.
+    fn try_from(decoder: UncheckedDecoder) -> Result<Self, Self::Error> {
+        if !decoder.matrix.is_configured_for(decoder.required_piece_count, decoder.piece_byte_len) {
+            return Err("decoder matrix doesn't match decoder configuration");
+        }
+
+        if decoder.drained_pieces.len() != decoder.required_piece_count {
+            return Err("drained pieces don't match decoder configuration");
+        }
+
+        let is_drained_column_zero = decoder
+            .drained_pieces
+            .iter()
+            .enumerate()
+            .filter(|&(_, &is_drained)| is_drained)
+            .all(|(col_idx, _)| (0..decoder.matrix.rank()).all(|row_idx| decoder.matrix.coefficient(row_idx, col_idx) == Gf256::zero()));
+        if !is_drained_column_zero {
+            return Err("decoder matrix holds coefficients for drained pieces");
+        }
+
+        let num_drained_pieces = decoder.drained_pieces.iter().filter(|&&is_drained| is_drained).count();
+        if decoder.useful_piece_count != decoder.matrix.rank() + num_drained_pieces
+            || decoder.merged_piece_count > decoder.useful_piece_count
+            || decoder.received_piece_count < decoder.useful_piece_count - decoder.merged_piece_count
+        {
+            return Err("decoder piece counters don't match rank of its matrix");
+        }
+
+        Ok(Decoder {
+            matrix: decoder.matrix,
+            piece_byte_len: decoder.piece_byte_len,
+            required_piece_count: decoder.required_piece_count,
+            received_piece_count: decoder.received_piece_count,
+            useful_piece_count: decoder.useful_piece_count,
+            merged_piece_count: decoder.merged_piece_count,
+            useless_streak: decoder.useless_streak,
+            expected_generation_tag: decoder.expected_generation_tag,
+            drained_pieces: decoder.drained_pieces,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BOUNDARY_MARKER, CHECKSUM_BYTE_LEN, CodedPiece, Decoder, GENERATION_TAG_BYTE_LEN, MatrixLayout, RLNCError, Recoder, coding_vector_from_seed};
+    use crate::common::gf256::Gf256;
+    use crate::full::encoder::Encoder;
+    use rand::Rng;
+
+    #[test]
+    fn test_decoder_new_invalid_inputs() {
+        // Test case 1: piece_byte_len is zero
+        let piece_byte_len_zero = 0;
+        let required_piece_count_non_zero = 10;
+
+        let result_piece_len_zero = Decoder::new(piece_byte_len_zero, required_piece_count_non_zero);
+        assert!(result_piece_len_zero.is_err());
+        assert_eq!(result_piece_len_zero.expect_err("Expected PieceLengthZero error"), RLNCError::PieceLengthZero);
+
+        // Test case 2: required_piece_count is zero
+        let piece_byte_len_non_zero = 10;
+        let required_piece_count_zero = 0;
+
+        let result_piece_count_zero = Decoder::new(piece_byte_len_non_zero, required_piece_count_zero);
+        assert!(result_piece_count_zero.is_err());
+        assert_eq!(result_piece_count_zero.expect_err("Expected PieceCountZero error"), RLNCError::PieceCountZero);
+
+        // Test case 3: Both piece_byte_len and required_piece_count are zero
+        let piece_byte_len_both_zero = 0;
+        let required_piece_count_both_zero = 0;
+
+        let result_both_zero = Decoder::new(piece_byte_len_both_zero, required_piece_count_both_zero);
+        assert!(result_both_zero.is_err());
+        assert_eq!(
+            result_both_zero.expect_err("Expected PieceLengthZero error for both zero inputs"),
+            RLNCError::PieceLengthZero
+        );
+
+        // Test case 4: Decoder's matrix would overflow `usize`, on 32 -bit targets as well as on 64 -bit ones
+        let half_width = 1usize << (usize::BITS / 2);
+        assert_eq!(Decoder::new(half_width, half_width).map(|_| ()), Err(RLNCError::ParametersTooLarge));
+        assert_eq!(Decoder::new(1, usize::MAX).map(|_| ()), Err(RLNCError::ParametersTooLarge));
+        assert_eq!(Decoder::new(usize::MAX, 1).map(|_| ()), Err(RLNCError::ParametersTooLarge));
+        assert_eq!(Decoder::new_tagged(isize::MAX as usize, 2, 0).map(|_| ()), Err(RLNCError::ParametersTooLarge));
+
+        // Test case 5: Valid input
+        let piece_byte_len_valid = 10;
+        let required_piece_count_valid = 5;
+        let result_valid = Decoder::new(piece_byte_len_valid, required_piece_count_valid);
+        assert!(result_valid.is_ok());
+    }
+
+    #[test]
+    fn test_decoder_new_bounded() {
+        let piece_byte_len = 10;
+        let required_piece_count = 5;
+        let matrix_byte_len = required_piece_count * (required_piece_count + piece_byte_len);
+
+        // Test case 1: Decoder's matrix, at full rank, fits in the cap exactly
+        let decoder = Decoder::new_bounded(piece_byte_len, required_piece_count, matrix_byte_len).expect("Decoder must fit in the cap");
+        assert_eq!(decoder.get_piece_byte_len(), piece_byte_len);
+        assert_eq!(decoder.get_num_pieces_coded_together(), required_piece_count);
+
+        // Test case 2: Decoder's matrix, at full rank, is a byte longer than the cap
+        assert_eq!(
+            Decoder::new_bounded(piece_byte_len, required_piece_count, matrix_byte_len - 1).map(|_| ()),
+            Err(RLNCError::ParametersTooLarge)
+        );
+
+        // Test case 3: Parameters, e.g. read off a frame header, which would need gigabytes, are refused, before allocating
+        assert_eq!(Decoder::new_bounded(1 << 20, 1 << 12, 1 << 20).map(|_| ()), Err(RLNCError::ParametersTooLarge));
+
+        // Test case 4: Cap doesn't lift the limit of `isize::MAX` bytes, nor does it skip the other checks
+        assert_eq!(Decoder::new_bounded(1, usize::MAX, usize::MAX).map(|_| ()), Err(RLNCError::ParametersTooLarge));
+        assert_eq!(
+            Decoder::new_bounded(0, required_piece_count, usize::MAX).map(|_| ()),
+            Err(RLNCError::PieceLengthZero)
+        );
+        assert_eq!(Decoder::new_bounded(piece_byte_len, 0, usize::MAX).map(|_| ()), Err(RLNCError::PieceCountZero));
+    }
+
+    #[test]
+    fn test_decoder_decode_invalid_piece_length() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data, piece_count).expect("Failed to create Encoder for decode invalid length test");
+
+        let piece_byte_len = encoder.get_piece_byte_len();
+        let required_piece_count = encoder.get_piece_count();
+        let full_coded_piece_byte_len = encoder.get_full_coded_piece_byte_len();
+
+        let mut decoder = Decoder::new(piece_byte_len, required_piece_count).expect("Failed to create Decoder for decode invalid length test");
+
+        // Test case 1: Piece coded data is shorter than expected
+        let short_coded_piece: Vec<u8> = (0..(full_coded_piece_byte_len - 1)).map(|_| rng.random()).collect();
+        let short_coded_piece = CodedPiece::from_bytes(short_coded_piece, required_piece_count, piece_byte_len - 1).expect("Failed to parse short piece");
+        let result_short = decoder.decode(&short_coded_piece);
+        assert!(result_short.is_err());
+        assert_eq!(
+            result_short.expect_err("Expected InvalidPieceLength error for short piece"),
+            RLNCError::InvalidPieceLength {
+                expected: full_coded_piece_byte_len,
+                got: full_coded_piece_byte_len - 1
+            }
+        );
+
+        // Test case 2: Piece coded data is longer than expected
+        let long_coded_piece: Vec<u8> = (0..(full_coded_piece_byte_len + 1)).map(|_| rng.random()).collect();
+        let long_coded_piece = CodedPiece::from_bytes(long_coded_piece, required_piece_count, piece_byte_len + 1).expect("Failed to parse long piece");
+        let result_long = decoder.decode(&long_coded_piece);
+        assert!(result_long.is_err());
+        assert_eq!(
+            result_long.expect_err("Expected InvalidPieceLength error for long piece"),
+            RLNCError::InvalidPieceLength {
+                expected: full_coded_piece_byte_len,
+                got: full_coded_piece_byte_len + 1
+            }
+        );
+
+        // Test case 3: Piece of same total length, but coded with a different number of pieces
+        let mismatching_coded_piece: Vec<u8> = (0..full_coded_piece_byte_len).map(|_| rng.random()).collect();
+        let mismatching_coded_piece =
+            CodedPiece::from_bytes(mismatching_coded_piece, required_piece_count + 1, piece_byte_len - 1).expect("Failed to parse mismatching piece");
+        let result_mismatching = decoder.decode(&mismatching_coded_piece);
+        assert!(result_mismatching.is_err());
+        assert_eq!(
+            result_mismatching.expect_err("Expected CodingVectorLengthMismatch error for mismatching piece"),
+            RLNCError::CodingVectorLengthMismatch {
+                expected: required_piece_count,
+                got: required_piece_count + 1
+            }
+        );
+
+        // Test case 4: Tagged piece, of the same configuration otherwise, is not cut up at wrong offsets
+        let tagged_coded_piece: Vec<u8> = (0..(GENERATION_TAG_BYTE_LEN + full_coded_piece_byte_len)).map(|_| rng.random()).collect();
+        let tagged_coded_piece = CodedPiece::from_tagged_bytes(tagged_coded_piece, required_piece_count, piece_byte_len).expect("Failed to parse tagged piece");
+        assert_eq!(decoder.decode(&tagged_coded_piece), Err(RLNCError::TaggingMismatch));
+
+        // Test case 5: Bytes of invalid length can't even be parsed as a coded piece
+        let result_zero = CodedPiece::from_bytes(Vec::new(), required_piece_count, piece_byte_len);
+        assert_eq!(
+            result_zero,
+            Err(RLNCError::InvalidPieceLength {
+                expected: full_coded_piece_byte_len,
+                got: 0
+            })
+        );
+
+        // Ensure decoder state is unchanged after invalid decode attempts
+        assert_eq!(decoder.get_received_piece_count(), 0);
+        assert_eq!(decoder.get_useful_piece_count(), 0);
+        assert!(!decoder.is_already_decoded());
+
+        // Test case 5: Valid coded piece - check if state changes
+        let correct_coded_piece = encoder.code(&mut rng);
+        let result_correct = decoder.decode(&correct_coded_piece);
+        assert!(result_correct.is_ok() || matches!(result_correct, Err(RLNCError::PieceNotUseful)));
+
+        // After a valid decode attempt, received_piece_count must increase
+        assert_eq!(decoder.get_received_piece_count(), 1);
+
+        // If the piece was useful, useful_piece_count will be 1. Otherwise, it remains 0.
+        // Given the small piece_count in this test, it's very likely to be useful.
+        if result_correct.is_ok() {
+            assert_eq!(decoder.get_useful_piece_count(), 1);
+            assert!(!decoder.is_already_decoded()); // Unless piece_count was 1
+        } else {
+            assert_eq!(decoder.get_useful_piece_count(), 0);
+        }
+    }
+
+    #[test]
+    fn test_decoder_matching() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+
+        // Test case 1: Untagged encoder
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for matching test");
+        let mut decoder = Decoder::matching(&encoder).expect("Failed to create matching Decoder");
+
+        assert_eq!(decoder.get_num_pieces_coded_together(), encoder.get_piece_count());
+        assert_eq!(decoder.get_piece_byte_len(), encoder.get_piece_byte_len());
+        assert_eq!(decoder.get_full_coded_piece_byte_len(), encoder.get_full_coded_piece_byte_len());
+        assert_eq!(decoder.get_expected_generation_tag(), None);
+
+        while !decoder.is_already_decoded() {
+            match decoder.decode(&encoder.code(&mut rng)) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+        }
+        assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+
+        // Test case 2: Tagged encoder
+        let generation_tag = rng.random::<u32>();
+        let tagged_encoder = Encoder::new_tagged(data, piece_count, generation_tag).expect("Failed to create tagged Encoder for matching test");
+        let tagged_decoder = Decoder::matching(&tagged_encoder).expect("Failed to create matching Decoder");
+
+        assert_eq!(tagged_decoder.get_expected_generation_tag(), Some(generation_tag));
+        assert_eq!(tagged_decoder.get_full_coded_piece_byte_len(), tagged_encoder.get_full_coded_piece_byte_len());
+    }
+
+    #[test]
+    fn test_decoder_getters() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for getters test");
+
+        let piece_byte_len = encoder.get_piece_byte_len();
+        let required_piece_count = encoder.get_piece_count();
+        let full_coded_piece_byte_len = encoder.get_full_coded_piece_byte_len();
+
+        let mut decoder = Decoder::new(piece_byte_len, required_piece_count).expect("Failed to create Decoder for getters test");
+
+        assert_eq!(decoder.get_num_pieces_coded_together(), required_piece_count);
+        assert_eq!(decoder.get_piece_byte_len(), piece_byte_len);
+        assert_eq!(decoder.get_full_coded_piece_byte_len(), full_coded_piece_byte_len);
+        assert_eq!(decoder.get_received_piece_count(), 0);
+        assert_eq!(decoder.get_useful_piece_count(), 0);
+        assert_eq!(decoder.rank(), 0);
+        assert_eq!(decoder.get_matrix_layout(), MatrixLayout::default());
+        assert_eq!(decoder.get_remaining_piece_count(), required_piece_count);
+        assert!(!decoder.is_already_decoded());
+
+        // Add some pieces and track useful ones
+        let num_pieces_to_decode_initially = required_piece_count / 2;
+        let mut expected_useful_pieces_after_initial = 0;
+
+        for _ in 0..num_pieces_to_decode_initially {
+            let coded_piece = encoder.code(&mut rng);
+            match decoder.decode(&coded_piece) {
+                Ok(_) => {
+                    expected_useful_pieces_after_initial += 1;
+                }
+                Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during initial decoding phase: {e:?}"),
+            }
+        }
+
+        assert_eq!(decoder.get_received_piece_count(), num_pieces_to_decode_initially);
+        assert_eq!(decoder.get_useful_piece_count(), expected_useful_pieces_after_initial);
+        assert_eq!(decoder.rank(), expected_useful_pieces_after_initial);
+        assert_eq!(decoder.get_remaining_piece_count(), required_piece_count - expected_useful_pieces_after_initial);
+
+        // Add remaining pieces to complete decoding
+        let mut total_pieces_received = num_pieces_to_decode_initially;
+        while !decoder.is_already_decoded() {
+            let coded_piece = encoder.code(&mut rng);
+
+            match decoder.decode(&coded_piece) {
+                Ok(_) => {}
+                Err(RLNCError::PieceNotUseful) => {}
+                Err(RLNCError::ReceivedAllPieces) => break,
+                Err(e) => panic!("Unexpected error during final decoding phase: {e:?}"),
+            }
+
+            total_pieces_received += 1;
+        }
+
+        assert_eq!(decoder.get_useful_piece_count(), required_piece_count);
+        assert_eq!(decoder.rank(), required_piece_count);
+        assert_eq!(decoder.get_remaining_piece_count(), 0);
+        assert!(decoder.is_already_decoded());
+        assert_eq!(decoder.get_received_piece_count(), total_pieces_received);
+    }
+
+    #[test]
+    fn test_decoder_progress_and_coding_efficiency() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 16usize;
+        let encoder =
+            Encoder::new((0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>(), piece_count).expect("Failed to create Encoder for progress test");
+
+        let mut decoder = Decoder::new(encoder.get_piece_byte_len(), piece_count).expect("Failed to create Decoder for progress test");
+        assert_eq!(decoder.progress(), 0.0);
+        assert_eq!(decoder.coding_efficiency(), 1.0);
+        assert_eq!(decoder.estimated_remaining_piece_count(), piece_count as f64);
+
+        // Half of the pieces are useful, the same piece received twice is useless the second time.
+        let num_useful_pieces = piece_count / 2;
+        (0..num_useful_pieces).for_each(|_| {
+            let coded_piece = encoder.code(&mut rng);
+
+            decoder.decode(&coded_piece).expect("Fresh random piece must be useful");
+            assert_eq!(decoder.decode(&coded_piece), Err(RLNCError::PieceNotUseful));
+        });
+
+        assert_eq!(decoder.progress(), 0.5);
+        assert_eq!(decoder.coding_efficiency(), 0.5);
+        assert_eq!(decoder.estimated_remaining_piece_count(), 2.0 * (piece_count - num_useful_pieces) as f64);
+
+        while !decoder.is_already_decoded() {
+            match decoder.decode(&encoder.code(&mut rng)) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+        }
+
+        assert_eq!(decoder.progress(), 1.0);
+        assert_eq!(decoder.coding_efficiency(), piece_count as f64 / decoder.get_received_piece_count() as f64);
+        assert_eq!(decoder.estimated_remaining_piece_count(), 0.0);
+    }
+
+    #[test]
+    fn test_decoder_expected_usefulness() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 16usize;
+        let encoder = Encoder::new((0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>(), piece_count)
+            .expect("Failed to create Encoder for expected usefulness test");
+
+        let mut decoder = Decoder::new(encoder.get_piece_byte_len(), piece_count).expect("Failed to create Decoder for expected usefulness test");
+        assert_eq!(decoder.expected_usefulness(), 1.0);
+
+        while decoder.get_remaining_piece_count() > 2 {
+            let _ = decoder.decode(&encoder.code(&mut rng));
+        }
+
+        assert_eq!(decoder.expected_usefulness(), 1.0 - 1.0 / 65536.0);
+
+        while decoder.get_remaining_piece_count() > 1 {
+            let _ = decoder.decode(&encoder.code(&mut rng));
+        }
+
+        assert_eq!(decoder.expected_usefulness(), 255.0 / 256.0);
+
+        while !decoder.is_already_decoded() {
+            let _ = decoder.decode(&encoder.code(&mut rng));
+        }
+
+        assert_eq!(decoder.expected_usefulness(), 0.0);
+    }
+
+    #[test]
+    fn test_decoder_useless_streak() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 16usize;
+        let encoder =
+            Encoder::new((0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>(), piece_count).expect("Failed to create Encoder for useless streak test");
+
+        let mut decoder = Decoder::new(encoder.get_piece_byte_len(), piece_count).expect("Failed to create Decoder for useless streak test");
+        assert_eq!(decoder.useless_streak(), 0);
+
+        // Test case 1: Peer holding a stale subspace keeps sending dependent pieces
+        let stale_pieces = encoder.coded_pieces(&mut rng).take(piece_count / 2).collect::<Vec<_>>();
+        stale_pieces
+            .iter()
+            .for_each(|coded_piece| decoder.decode(coded_piece).expect("Fresh random piece must be useful"));
+        assert_eq!(decoder.useless_streak(), 0);
+
+        let stale_recoder = decoder.clone().into_recoder().expect("Recoder creation from decoder must not fail");
+        let num_stale_pieces = 20;
+        (1..=num_stale_pieces).for_each(|streak| {
+            assert_eq!(decoder.decode(&stale_recoder.recode(&mut rng)), Err(RLNCError::PieceNotUseful));
+            assert_eq!(decoder.useless_streak(), streak);
+        });
+
+        // Test case 2: Malformed pieces don't extend the streak
+        let mut zero_coded_piece = encoder.code(&mut rng).into_bytes();
+        zero_coded_piece[..piece_count].fill(0);
+        let zero_coded_piece = CodedPiece::from_bytes(zero_coded_piece, piece_count, encoder.get_piece_byte_len()).expect("Parsing coded piece must not fail");
+        assert_eq!(decoder.decode(&zero_coded_piece), Err(RLNCError::ZeroCodingVector));
+        assert_eq!(decoder.useless_streak(), num_stale_pieces);
+
+        // Test case 3: A useful piece, from a different peer, resets the streak
+        decoder.decode(&encoder.code(&mut rng)).expect("Fresh random piece must be useful");
+        assert_eq!(decoder.useless_streak(), 0);
+
+        assert_eq!(decoder.decode(&stale_pieces[0]), Err(RLNCError::PieceNotUseful));
+        assert_eq!(decoder.useless_streak(), 1);
+
+        decoder.reset();
+        assert_eq!(decoder.useless_streak(), 0);
+    }
+
+    #[test]
+    fn test_decoder_solvable_pieces() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 8usize;
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for solvable pieces test");
+
+        let piece_byte_len = encoder.get_piece_byte_len();
+
+        let mut padded_data = data;
+        padded_data.push(BOUNDARY_MARKER);
+        padded_data.resize(piece_count * piece_byte_len, 0);
+        let source_piece = |idx: usize| padded_data[(idx * piece_byte_len)..((idx + 1) * piece_byte_len)].to_vec();
+
+        let mut decoder = Decoder::new(piece_byte_len, piece_count).expect("Failed to create Decoder for solvable pieces test");
+        assert!(decoder.solvable_pieces().is_empty());
+        assert!(decoder.recovered_piece_indices().is_empty());
+        assert_eq!(decoder.get_piece(0), None);
+
+        // Staircase structure: source piece 0 is isolated, pieces 1 and 2 are mixed together, and source piece 5
+        // is only reachable after eliminating piece 3 from a combination of both.
+        let coding_vectors: [[u8; 8]; 4] = [
+            [7, 0, 0, 0, 0, 0, 0, 0],
+            [0, 3, 9, 0, 0, 0, 0, 0],
+            [0, 0, 0, 11, 0, 0, 0, 0],
+            [0, 0, 0, 5, 0, 13, 0, 0],
+        ];
+
+        for coding_vector in coding_vectors {
+            let coded_piece = encoder
+                .code_with_coding_vector(&coding_vector)
+                .expect("Coding with valid coding vector must not fail");
+            decoder.decode(&coded_piece).expect("Each staircase coded piece must be useful");
+        }
+
+        assert!(!decoder.is_already_decoded());
+
+        let solvable_pieces = decoder.solvable_pieces();
+        let solvable_piece_indices = solvable_pieces.iter().map(|&(idx, _)| idx).collect::<Vec<usize>>();
+        assert_eq!(solvable_piece_indices, vec![0, 3, 5]);
+
+        solvable_pieces.iter().for_each(|(idx, piece)| {
+            assert_eq!(*piece, source_piece(*idx));
+        });
+
+        assert_eq!(decoder.recovered_piece_indices(), solvable_piece_indices);
+        (0..piece_count).for_each(|idx| {
+            let expected_piece = solvable_piece_indices.contains(&idx).then(|| source_piece(idx));
+            assert_eq!(decoder.get_piece(idx), expected_piece);
+        });
+        assert_eq!(decoder.get_piece(piece_count), None);
+
+        // Once fully decoded, every source piece must be solvable.
+        while !decoder.is_already_decoded() {
+            let _ = decoder.decode(&encoder.code(&mut rng));
+        }
+
+        let solvable_pieces = decoder.solvable_pieces();
+        assert_eq!(solvable_pieces.len(), piece_count);
+        solvable_pieces.iter().enumerate().for_each(|(expected_idx, (idx, piece))| {
+            assert_eq!(expected_idx, *idx);
+            assert_eq!(*piece, source_piece(*idx));
+        });
+
+        assert_eq!(decoder.recovered_piece_indices(), (0..piece_count).collect::<Vec<usize>>());
+        (0..piece_count).for_each(|idx| assert_eq!(decoder.get_piece(idx), Some(source_piece(idx))));
+    }
+
+    #[test]
+    fn test_decoder_decode_with_callback() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 16usize;
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for decode with callback test");
+
+        let piece_byte_len = encoder.get_piece_byte_len();
+
+        let mut padded_data = data;
+        padded_data.push(BOUNDARY_MARKER);
+        padded_data.resize(piece_count * piece_byte_len, 0);
+        let source_piece = |idx: usize| padded_data[(idx * piece_byte_len)..((idx + 1) * piece_byte_len)].to_vec();
+
+        let mut decoder = Decoder::new(piece_byte_len, piece_count).expect("Failed to create Decoder for decode with callback test");
+        let mut solved_piece_indices = Vec::new();
+
+        // Test case 1: Invalid pieces are rejected, same as `Decoder::decode` does, without invoking the callback
+        let full_coded_piece = encoder.code(&mut rng).into_bytes();
+        let short_coded_piece = CodedPiece::from_bytes(full_coded_piece[1..].to_vec(), piece_count, piece_byte_len - 1).expect("Failed to parse short piece");
+        assert_eq!(
+            decoder.decode_with_callback(&short_coded_piece, |_, _| panic!("Callback must not be invoked")),
+            Err(RLNCError::InvalidPieceLength {
+                expected: full_coded_piece.len(),
+                got: full_coded_piece.len() - 1
+            })
+        );
+
+        let mismatching_coded_piece = CodedPiece::from_bytes(full_coded_piece, piece_count + 1, piece_byte_len - 1).expect("Failed to parse mismatching piece");
+        assert_eq!(
+            decoder.decode_with_callback(&mismatching_coded_piece, |_, _| panic!("Callback must not be invoked")),
+            decoder.clone().decode(&mismatching_coded_piece)
+        );
+        assert_eq!(
+            decoder.decode_with_callback(&mismatching_coded_piece, |_, _| panic!("Callback must not be invoked")),
+            Err(RLNCError::CodingVectorLengthMismatch {
+                expected: piece_count,
+                got: piece_count + 1
+            })
+        );
+
+        // Test case 2: Each systematic piece solves its own source piece, right away
+        let systematic_piece_indices = (0..piece_count).filter(|_| rng.random_bool(0.5)).collect::<Vec<usize>>();
+        systematic_piece_indices.iter().for_each(|&piece_idx| {
+            let mut coding_vector = vec![0u8; piece_count];
+            coding_vector[piece_idx] = 1;
+
+            let systematic_piece = encoder.code_with_coding_vector(&coding_vector).expect("Coding must not fail");
+            let mut num_solved = 0;
+            decoder
+                .decode_with_callback(&systematic_piece, |idx, piece| {
+                    assert_eq!(idx, piece_idx);
+                    assert_eq!(piece, source_piece(idx));
+
+                    num_solved += 1;
+                    solved_piece_indices.push(idx);
+                })
+                .expect("Each systematic piece must be useful");
+            assert_eq!(num_solved, 1);
+        });
+
+        // Test case 3: Dense pieces solve the remaining source pieces at once, each reported exactly once
+        while !decoder.is_already_decoded() {
+            let full_coded_piece = encoder.code(&mut rng);
+            let _ = decoder.decode_with_callback(&full_coded_piece, |idx, piece| {
+                assert!(!solved_piece_indices.contains(&idx), "source piece {idx} is reported twice");
+                assert_eq!(piece, source_piece(idx));
+
+                solved_piece_indices.push(idx);
+            });
+        }
+
+        solved_piece_indices.sort_unstable();
+        assert_eq!(solved_piece_indices, (0..piece_count).collect::<Vec<usize>>());
+        assert_eq!(
+            decoder.decode_with_callback(&encoder.code(&mut rng), |_, _| panic!("Callback must not be invoked")),
+            Err(RLNCError::ReceivedAllPieces)
+        );
+    }
+
+    #[test]
+    fn test_decoder_drain_solved_pieces() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 16usize;
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for drain solved pieces test");
+
+        let piece_byte_len = encoder.get_piece_byte_len();
+
+        let mut padded_data = data;
+        padded_data.push(BOUNDARY_MARKER);
+        padded_data.resize(piece_count * piece_byte_len, 0);
+        let source_piece = |idx: usize| padded_data[(idx * piece_byte_len)..((idx + 1) * piece_byte_len)].to_vec();
+
+        let mut decoder = Decoder::new(piece_byte_len, piece_count).expect("Failed to create Decoder for drain solved pieces test");
+
+        for _ in 0..2 {
+            assert!(decoder.drain_solved_pieces().is_empty());
+
+            // Progressive download: systematic pieces, some of them lost, followed by repair pieces.
+            let mut drained_piece_indices = Vec::new();
+            let drain = |decoder: &mut Decoder, drained_piece_indices: &mut Vec<usize>| {
+                decoder.drain_solved_pieces().into_iter().for_each(|(idx, piece)| {
+                    assert!(!drained_piece_indices.contains(&idx), "source piece {idx} is drained twice");
+                    assert_eq!(piece, source_piece(idx));
+
+                    drained_piece_indices.push(idx);
+                });
+            };
+
+            (0..piece_count).filter(|_| rng.random_bool(0.75)).for_each(|piece_idx| {
+                let mut coding_vector = vec![0u8; piece_count];
+                coding_vector[piece_idx] = 1;
+
+                let systematic_piece = encoder.code_with_coding_vector(&coding_vector).expect("Coding must not fail");
+                decoder.decode(&systematic_piece).expect("Each systematic piece must be useful");
+
+                drain(&mut decoder, &mut drained_piece_indices);
+            });
+
+            // Rows of drained pieces are removed, while they keep counting towards the rank.
+            assert_eq!(decoder.coefficient_matrix().len(), 0);
+            assert_eq!(decoder.rank(), drained_piece_indices.len());
+            assert_eq!(decoder.get_useful_piece_count(), drained_piece_indices.len());
+
+            // A piece having a non-zero coefficient for a drained piece can't be reduced anymore, unless all pieces arrived.
+            if !drained_piece_indices.is_empty() && !decoder.is_already_decoded() {
+                let received_piece_count = decoder.get_received_piece_count();
+                let coding_vector = vec![1u8; piece_count];
+                let coded_piece = encoder.code_with_coding_vector(&coding_vector).expect("Coding must not fail");
+
+                assert_eq!(decoder.is_useful(&coded_piece), Err(RLNCError::PieceAlreadyDrained));
+                assert_eq!(decoder.decode(&coded_piece), Err(RLNCError::PieceAlreadyDrained));
+                assert_eq!(decoder.get_received_piece_count(), received_piece_count);
+            }
+
+            // Repair pieces code over the source pieces, which are not drained yet, as a sliding window sender does.
+            while !decoder.is_already_decoded() {
+                let coding_vector = (0..piece_count)
+                    .map(|idx| if drained_piece_indices.contains(&idx) { 0 } else { rng.random() })
+                    .collect::<Vec<u8>>();
+                let coded_piece = encoder.code_with_coding_vector(&coding_vector).expect("Coding must not fail");
+
+                let _ = decoder.decode(&coded_piece);
+                drain(&mut decoder, &mut drained_piece_indices);
+            }
+
+            drained_piece_indices.sort_unstable();
+            assert_eq!(drained_piece_indices, (0..piece_count).collect::<Vec<usize>>());
+            assert!(decoder.drain_solved_pieces().is_empty());
+
+            // Memory of all drained pieces is released, so that the original data can't be recovered as a whole.
+            assert_eq!(decoder.matrix.capacity(), 0);
+            assert_eq!(decoder.get_decoded_data_ref(), Err(RLNCError::PieceAlreadyDrained));
+            assert_eq!(decoder.original_data_len(), None);
+
+            // Reset decoder hands over pieces of the next generation again.
+            decoder.reset();
+        }
+    }
+
+    #[test]
+    fn test_decoder_into_recoder() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for into recoder test");
+
+        // Test case 1: Decoder without a single useful piece can't be turned into a recoder
+        let empty_decoder = Decoder::new(encoder.get_piece_byte_len(), encoder.get_piece_count()).expect("Failed to create Decoder");
+        assert_eq!(
+            empty_decoder.into_recoder().expect_err("Expected NotEnoughPiecesToRecode error"),
+            RLNCError::NotEnoughPiecesToRecode
+        );
+
+        // Test case 2: Decoder holding half of the pieces becomes a relay
+        let mut relay_decoder = Decoder::new(encoder.get_piece_byte_len(), encoder.get_piece_count()).expect("Failed to create Decoder");
+        while relay_decoder.get_useful_piece_count() < piece_count / 2 {
+            let _ = relay_decoder.decode(&encoder.code(&mut rng));
+        }
+
+        let relay_rank = relay_decoder.get_useful_piece_count();
+        let recoder = relay_decoder.into_recoder().expect("Decoder with useful pieces must turn into a Recoder");
+
+        assert_eq!(recoder.get_original_num_pieces_coded_together(), encoder.get_piece_count());
+        assert_eq!(recoder.get_num_pieces_recoded_together(), relay_rank);
+        assert_eq!(recoder.get_full_coded_piece_byte_len(), encoder.get_full_coded_piece_byte_len());
+
+        // A fresh decoder fed only from the relay can reach, but never exceed, the relay's rank.
+        let mut decoder = Decoder::new(encoder.get_piece_byte_len(), encoder.get_piece_count()).expect("Failed to create Decoder");
+        (0..(relay_rank * 4)).for_each(|_| {
+            let _ = decoder.decode(&recoder.recode(&mut rng));
+        });
+        assert_eq!(decoder.get_useful_piece_count(), relay_rank);
+
+        // Rest of the pieces come directly from the source.
+        while !decoder.is_already_decoded() {
+            let _ = decoder.decode(&encoder.code(&mut rng));
+        }
+
+        assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+    }
+
+    #[test]
+    fn test_decoder_basis_pieces() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+        let generation_tag = rng.random::<u32>();
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for basis pieces test");
+        let tagged_encoder = Encoder::new_tagged(data.clone(), piece_count, generation_tag).expect("Failed to create Encoder for basis pieces test");
+
+        // Test case 1: Basis pieces of a decoder holding half of the pieces carry all of its knowledge to another decoder
+        let mut decoder = Decoder::new(encoder.get_piece_byte_len(), piece_count).expect("Failed to create Decoder");
+        assert_eq!(decoder.basis_piece(0), None);
+        assert_eq!(decoder.basis_pieces().count(), 0);
+
+        while decoder.rank() < piece_count / 2 {
+            let _ = decoder.decode(&encoder.code(&mut rng));
+        }
+        assert_eq!(decoder.basis_piece(decoder.rank()), None);
+
+        let basis_pieces = decoder.basis_pieces().collect::<Vec<_>>();
+        assert_eq!(basis_pieces.len(), decoder.rank());
+        assert_eq!(basis_pieces[0], decoder.basis_piece(0).expect("Row within rank must be a basis piece"));
+
+        let mut peer = Decoder::new(encoder.get_piece_byte_len(), piece_count).expect("Failed to create Decoder");
+        basis_pieces.iter().for_each(|basis_piece| {
+            peer.decode(basis_piece).expect("Basis pieces must be linearly independent");
+        });
+        assert_eq!(peer.coefficient_matrix(), decoder.coefficient_matrix());
+
+        // Test case 2: Basis pieces make a recoder, while the decoder keeps decoding
+        let recoder = Recoder::new(
+            basis_pieces.iter().flat_map(|basis_piece| basis_piece.as_bytes()).copied().collect(),
+            encoder.get_full_coded_piece_byte_len(),
+            piece_count,
+        )
+        .expect("Recoder creation failed");
+        assert_eq!(recoder.get_num_pieces_recoded_together(), decoder.rank());
+
+        while !decoder.is_already_decoded() {
+            let _ = decoder.decode(&encoder.code(&mut rng));
+        }
+        while !peer.is_already_decoded() {
+            let _ = peer.decode(&recoder.recode(&mut rng));
+            let _ = peer.decode(&encoder.code(&mut rng));
+        }
+        assert_eq!(peer.get_decoded_data().expect("Decoding must succeed"), data);
+
+        // Test case 3: Basis pieces of a tagged decoder carry its generation tag
+        let mut tagged_decoder = Decoder::matching(&tagged_encoder).expect("Failed to create Decoder");
+        let mut tagged_peer = Decoder::matching(&tagged_encoder).expect("Failed to create Decoder");
+        while !tagged_decoder.is_already_decoded() {
+            let _ = tagged_decoder.decode(&tagged_encoder.code(&mut rng));
+        }
+
+        tagged_decoder.basis_pieces().for_each(|basis_piece| {
+            assert_eq!(basis_piece.generation_tag(), Some(generation_tag));
+            tagged_peer.decode(&basis_piece).expect("Basis pieces must be linearly independent");
+        });
+        assert_eq!(tagged_peer.get_decoded_data().expect("Decoding must succeed"), data);
+    }
+
+    #[test]
+    fn test_decoder_reset_with_shorter_final_generation() {
+        let mut rng = rand::rng();
+
+        let piece_count = 16usize;
+        let generation_byte_len = 4096usize;
+        let stream = (0..(3 * generation_byte_len + 1000)).map(|_| rng.random()).collect::<Vec<u8>>();
+
+        let piece_byte_len = (generation_byte_len + 1).div_ceil(piece_count);
+        let mut decoder = Decoder::new(piece_byte_len, piece_count).expect("Failed to create Decoder for reset with test");
+        let matrix_capacity = decoder.matrix.capacity();
+
+        // Test case 1: Invalid configurations leave the decoder untouched
+        assert_eq!(decoder.reset_with(0, piece_count), Err(RLNCError::PieceLengthZero));
+        assert_eq!(decoder.reset_with(piece_byte_len, 0), Err(RLNCError::PieceCountZero));
+        assert_eq!(decoder.reset_with(usize::MAX, 2), Err(RLNCError::ParametersTooLarge));
+        assert_eq!(decoder.get_piece_byte_len(), piece_byte_len);
+        assert_eq!(decoder.get_num_pieces_coded_together(), piece_count);
+
+        // Test case 2: A single decoder decodes all generations, the final one being shorter, without reallocating
+        stream.chunks(generation_byte_len).for_each(|generation| {
+            let encoder = Encoder::new(generation.to_vec(), piece_count).expect("Failed to create Encoder for reset with test");
+            decoder
+                .reset_with(encoder.get_piece_byte_len(), encoder.get_piece_count())
+                .expect("Reconfiguring decoder must not fail");
+
+            assert_eq!(decoder.get_received_piece_count(), 0);
+            assert_eq!(decoder.get_full_coded_piece_byte_len(), encoder.get_full_coded_piece_byte_len());
+
+            while !decoder.is_already_decoded() {
+                match decoder.decode(&encoder.code(&mut rng)) {
+                    Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                    Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+                }
+            }
+
+            assert_eq!(decoder.clone().get_decoded_data().expect("Decoding must succeed"), generation);
+            assert_eq!(decoder.matrix.capacity(), matrix_capacity);
+        });
+
+        // Test case 3: A larger generation, with more pieces, grows the decoder
+        let data = (0..(2 * generation_byte_len)).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), 2 * piece_count).expect("Failed to create Encoder for reset with test");
+        decoder
+            .reset_with(encoder.get_piece_byte_len(), encoder.get_piece_count())
+            .expect("Reconfiguring decoder must not fail");
+
+        while !decoder.is_already_decoded() {
+            let _ = decoder.decode(&encoder.code(&mut rng));
+        }
+        assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+    }
+
+    #[test]
+    fn test_decoder_reset_reused_across_generations() {
+        const NUM_GENERATIONS: usize = 10_000;
+
+        let mut rng = rand::rng();
+
+        let data_byte_len = 64usize;
+        let piece_count = 4usize;
+
+        let piece_byte_len = (data_byte_len + 1).div_ceil(piece_count);
+        let mut decoder = Decoder::new(piece_byte_len, piece_count).expect("Failed to create Decoder for reset stress test");
+        let matrix_capacity = decoder.matrix.capacity();
+
+        (0..NUM_GENERATIONS).for_each(|_| {
+            let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+            let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for reset stress test");
+
+            decoder.reset();
+
+            assert_eq!(decoder.get_received_piece_count(), 0);
+            assert_eq!(decoder.get_useful_piece_count(), 0);
+            assert_eq!(decoder.get_remaining_piece_count(), piece_count);
+            assert!(!decoder.is_already_decoded());
+
+            let mut num_pieces_received = 0;
+            while !decoder.is_already_decoded() {
+                match decoder.decode(&encoder.code(&mut rng)) {
+                    Ok(_) | Err(RLNCError::PieceNotUseful) => num_pieces_received += 1,
+                    Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+                }
+            }
+
+            assert_eq!(decoder.get_received_piece_count(), num_pieces_received);
+            assert_eq!(decoder.get_useful_piece_count(), piece_count);
+
+            let decoded_data = decoder.clone().get_decoded_data().expect("Decoding must succeed");
+            assert_eq!(decoded_data, data);
+
+            assert_eq!(decoder.matrix.capacity(), matrix_capacity);
+        });
+    }
+
+    #[test]
+    fn test_decoder_tagged_generation() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+        let generation_tag = rng.random::<u32>();
+        let stale_generation_tag = generation_tag.wrapping_add(1);
+
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new_tagged(data.clone(), piece_count, generation_tag).expect("Failed to create tagged Encoder");
+        let stale_encoder = Encoder::new_tagged(data.clone(), piece_count, stale_generation_tag).expect("Failed to create tagged Encoder");
+
+        let piece_byte_len = encoder.get_piece_byte_len();
+        let mut decoder = Decoder::new_tagged(piece_byte_len, piece_count, generation_tag).expect("Failed to create tagged Decoder");
+
+        assert_eq!(encoder.get_generation_tag(), Some(generation_tag));
+        assert_eq!(decoder.get_expected_generation_tag(), Some(generation_tag));
+        assert_eq!(decoder.get_full_coded_piece_byte_len(), encoder.get_full_coded_piece_byte_len());
+        assert_eq!(encoder.get_full_coded_piece_byte_len(), 4 + piece_count + piece_byte_len);
+
+        // Test case 1: Piece of a stale generation is rejected, without touching the matrix
+        let stale_piece = stale_encoder.code(&mut rng);
+        assert_eq!(decoder.decode(&stale_piece), Err(RLNCError::StaleGeneration));
+        assert_eq!(decoder.get_received_piece_count(), 0);
+        assert_eq!(decoder.rank(), 0);
+
+        // Test case 2: Untagged piece is rejected, as it carries no generation tag
+        let untagged_piece = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder").code(&mut rng);
+        assert_eq!(decoder.decode(&untagged_piece), Err(RLNCError::TaggingMismatch));
+
+        // Test case 3: Tagged pieces, interleaved with stale ones, round-trip
+        while !decoder.is_already_decoded() {
+            match decoder.decode(&encoder.code(&mut rng)) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+
+            let expected_error = if decoder.is_already_decoded() {
+                RLNCError::ReceivedAllPieces
+            } else {
+                RLNCError::StaleGeneration
+            };
+            assert_eq!(decoder.decode(&stale_encoder.code(&mut rng)), Err(expected_error));
+        }
+
+        assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_decoder_decode_stream() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for decode stream test");
+        let piece_byte_len = encoder.get_piece_byte_len();
+
+        // Test case 1: Stream yields more pieces than required, including a useless one, decoding stops at full rank
+        let mut coded_pieces = (0..piece_count + 8).map(|_| encoder.code(&mut rng)).collect::<Vec<CodedPiece>>();
+        coded_pieces.insert(1, coded_pieces[0].clone());
+
+        let mut decoder = Decoder::new(piece_byte_len, piece_count).expect("Failed to create Decoder for decode stream test");
+        let result = futures::executor::block_on(decoder.decode_stream(futures::stream::iter(coded_pieces)));
+
+        assert_eq!(result, Ok(()));
+        assert!(decoder.is_already_decoded());
+        assert!(decoder.get_received_piece_count() < piece_count + 9);
+        assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+
+        // Test case 2: Stream ends before full rank is reached
+        let coded_pieces = (0..piece_count / 2).map(|_| encoder.code(&mut rng)).collect::<Vec<CodedPiece>>();
+
+        let mut decoder = Decoder::new(piece_byte_len, piece_count).expect("Failed to create Decoder for decode stream test");
+        let result = futures::executor::block_on(decoder.decode_stream(futures::stream::iter(coded_pieces)));
+
+        assert_eq!(result, Err(RLNCError::NotAllPiecesReceivedYet));
+        assert_eq!(decoder.get_received_piece_count(), piece_count / 2);
+
+        // Test case 3: Stream yields a piece of different configuration
+        let mismatching_coded_piece = CodedPiece::from_bytes(vec![0u8; piece_count + 1], piece_count, 1).expect("Failed to parse mismatching piece");
+        let coded_pieces = vec![encoder.code(&mut rng), mismatching_coded_piece];
+
+        let result = futures::executor::block_on(decoder.decode_stream(futures::stream::iter(coded_pieces)));
+        assert_eq!(
+            result,
+            Err(RLNCError::InvalidPieceLength {
+                expected: decoder.get_full_coded_piece_byte_len(),
+                got: piece_count + 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_decoder_with_matrix_layout() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for matrix layout test");
+
+        for layout in [MatrixLayout::Interleaved, MatrixLayout::CoefficientSeparated] {
+            let mut decoder =
+                Decoder::with_matrix_layout(encoder.get_piece_byte_len(), piece_count, layout).expect("Failed to create Decoder for matrix layout test");
+            assert_eq!(decoder.get_matrix_layout(), layout);
+
+            while !decoder.is_already_decoded() {
+                match decoder.decode(&encoder.code(&mut rng)) {
+                    Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                    Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+                }
+            }
+
+            let solved_pieces = decoder.solvable_pieces();
+            assert!(solved_pieces.iter().map(|(piece_idx, _)| *piece_idx).eq(0..piece_count));
+
+            assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+        }
+    }
+
+    #[test]
+    fn test_decoder_decode_with_seed() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+        let generation_tag = rng.random::<u32>();
+
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new_tagged(data.clone(), piece_count, generation_tag).expect("Failed to create Encoder for decode_with_seed test");
+        let mut decoder =
+            Decoder::new_tagged(encoder.get_piece_byte_len(), piece_count, generation_tag).expect("Failed to create Decoder for decode_with_seed test");
+
+        // Test case 1: Full coded piece passed as seeded one
+        assert_eq!(
+            decoder.decode_with_seed(0, encoder.code(&mut rng).as_bytes()),
+            Err(RLNCError::InvalidPieceLength {
+                expected: encoder.get_full_coded_piece_byte_len() - piece_count,
+                got: encoder.get_full_coded_piece_byte_len()
+            })
+        );
+
+        // Test case 2: Seeded pieces, interleaved with regular ones, round-trip
+        let mut seed = rng.random::<u64>();
+        while !decoder.is_already_decoded() {
+            match decoder.decode_with_seed(seed, &encoder.code_with_seed(seed)) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+
+            seed = seed.wrapping_add(1);
+            if decoder.is_already_decoded() {
+                break;
+            }
+
+            match decoder.decode(&encoder.code(&mut rng)) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+        }
+
+        assert_eq!(decoder.decode_with_seed(seed, &encoder.code_with_seed(seed)), Err(RLNCError::ReceivedAllPieces));
+        assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+
+        // Test case 3: Seed expanding into an all-zero coding vector, which is likely for a single piece, is rejected
+        let encoder = Encoder::new(vec![1u8; 16], 1).expect("Failed to create Encoder for decode_with_seed test");
+        let mut decoder = Decoder::matching(&encoder).expect("Failed to create Decoder for decode_with_seed test");
+
+        let zero_seed = (0..)
+            .find(|&seed| coding_vector_from_seed(seed, 1) == [0])
+            .expect("Some seed must expand into a zero coding vector");
+        assert_eq!(
+            decoder.decode_with_seed(zero_seed, &encoder.code_with_seed(zero_seed)),
+            Err(RLNCError::ZeroCodingVector)
+        );
+        assert_eq!(decoder.get_received_piece_count(), 0);
+        assert_eq!(decoder.useless_streak(), 0);
+    }
+
+    #[test]
+    fn test_decoder_decode_partial() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+        let known_piece_count = piece_count / 2;
+
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for decode_partial test");
+        let piece_byte_len = encoder.get_piece_byte_len();
+        let mut decoder = Decoder::new(piece_byte_len, piece_count).expect("Failed to create Decoder for decode_partial test");
+
+        // Early sender, knowing only the first `known_piece_count` source pieces, codes them alone.
+        let code_partial = |rng: &mut rand::rngs::ThreadRng| {
+            let mut coding_vector = (0..known_piece_count).map(|_| rng.random()).collect::<Vec<u8>>();
+            coding_vector.resize(piece_count, 0);
+
+            let coded_piece = encoder.code_with_coding_vector(&coding_vector).expect("Coding must not fail");
+            (coding_vector[..known_piece_count].to_vec(), coded_piece.data().to_vec())
+        };
+
+        // Test case 1: Invalid inputs
+        let (coding_vector, coded_data) = code_partial(&mut rng);
+        assert_eq!(
+            decoder.decode_partial(&vec![1u8; piece_count + 1], &coded_data),
+            Err(RLNCError::CodingVectorLengthMismatch {
+                expected: piece_count,
+                got: piece_count + 1
+            })
+        );
+        assert_eq!(
+            decoder.decode_partial(&coding_vector, &coded_data[1..]),
+            Err(RLNCError::InvalidPieceLength {
+                expected: coded_data.len(),
+                got: coded_data.len() - 1
+            })
+        );
+        assert_eq!(decoder.decode_partial(&[0u8; 4], &coded_data), Err(RLNCError::ZeroCodingVector));
+        assert_eq!(decoder.decode_partial(&[], &coded_data), Err(RLNCError::ZeroCodingVector));
+        assert_eq!(decoder.get_received_piece_count(), 0);
+
+        // Test case 2: Partial pieces recover the known prefix of source pieces, but never more than that
+        while decoder.get_useful_piece_count() < known_piece_count {
+            let (coding_vector, coded_data) = code_partial(&mut rng);
+            match decoder.decode_partial(&coding_vector, &coded_data) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+        }
+
+        let (coding_vector, coded_data) = code_partial(&mut rng);
+        assert_eq!(decoder.decode_partial(&coding_vector, &coded_data), Err(RLNCError::PieceNotUseful));
+        assert_eq!(decoder.recovered_piece_indices(), (0..known_piece_count).collect::<Vec<usize>>());
+        (0..known_piece_count).for_each(|piece_idx| {
+            assert_eq!(
+                decoder.get_piece(piece_idx).expect("Known piece must be recovered"),
+                data[(piece_idx * piece_byte_len)..((piece_idx + 1) * piece_byte_len)]
+            );
+        });
+
+        // Test case 3: Full coded pieces, from a sender knowing all source pieces, complete decoding
+        while !decoder.is_already_decoded() {
+            let coded_piece = encoder.code(&mut rng);
+            match decoder.decode_partial(coded_piece.coding_vector(), coded_piece.data()) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+        }
+
+        assert_eq!(decoder.decode_partial(&coding_vector, &coded_data), Err(RLNCError::ReceivedAllPieces));
+        assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+    }
+
+    #[test]
+    fn test_decoder_get_decoded_data_ref() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for get_decoded_data_ref test");
+        let mut decoder = Decoder::new(encoder.get_piece_byte_len(), piece_count).expect("Failed to create Decoder for get_decoded_data_ref test");
+
+        assert_eq!(decoder.get_decoded_data_ref(), Err(RLNCError::NotAllPiecesReceivedYet));
+
+        while !decoder.is_already_decoded() {
+            match decoder.decode(&encoder.code(&mut rng)) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+        }
+
+        assert_eq!(decoder.get_decoded_data_ref().expect("Decoding must succeed"), data);
+
+        // Decoder is still usable, after recovering decoded data, e.g. for late-arriving pieces.
+        let received_piece_count = decoder.get_received_piece_count();
+        assert_eq!(decoder.decode(&encoder.code(&mut rng)), Err(RLNCError::ReceivedAllPieces));
+        assert_eq!(decoder.get_received_piece_count(), received_piece_count);
+
+        assert_eq!(decoder.get_decoded_data_ref().expect("Decoding must succeed"), data);
+        assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+    }
+
+    #[test]
+    fn test_decoder_from_pieces() {
+        let mut rng = rand::rng();
 
         let data_byte_len = 1024usize;
         let piece_count = 32usize;
-        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
-        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for getters test");
 
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for from_pieces test");
         let piece_byte_len = encoder.get_piece_byte_len();
-        let required_piece_count = encoder.get_piece_count();
-        let full_coded_piece_byte_len = encoder.get_full_coded_piece_byte_len();
 
-        let mut decoder = Decoder::new(piece_byte_len, required_piece_count).expect("Failed to create Decoder for getters test");
+        // Test case 1: Invalid configuration or concatenated pieces of invalid length
+        assert_eq!(Decoder::from_pieces(0, piece_count, &[]).map(|_| ()), Err(RLNCError::PieceLengthZero));
+        assert_eq!(Decoder::from_pieces(piece_byte_len, 0, &[]).map(|_| ()), Err(RLNCError::PieceCountZero));
 
-        assert_eq!(decoder.get_num_pieces_coded_together(), required_piece_count);
-        assert_eq!(decoder.get_piece_byte_len(), piece_byte_len);
-        assert_eq!(decoder.get_full_coded_piece_byte_len(), full_coded_piece_byte_len);
+        let mut pieces = encoder.coded_pieces(&mut rng).take(piece_count / 2).flatten().collect::<Vec<u8>>();
+        assert_eq!(
+            Decoder::from_pieces(piece_byte_len, piece_count, &pieces[1..]).map(|_| ()),
+            Err(RLNCError::InvalidPieceLength {
+                expected: pieces.len(),
+                got: pieces.len() - 1
+            })
+        );
+
+        // Test case 2: Not enough pieces, including a duplicate one, to reach full rank
+        pieces.extend_from_within(..encoder.get_full_coded_piece_byte_len());
+
+        let (decoder, useful_piece_count) = Decoder::from_pieces(piece_byte_len, piece_count, &pieces).expect("Bulk decoding must not fail");
+        assert_eq!(useful_piece_count, piece_count / 2);
+        assert_eq!(decoder.get_received_piece_count(), piece_count / 2 + 1);
+        assert!(!decoder.is_already_decoded());
+
+        // Test case 3: More than enough pieces, the surplus of which is never fed
+        pieces.extend(encoder.coded_pieces(&mut rng).take(piece_count).flatten());
+
+        let (decoder, useful_piece_count) = Decoder::from_pieces(piece_byte_len, piece_count, &pieces).expect("Bulk decoding must not fail");
+        assert_eq!(useful_piece_count, piece_count);
+        assert!(decoder.get_received_piece_count() < pieces.len() / encoder.get_full_coded_piece_byte_len());
+        assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+    }
+
+    #[test]
+    fn test_decoder_decode_checked() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+        let generation_tag = rng.random::<u32>();
+
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new_tagged(data.clone(), piece_count, generation_tag).expect("Failed to create Encoder for checksum test");
+        let mut decoder = Decoder::new_tagged(encoder.get_piece_byte_len(), piece_count, generation_tag).expect("Failed to create Decoder for checksum test");
+
+        let checked_coded_piece = encoder.code_checked(&mut rng);
+        assert_eq!(checked_coded_piece.len(), encoder.get_full_coded_piece_byte_len() + CHECKSUM_BYTE_LEN);
+
+        // Test case 1: Piece of invalid length
+        assert_eq!(
+            decoder.decode_checked(&checked_coded_piece[1..]),
+            Err(RLNCError::InvalidPieceLength {
+                expected: checked_coded_piece.len(),
+                got: checked_coded_piece.len() - 1
+            })
+        );
+
+        // Test case 2: Single flipped bit, anywhere in the piece, including its checksum, is detected
+        (0..checked_coded_piece.len()).for_each(|byte_idx| {
+            let mut corrupted = checked_coded_piece.clone();
+            corrupted[byte_idx] ^= 1 << rng.random_range(0..u8::BITS);
+
+            assert_eq!(decoder.decode_checked(&corrupted), Err(RLNCError::ChecksumMismatch));
+        });
+        assert_eq!(decoder.get_received_piece_count(), 0);
+
+        // Test case 3: Intact pieces decode, with checksum stripped before matrix insertion
+        assert_eq!(decoder.decode_checked(&checked_coded_piece), Ok(()));
+        assert_eq!(decoder.decode_checked(&checked_coded_piece), Err(RLNCError::PieceNotUseful));
+
+        while !decoder.is_already_decoded() {
+            match decoder.decode_checked(&encoder.code_checked(&mut rng)) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+        }
+
+        assert_eq!(decoder.decode_checked(&checked_coded_piece), Err(RLNCError::ReceivedAllPieces));
+        assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+    }
+
+    #[test]
+    fn test_decoder_rejects_zero_coding_vector() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for zero coding vector test");
+        let mut decoder = Decoder::new(encoder.get_piece_byte_len(), piece_count).expect("Failed to create Decoder for zero coding vector test");
+
+        // All-zero coding vector, with arbitrary data, is malformed, irrespective of the decoder's state.
+        let mut garbage = vec![0u8; piece_count];
+        garbage.extend((0..encoder.get_piece_byte_len()).map(|_| rng.random::<u8>()));
+        let garbage = CodedPiece::from_bytes(garbage, piece_count, encoder.get_piece_byte_len()).expect("Failed to parse coded piece");
+
+        assert_eq!(decoder.decode(&garbage), Err(RLNCError::ZeroCodingVector));
         assert_eq!(decoder.get_received_piece_count(), 0);
+
+        // Legitimately linearly dependent piece is still reported as not useful.
+        let coded_piece = encoder.code(&mut rng);
+        assert_eq!(decoder.decode(&coded_piece), Ok(()));
+        assert_eq!(decoder.decode(&coded_piece), Err(RLNCError::PieceNotUseful));
+
+        assert_eq!(decoder.decode(&garbage), Err(RLNCError::ZeroCodingVector));
+        assert_eq!(decoder.get_received_piece_count(), 2);
+        assert_eq!(decoder.get_useful_piece_count(), 1);
+
+        while !decoder.is_already_decoded() {
+            match decoder.decode(&encoder.code(&mut rng)) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+        }
+
+        assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+    }
+
+    #[test]
+    fn test_decoder_coefficient_matrix_and_pivot_columns() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data, piece_count).expect("Failed to create Encoder for coefficient matrix test");
+
+        for layout in [MatrixLayout::Interleaved, MatrixLayout::CoefficientSeparated] {
+            let mut decoder =
+                Decoder::with_matrix_layout(encoder.get_piece_byte_len(), piece_count, layout).expect("Failed to create Decoder for coefficient matrix test");
+
+            assert!(decoder.coefficient_matrix().is_empty());
+            assert!(decoder.pivot_columns().is_empty());
+
+            // Sparse coded pieces make pivot-less columns likely, before full rank is reached.
+            while !decoder.is_already_decoded() {
+                let coded_piece = encoder.code_sparse(&mut rng, 2).expect("Sparse coding must not fail");
+                match decoder.decode(&coded_piece) {
+                    Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                    Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+                }
+
+                let coefficient_matrix = decoder.coefficient_matrix();
+                let pivot_columns = decoder.pivot_columns();
+
+                assert_eq!(coefficient_matrix.len(), decoder.get_useful_piece_count());
+                assert_eq!(coefficient_matrix.len(), decoder.rank());
+                assert_eq!(pivot_columns.len(), decoder.rank());
+                assert!(pivot_columns.windows(2).all(|pair| pair[0] < pair[1]));
+
+                // Reduced row echelon form: each pivot is 1, and the only non-zero entry of its column.
+                coefficient_matrix.iter().zip(&pivot_columns).for_each(|(coefficients, &pivot_col_idx)| {
+                    assert_eq!(coefficients.len(), piece_count);
+                    assert!(coefficients[..pivot_col_idx].iter().all(|&coefficient| coefficient == 0));
+                    assert_eq!(coefficients[pivot_col_idx], 1);
+                });
+                pivot_columns.iter().for_each(|&pivot_col_idx| {
+                    assert_eq!(coefficient_matrix.iter().filter(|coefficients| coefficients[pivot_col_idx] != 0).count(), 1);
+                });
+            }
+
+            assert_eq!(decoder.pivot_columns(), (0..piece_count).collect::<Vec<usize>>());
+        }
+    }
+
+    #[test]
+    fn test_decoder_merge() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for merge test");
+
+        let mut decoder_a = Decoder::new(encoder.get_piece_byte_len(), piece_count).expect("Failed to create Decoder for merge test");
+        let mut decoder_b = Decoder::with_matrix_layout(encoder.get_piece_byte_len(), piece_count, MatrixLayout::CoefficientSeparated)
+            .expect("Failed to create Decoder for merge test");
+
+        // Neither decoder alone reaches full rank, but together they do, as random coded pieces are almost surely
+        // linearly independent.
+        let useful_pieces_per_decoder = piece_count * 3 / 4;
+        for decoder in [&mut decoder_a, &mut decoder_b] {
+            while decoder.get_useful_piece_count() < useful_pieces_per_decoder {
+                match decoder.decode(&encoder.code(&mut rng)) {
+                    Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                    Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+                }
+            }
+        }
+
+        assert!(!decoder_a.is_already_decoded());
+        assert!(!decoder_b.is_already_decoded());
+
+        // Merging a decoder into itself, or into a copy of itself, is not useful, neither does it count as receiving pieces.
+        let mut decoder_a_copy = decoder_a.clone();
+        assert_eq!(decoder_a_copy.merge(&decoder_a), Ok(0));
+        assert_eq!(decoder_a_copy.get_useful_piece_count(), useful_pieces_per_decoder);
+        assert_eq!(decoder_a_copy.get_received_piece_count(), decoder_a.get_received_piece_count());
+        assert_eq!(decoder_a_copy.useless_streak(), decoder_a.useless_streak());
+
+        let received_piece_count = decoder_a.get_received_piece_count();
+        let useless_streak = decoder_a.useless_streak();
+
+        let num_useful_rows = decoder_a.merge(&decoder_b).expect("Merging compatible decoders must not fail");
+        assert_eq!(num_useful_rows, piece_count - useful_pieces_per_decoder);
+        assert!(decoder_a.is_already_decoded());
+        assert_eq!(decoder_a.get_useful_piece_count(), piece_count);
+        assert_eq!(decoder_a.get_received_piece_count(), received_piece_count);
+        assert_eq!(decoder_a.useless_streak(), useless_streak);
+        assert_eq!(decoder_a.coding_efficiency(), useful_pieces_per_decoder as f64 / received_piece_count as f64);
+        assert_eq!(decoder_a.estimated_remaining_piece_count(), 0.0);
+
+        // Merged decoder, having more useful pieces than received ones, survives a serialization round-trip.
+        #[cfg(feature = "serde")]
+        {
+            let serialized_decoder = serde_json::to_string(&decoder_a).expect("Serializing decoder must not fail");
+            let resumed_decoder: Decoder = serde_json::from_str(&serialized_decoder).expect("Deserializing decoder must not fail");
+            assert_eq!(resumed_decoder.coding_efficiency(), decoder_a.coding_efficiency());
+        }
+        assert_eq!(decoder_a.get_decoded_data_ref().expect("Decoding must succeed"), data);
+
+        // Other decoder is left as is.
+        assert_eq!(decoder_b.get_useful_piece_count(), useful_pieces_per_decoder);
+
+        // Decoders set up for different configurations can't be merged.
+        let different_piece_byte_len = Decoder::new(encoder.get_piece_byte_len() + 1, piece_count).expect("Failed to create Decoder for merge test");
+        assert_eq!(decoder_b.merge(&different_piece_byte_len), Err(RLNCError::IncompatibleDecoders));
+
+        let different_piece_count = Decoder::new(encoder.get_piece_byte_len(), piece_count + 1).expect("Failed to create Decoder for merge test");
+        assert_eq!(decoder_b.merge(&different_piece_count), Err(RLNCError::IncompatibleDecoders));
+
+        // Decoders expecting different generation tags can't be merged.
+        let mut tagged_decoder = Decoder::new_tagged(encoder.get_piece_byte_len(), piece_count, 1).expect("Failed to create Decoder for merge test");
+        let other_tagged_decoder = Decoder::new_tagged(encoder.get_piece_byte_len(), piece_count, 2).expect("Failed to create Decoder for merge test");
+        assert_eq!(tagged_decoder.merge(&other_tagged_decoder), Err(RLNCError::StaleGeneration));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_decoder_write_decoded_data() {
+        /// Writer accepting only a limited number of bytes, before failing.
+        struct LimitedWriter {
+            written: Vec<u8>,
+            limit: usize,
+        }
+
+        impl std::io::Write for LimitedWriter {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                let num_bytes = buf.len().min(self.limit - self.written.len());
+                if num_bytes == 0 && !buf.is_empty() {
+                    return Err(std::io::Error::new(std::io::ErrorKind::WriteZero, "writer is full"));
+                }
+
+                self.written.extend_from_slice(&buf[..num_bytes]);
+                Ok(num_bytes)
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut rng = rand::rng();
+
+        for (data_byte_len, piece_count) in [(1usize, 1usize), (1, 8), (1023, 32), (1024, 32), (1025, 32), (4096, 7)] {
+            let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+            let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for write_decoded_data test");
+            let mut decoder = Decoder::new(encoder.get_piece_byte_len(), piece_count).expect("Failed to create Decoder for write_decoded_data test");
+
+            let mut out = Vec::new();
+            assert_eq!(decoder.write_decoded_data(&mut out), Err(RLNCError::NotAllPiecesReceivedYet));
+            assert!(out.is_empty());
+            assert_eq!(decoder.original_data_len(), None);
+
+            while !decoder.is_already_decoded() {
+                match decoder.decode(&encoder.code(&mut rng)) {
+                    Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                    Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+                }
+            }
+
+            assert_eq!(decoder.original_data_len(), Some(encoder.original_data_len()));
+            let mut out = Vec::with_capacity(decoder.original_data_len().expect("Original data length must be known after decoding"));
+
+            assert_eq!(decoder.write_decoded_data(&mut out), Ok(data_byte_len));
+            assert_eq!(out, data);
+            assert_eq!(out, decoder.get_decoded_data_ref().expect("Decoding must succeed"));
+
+            let mut limited = LimitedWriter {
+                written: Vec::new(),
+                limit: data_byte_len - 1,
+            };
+            assert_eq!(decoder.write_decoded_data(&mut limited), Err(RLNCError::DecodedDataWriteFailed));
+            assert_eq!(limited.written, data[..(data_byte_len - 1)]);
+        }
+    }
+
+    #[test]
+    fn test_decoder_take_into() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1000usize;
+        let piece_count = 16usize;
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for take into test");
+
+        let mut decoder = Decoder::new(encoder.get_piece_byte_len(), piece_count).expect("Failed to create Decoder for take into test");
+
+        let mut out = vec![0u8; data_byte_len];
+        assert_eq!(decoder.take_into(&mut out), Err(RLNCError::NotAllPiecesReceivedYet));
+
+        while !decoder.is_already_decoded() {
+            match decoder.decode(&encoder.code(&mut rng)) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+        }
+
+        // Too small output buffer is left untouched, while the decoder keeps its progress.
+        let mut short_out = vec![0u8; data_byte_len - 1];
+        assert_eq!(decoder.take_into(&mut short_out), Err(RLNCError::OutputBufferTooSmall));
+        assert!(short_out.iter().all(|&byte| byte == 0));
+        assert!(decoder.is_already_decoded());
+
+        // Bytes beyond the original data are left untouched.
+        let sentinel = 0xa5u8;
+        let mut long_out = vec![sentinel; data_byte_len + encoder.get_piece_byte_len()];
+        let expected = decoder.get_decoded_data_ref().expect("Decoding must succeed");
+
+        assert_eq!(decoder.take_into(&mut long_out), Ok(data_byte_len));
+        assert_eq!(&long_out[..data_byte_len], data);
+        assert!(long_out[data_byte_len..].iter().all(|&byte| byte == sentinel));
+        assert_eq!(expected, data);
+
+        // Decoder is reset, ready for the next generation.
         assert_eq!(decoder.get_useful_piece_count(), 0);
-        assert_eq!(decoder.get_remaining_piece_count(), required_piece_count);
-        assert!(!decoder.is_already_decoded());
+        assert_eq!(decoder.get_received_piece_count(), 0);
+        assert_eq!(decoder.take_into(&mut out), Err(RLNCError::NotAllPiecesReceivedYet));
+    }
 
-        // Add some pieces and track useful ones
-        let num_pieces_to_decode_initially = required_piece_count / 2;
-        let mut expected_useful_pieces_after_initial = 0;
+    #[test]
+    fn test_decoder_data_ending_with_boundary_marker_like_bytes() {
+        let mut rng = rand::rng();
 
-        for _ in 0..num_pieces_to_decode_initially {
-            let coded_piece = encoder.code(&mut rng);
-            match decoder.decode(&coded_piece) {
-                Ok(_) => {
-                    expected_useful_pieces_after_initial += 1;
+        let tails: &[&[u8]] = &[
+            &[BOUNDARY_MARKER],
+            &[BOUNDARY_MARKER, 0x00],
+            &[BOUNDARY_MARKER, 0x00, 0x00],
+            &[BOUNDARY_MARKER, BOUNDARY_MARKER, 0x00],
+            &[0x00, BOUNDARY_MARKER, 0x00, 0x00, 0x00],
+            &[0x00, 0x00, 0x00],
+        ];
+
+        for &tail in tails {
+            for data_byte_len in [0usize, 1, 7, 31, 64] {
+                for piece_count in [1usize, 2, 3, 8, 16] {
+                    let mut data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+                    data.extend_from_slice(tail);
+
+                    let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for boundary marker test");
+                    let mut decoder = Decoder::new(encoder.get_piece_byte_len(), piece_count).expect("Failed to create Decoder for boundary marker test");
+
+                    while !decoder.is_already_decoded() {
+                        match decoder.decode(&encoder.code(&mut rng)) {
+                            Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                            Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+                        }
+                    }
+
+                    assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
                 }
-                Err(RLNCError::PieceNotUseful) => {}
-                Err(e) => panic!("Unexpected error during initial decoding phase: {e:?}"),
             }
         }
+    }
 
-        assert_eq!(decoder.get_received_piece_count(), num_pieces_to_decode_initially);
-        assert_eq!(decoder.get_useful_piece_count(), expected_useful_pieces_after_initial);
-        assert_eq!(decoder.get_remaining_piece_count(), required_piece_count - expected_useful_pieces_after_initial);
+    #[test]
+    fn test_decoder_is_useful() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 8usize;
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for is useful test");
+
+        let mut decoder = Decoder::new(encoder.get_piece_byte_len(), piece_count).expect("Failed to create Decoder for is useful test");
+
+        // Pieces of another configuration are rejected, same as `Decoder::decode` does.
+        let valid_piece = encoder.code(&mut rng);
+        let short_piece =
+            CodedPiece::from_bytes(valid_piece.as_bytes()[1..].to_vec(), piece_count, encoder.get_piece_byte_len() - 1).expect("Failed to parse short piece");
+        assert_eq!(
+            decoder.is_useful(&short_piece),
+            Err(RLNCError::InvalidPieceLength {
+                expected: valid_piece.as_bytes().len(),
+                got: valid_piece.as_bytes().len() - 1
+            })
+        );
+
+        let other_encoder = Encoder::new(data.clone(), piece_count / 2).expect("Failed to create Encoder for is useful test");
+        assert_eq!(
+            decoder.is_useful(&other_encoder.code(&mut rng)),
+            Err(RLNCError::CodingVectorLengthMismatch {
+                expected: piece_count,
+                got: piece_count / 2
+            })
+        );
+
+        let tagged_encoder = Encoder::new_tagged(data.clone(), piece_count, 1).expect("Failed to create Encoder for is useful test");
+        assert_eq!(decoder.is_useful(&tagged_encoder.code(&mut rng)), Err(RLNCError::TaggingMismatch));
 
-        // Add remaining pieces to complete decoding
-        let mut total_pieces_received = num_pieces_to_decode_initially;
         while !decoder.is_already_decoded() {
-            let coded_piece = encoder.code(&mut rng);
+            // Sparse coding vectors make linearly dependent pieces likely.
+            let coding_vector = (0..piece_count)
+                .map(|_| if rng.random_bool(0.75) { 0 } else { rng.random() })
+                .collect::<Vec<u8>>();
+            let coded_piece = encoder
+                .code_with_coding_vector(&coding_vector)
+                .expect("Coding with valid coding vector must not fail");
+
+            let rank_before = decoder.rank();
+            let is_useful = decoder.is_useful(&coded_piece).expect("Checking piece of valid length must not fail");
+            assert_eq!(decoder.rank(), rank_before);
 
             match decoder.decode(&coded_piece) {
-                Ok(_) => {}
-                Err(RLNCError::PieceNotUseful) => {}
-                Err(RLNCError::ReceivedAllPieces) => break,
-                Err(e) => panic!("Unexpected error during final decoding phase: {e:?}"),
+                Ok(_) => assert!(is_useful),
+                Err(RLNCError::PieceNotUseful) | Err(RLNCError::ZeroCodingVector) => assert!(!is_useful),
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
             }
+        }
 
-            total_pieces_received += 1;
+        assert_eq!(decoder.is_useful(&encoder.code(&mut rng)), Ok(false));
+        assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+    }
+
+    #[test]
+    fn test_decoder_dependency_coefficients() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 8usize;
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for dependency coefficients test");
+
+        let mut decoder = Decoder::new(encoder.get_piece_byte_len(), piece_count).expect("Failed to create Decoder for dependency coefficients test");
+
+        // Malformed pieces are rejected, instead of being mistaken for useful ones.
+        let valid_piece = encoder.code(&mut rng);
+        let short_piece =
+            CodedPiece::from_bytes(valid_piece.as_bytes()[1..].to_vec(), piece_count, encoder.get_piece_byte_len() - 1).expect("Failed to parse short piece");
+        assert_eq!(
+            decoder.dependency_coefficients(&short_piece),
+            Err(RLNCError::InvalidPieceLength {
+                expected: valid_piece.as_bytes().len(),
+                got: valid_piece.as_bytes().len() - 1
+            })
+        );
+
+        let tagged_encoder = Encoder::new_tagged(data.clone(), piece_count, 1).expect("Failed to create Encoder for dependency coefficients test");
+        assert_eq!(decoder.dependency_coefficients(&tagged_encoder.code(&mut rng)), Err(RLNCError::TaggingMismatch));
+
+        while !decoder.is_already_decoded() {
+            // Sparse coding vectors make linearly dependent pieces likely.
+            let coding_vector = (0..piece_count)
+                .map(|_| if rng.random_bool(0.75) { 0 } else { rng.random() })
+                .collect::<Vec<u8>>();
+            let coded_piece = encoder
+                .code_with_coding_vector(&coding_vector)
+                .expect("Coding with valid coding vector must not fail");
+
+            match decoder
+                .dependency_coefficients(&coded_piece)
+                .expect("Explaining piece of valid configuration must not fail")
+            {
+                Some(multipliers) => {
+                    assert_eq!(multipliers.len(), decoder.get_useful_piece_count());
+
+                    // Recombining rows of the coefficient matrix, with the multipliers, must give back the coding vector.
+                    let recombined =
+                        decoder
+                            .coefficient_matrix()
+                            .iter()
+                            .zip(&multipliers)
+                            .fold(vec![Gf256::zero(); piece_count], |mut recombined, (row, &multiplier)| {
+                                recombined
+                                    .iter_mut()
+                                    .zip(row)
+                                    .for_each(|(acc, &coefficient)| *acc += Gf256::new(coefficient) * Gf256::new(multiplier));
+                                recombined
+                            });
+                    assert_eq!(recombined.iter().map(Gf256::get).collect::<Vec<u8>>(), coding_vector);
+
+                    assert!(matches!(
+                        decoder.decode(&coded_piece),
+                        Err(RLNCError::PieceNotUseful) | Err(RLNCError::ZeroCodingVector)
+                    ));
+                }
+                None => decoder.decode(&coded_piece).expect("Decoding useful piece must not fail"),
+            }
         }
 
-        assert_eq!(decoder.get_useful_piece_count(), required_piece_count);
-        assert_eq!(decoder.get_remaining_piece_count(), 0);
-        assert!(decoder.is_already_decoded());
-        assert_eq!(decoder.get_received_piece_count(), total_pieces_received);
+        assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_decoder_serde_round_trip() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+        let generation_tag = rng.random::<u32>();
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+
+        let encoder = Encoder::new_tagged(data.clone(), piece_count, generation_tag).expect("Failed to create Encoder for serde test");
+        let serialized_encoder = serde_json::to_string(&encoder).expect("Serializing encoder must not fail");
+        let encoder: Encoder = serde_json::from_str(&serialized_encoder).expect("Deserializing encoder must not fail");
+
+        // Borrowing encoder deserializes into an owning one, coding identically.
+        let borrowed_encoder = Encoder::new_borrowed(&data, piece_count).expect("Failed to create borrowed Encoder for serde test");
+        let serialized_borrowed_encoder = serde_json::to_string(&borrowed_encoder).expect("Serializing encoder must not fail");
+        let deserialized_encoder: Encoder = serde_json::from_str(&serialized_borrowed_encoder).expect("Deserializing encoder must not fail");
+        let coding_vector = (0..piece_count).map(|_| rng.random()).collect::<Vec<u8>>();
+        assert_eq!(
+            deserialized_encoder.code_with_coding_vector(&coding_vector),
+            borrowed_encoder.code_with_coding_vector(&coding_vector)
+        );
+
+        // Encoder state, missing its trailing pieces, must be rejected.
+        let mut tampered_encoder = serde_json::from_str::<serde_json::Value>(&serialized_borrowed_encoder).expect("Parsing serialized encoder must not fail");
+        tampered_encoder.as_object_mut().expect("Serialized encoder must be an object").remove("tail");
+        assert!(serde_json::from_value::<Encoder>(tampered_encoder).is_err());
+
+        for layout in [MatrixLayout::Interleaved, MatrixLayout::CoefficientSeparated] {
+            let mut decoder = Decoder::with_matrix_layout(encoder.get_piece_byte_len(), piece_count, layout).expect("Failed to create Decoder for serde test");
+            decoder.expected_generation_tag = Some(generation_tag);
+
+            while decoder.get_useful_piece_count() < piece_count / 2 {
+                let coded_piece = encoder.code(&mut rng);
+                let serialized_piece = serde_json::to_string(&coded_piece).expect("Serializing coded piece must not fail");
+                let coded_piece: CodedPiece = serde_json::from_str(&serialized_piece).expect("Deserializing coded piece must not fail");
+
+                let _ = decoder.decode(&coded_piece);
+            }
+
+            let serialized_decoder = serde_json::to_string(&decoder).expect("Serializing decoder must not fail");
+            let mut resumed_decoder: Decoder = serde_json::from_str(&serialized_decoder).expect("Deserializing decoder must not fail");
+
+            assert_eq!(resumed_decoder.matrix, decoder.matrix);
+            assert_eq!(resumed_decoder.get_received_piece_count(), decoder.get_received_piece_count());
+            assert_eq!(resumed_decoder.get_useful_piece_count(), decoder.get_useful_piece_count());
+            assert_eq!(resumed_decoder.get_expected_generation_tag(), Some(generation_tag));
+
+            while !resumed_decoder.is_already_decoded() {
+                match resumed_decoder.decode(&encoder.code(&mut rng)) {
+                    Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                    Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+                }
+            }
+
+            assert_eq!(resumed_decoder.get_decoded_data().expect("Decoding must succeed"), data);
+
+            // Decoder state, inconsistent with its matrix, must be rejected.
+            let mut tampered_decoder = serde_json::from_str::<serde_json::Value>(&serialized_decoder).expect("Parsing serialized decoder must not fail");
+            tampered_decoder["piece_byte_len"] = serde_json::Value::from(encoder.get_piece_byte_len() + 1);
+            assert!(serde_json::from_value::<Decoder>(tampered_decoder).is_err());
+
+            let mut tampered_decoder = serde_json::from_str::<serde_json::Value>(&serialized_decoder).expect("Parsing serialized decoder must not fail");
+            tampered_decoder["matrix"]["rows"] = serde_json::Value::from(decoder.rank() + 1);
+            assert!(serde_json::from_value::<Decoder>(tampered_decoder).is_err());
+
+            let mut tampered_decoder = serde_json::from_str::<serde_json::Value>(&serialized_decoder).expect("Parsing serialized decoder must not fail");
+            tampered_decoder["drained_pieces"] = serde_json::Value::from(vec![false; piece_count + 1]);
+            assert!(serde_json::from_value::<Decoder>(tampered_decoder).is_err());
+
+            // Decoder state, missing any of its fields, must be rejected.
+            for field in ["merged_piece_count", "useless_streak", "drained_pieces"] {
+                let mut tampered_decoder = serde_json::from_str::<serde_json::Value>(&serialized_decoder).expect("Parsing serialized decoder must not fail");
+                tampered_decoder.as_object_mut().expect("Serialized decoder must be an object").remove(field);
+                assert!(serde_json::from_value::<Decoder>(tampered_decoder).is_err());
+            }
+
+            // Drained piece, whose column still holds a non-zero coefficient, must be rejected.
+            let mut tampered_decoder = serde_json::from_str::<serde_json::Value>(&serialized_decoder).expect("Parsing serialized decoder must not fail");
+            tampered_decoder["drained_pieces"][decoder.pivot_columns()[0]] = serde_json::Value::from(true);
+            assert!(serde_json::from_value::<Decoder>(tampered_decoder).is_err());
+        }
+
+        let error = RLNCError::PieceNotUseful;
+        let serialized_error = serde_json::to_string(&error).expect("Serializing error must not fail");
+        assert_eq!(
+            serde_json::from_str::<RLNCError>(&serialized_error).expect("Deserializing error must not fail"),
+            error
+        );
     }
 }
@@ -1,22 +1,72 @@
+use super::encoder::checked_buffer_byte_len;
 use crate::{
     RLNCError,
-    common::{
-        gf256::Gf256,
-        simd::{gf256_inplace_mul_vec_by_scalar, gf256_mul_vec_by_scalar_then_add_into_vec},
-    },
+    common::{field::Field, gf256::Gf256},
 };
-use std::ops::{Index, IndexMut};
+use alloc::vec::Vec;
+use core::{marker::PhantomData, ops::Range};
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Memory layout of the rows of a decoder matrix.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MatrixLayout {
+    /// Each full erasure-coded piece is stored contiguously, as it is received, i.e. coefficients followed by the
+    /// coded data, in a single row-major buffer.
+    #[default]
+    Interleaved,
+    /// Coefficients of all rows are stored in one row-major buffer, while coded data of all rows are stored in
+    /// another, so that scanning coefficient columns, while searching for pivots, touches a much smaller and
+    /// denser region of memory.
+    CoefficientSeparated,
+}
+
+/// Minimum number of bytes to be updated, while eliminating a pivot column from rows, for the parallel elimination to
+/// be worth it.
+#[cfg(feature = "parallel")]
+const MIN_PARALLEL_ELIMINATION_BYTE_LEN: usize = 1 << 16;
+
+/// How rows are updated, while eliminating a pivot column from them, during Gaussian elimination.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum Elimination {
+    /// Rows are updated one after another, on the calling thread.
+    #[cfg_attr(not(feature = "parallel"), default)]
+    Serial,
+    /// Rows are updated in parallel, using `rayon`, as the updates of different rows are independent of each other.
+    #[cfg(feature = "parallel")]
+    #[default]
+    Parallel,
+}
+
+/// Matrix of full erasure-coded pieces, over finite field `F`, which is GF(2^8) by default. Each coefficient and each
+/// symbol of coded data takes `F::BYTE_LEN` bytes, while row and column indices, passed to functions of this matrix,
+/// are counted in field elements.
 #[derive(Clone, Debug, PartialEq)]
-pub struct DecoderMatrix {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(try_from = "UncheckedDecoderMatrix"))]
+pub struct DecoderMatrix<F: Field = Gf256> {
+    layout: MatrixLayout,
     num_pieces_coded_together: usize,
     rows: usize,
+    /// Byte length of each row i.e. of `num_pieces_coded_together` coefficients followed by the coded data.
     cols: usize,
+    /// Row-major buffer, each row starting with `num_pieces_coded_together` coefficients. In the interleaved layout,
+    /// coefficients are followed by the coded data, in the same row.
     elements: Vec<u8>,
+    elements_row_len: usize,
+    /// Row-major buffer of coded data, used only in the coefficient-separated layout, otherwise it stays empty.
+    payloads: Vec<u8>,
+    payloads_row_len: usize,
+    /// Pivot column of each of the leading `pivot_cols.len()` rows, which are known to be in RREF. Rows added after them,
+    /// using `Self::add_row`, are yet to be reduced, so that the matrix is in RREF only when it holds a pivot per row.
+    pivot_cols: Vec<usize>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    field: PhantomData<F>,
 }
 
-impl DecoderMatrix {
-    /// Given RLNC encoding configuration, it sets up a decoder matrix.
+impl<F: Field> DecoderMatrix<F> {
+    /// Given RLNC encoding configuration, it sets up a decoder matrix, using the default `MatrixLayout`.
     ///
     /// This decoder matrix can be used to add incoming erasure-coded pieces,
     /// and incrementally decode them using Gaussian Elimination, if it's a
@@ -24,43 +74,290 @@ impl DecoderMatrix {
     ///
     /// # Arguments
     /// * `num_pieces_coded_together` - The minimum number of useful coded pieces needed for decoding.
-    /// * `piece_byte_length` - The byte length of each original data piece.
+    /// * `piece_byte_length` - The byte length of each original data piece, which must be a multiple of `F::BYTE_LEN`.
     ///
     /// # Returns
-    /// An instance of decoder matrix - ready to use for decoding.
-    pub fn new(num_pieces_coded_together: usize, piece_byte_length: usize) -> Self {
-        let full_coded_piece_byte_len = num_pieces_coded_together + piece_byte_length;
-        let total_byte_len = num_pieces_coded_together * full_coded_piece_byte_len;
-        let elements = Vec::with_capacity(total_byte_len);
+    /// * Ok(matrix) - An instance of decoder matrix - ready to use for decoding.
+    /// * Err(RLNCError::ParametersTooLarge) - If the matrix, at full rank, would be longer than `isize::MAX` bytes.
+    pub fn new(num_pieces_coded_together: usize, piece_byte_length: usize) -> Result<Self, RLNCError> {
+        Self::with_layout(num_pieces_coded_together, piece_byte_length, MatrixLayout::default())
+    }
+
+    /// Same as `Self::new`, but storing rows in the specified `MatrixLayout`.
+    pub fn with_layout(num_pieces_coded_together: usize, piece_byte_length: usize, layout: MatrixLayout) -> Result<Self, RLNCError> {
+        let (full_coded_piece_byte_len, elements_row_len, payloads_row_len) = Self::row_lens(num_pieces_coded_together, piece_byte_length, layout)?;
 
-        Self {
+        Ok(Self {
+            layout,
             num_pieces_coded_together,
             rows: 0,
             cols: full_coded_piece_byte_len,
-            elements,
-        }
+            elements: Vec::with_capacity(num_pieces_coded_together * elements_row_len),
+            elements_row_len,
+            payloads: Vec::with_capacity(num_pieces_coded_together * payloads_row_len),
+            payloads_row_len,
+            pivot_cols: Vec::with_capacity(num_pieces_coded_together),
+            field: PhantomData,
+        })
     }
 
-    /// Adds a new row to the decoder matrix.
+    /// Removes all rows from the matrix, same as `Self::clear`, while setting it up for another RLNC configuration, keeping
+    /// the memory layout. Allocated memory is retained, so that no reallocation happens unless the new configuration needs
+    /// more memory than the matrix can already hold. The matrix is left untouched if the new configuration is invalid.
+    ///
+    /// # Returns
+    /// * Ok(()) - If the matrix is set up for the new configuration.
+    /// * Err(RLNCError::ParametersTooLarge) - If the matrix, at full rank, would be longer than `isize::MAX` bytes.
+    pub(crate) fn reconfigure(&mut self, num_pieces_coded_together: usize, piece_byte_length: usize) -> Result<(), RLNCError> {
+        let (full_coded_piece_byte_len, elements_row_len, payloads_row_len) = Self::row_lens(num_pieces_coded_together, piece_byte_length, self.layout)?;
+
+        self.clear();
+        self.num_pieces_coded_together = num_pieces_coded_together;
+        self.cols = full_coded_piece_byte_len;
+        self.elements_row_len = elements_row_len;
+        self.payloads_row_len = payloads_row_len;
+
+        self.elements.reserve(num_pieces_coded_together * elements_row_len);
+        self.payloads.reserve(num_pieces_coded_together * payloads_row_len);
+        self.pivot_cols.reserve(num_pieces_coded_together);
+
+        Ok(())
+    }
+
+    /// Computes byte length of each full erasure-coded piece, and of its parts stored in `elements` and `payloads`, for
+    /// the given RLNC configuration and memory layout, checking that the matrix, at full rank, fits in memory.
+    fn row_lens(num_pieces_coded_together: usize, piece_byte_length: usize, layout: MatrixLayout) -> Result<(usize, usize, usize), RLNCError> {
+        debug_assert_eq!(piece_byte_length % F::BYTE_LEN, 0);
+
+        let coding_vector_byte_len = checked_buffer_byte_len(num_pieces_coded_together, F::BYTE_LEN)?;
+        let full_coded_piece_byte_len = coding_vector_byte_len.checked_add(piece_byte_length).ok_or(RLNCError::ParametersTooLarge)?;
+        checked_buffer_byte_len(num_pieces_coded_together, full_coded_piece_byte_len)?;
+
+        let (elements_row_len, payloads_row_len) = match layout {
+            MatrixLayout::Interleaved => (full_coded_piece_byte_len, 0),
+            MatrixLayout::CoefficientSeparated => (coding_vector_byte_len, piece_byte_length),
+        };
+
+        Ok((full_coded_piece_byte_len, elements_row_len, payloads_row_len))
+    }
+
+    /// Checks whether this matrix is set up for the RLNC configuration, where `num_pieces_coded_together` pieces of
+    /// `piece_byte_length` bytes each are coded together.
+    #[cfg(feature = "serde")]
+    pub(crate) fn is_configured_for(&self, num_pieces_coded_together: usize, piece_byte_length: usize) -> bool {
+        self.num_pieces_coded_together == num_pieces_coded_together
+            && num_pieces_coded_together
+                .checked_mul(F::BYTE_LEN)
+                .and_then(|coding_vector_byte_len| coding_vector_byte_len.checked_add(piece_byte_length))
+                == Some(self.cols)
+    }
+
+    /// Returns the memory layout of the rows of this matrix.
+    pub fn layout(&self) -> MatrixLayout {
+        self.layout
+    }
+
+    /// Adds a new row to the decoder matrix, without reducing it. The matrix needs to be brought back to RREF,
+    /// using `Self::rref`, before calling `Self::rank` or `Self::add_row_and_reduce`.
+    ///
+    /// The matrix never holds more than `num_pieces_coded_together` rows, which is its capacity, as that many linearly
+    /// independent rows already span the whole space. So, before adding more rows to a full matrix, it needs to be
+    /// brought back to RREF, dropping its zero rows.
     ///
     /// # Arguments
     /// `row` - A byte slice, representing a full erasure-coded piece i.e. containing the coefficients followed by
-    ///  the coded data for one piece. Its length must be `num_pieces_coded_together + piece_byte_length`.
+    ///  the coded data for one piece. Its length must be `num_pieces_coded_together * F::BYTE_LEN + piece_byte_length`.
     ///
     /// # Returns
     /// * Ok(&mut Self) - If full erasure-coded piece is of valid length.
     /// * Err(RLNCError::InvalidPieceLength) - If full erasure-coded piece length doesn't match expected value.
+    /// * Err(RLNCError::ReceivedAllPieces) - If the matrix already holds `num_pieces_coded_together` rows.
     pub fn add_row(&mut self, row: &[u8]) -> Result<&mut Self, RLNCError> {
         if row.len() != self.cols {
-            return Err(RLNCError::InvalidPieceLength);
+            return Err(RLNCError::InvalidPieceLength {
+                expected: self.cols,
+                got: row.len(),
+            });
+        }
+        if self.rows == self.num_pieces_coded_together {
+            return Err(RLNCError::ReceivedAllPieces);
         }
 
-        self.elements.extend_from_slice(row);
-        self.rows += 1;
+        let (elements_row, payloads_row) = row.split_at(self.elements_row_len);
+        self.push_row(elements_row, payloads_row);
 
         Ok(self)
     }
 
+    /// Appends a row, given as its part stored in `elements` and its part stored in `payloads`, to the matrix.
+    fn push_row(&mut self, elements_row: &[u8], payloads_row: &[u8]) {
+        self.elements.extend_from_slice(elements_row);
+        self.payloads.extend_from_slice(payloads_row);
+        self.rows += 1;
+    }
+
+    /// Adds a new row to the decoder matrix, which must already be in RREF, while keeping it in RREF.
+    ///
+    /// The incoming row is reduced against the pivots of the stored rows. If it zeroes out, it is linearly
+    /// dependent on them and it is dropped. Otherwise its leading non-zero coefficient becomes a new pivot,
+    /// which is normalized to 1 and eliminated from the stored rows, before the row is moved in place, so
+    /// that rows stay sorted by their pivot column. This costs O(rank * row length), instead of running
+    /// Gaussian elimination over the whole matrix, as `Self::rref` does. A row whose coefficients form a unit
+    /// vector, with its non-zero coefficient at a column which is not a pivot yet, e.g. a systematic piece, skips
+    /// the reduction, as there is nothing to reduce. Any row is linearly dependent on the rows of a full rank matrix,
+    /// so that it is dropped right away.
+    ///
+    /// # Arguments
+    /// `row` - A byte slice, representing a full erasure-coded piece. Its length must be
+    /// `num_pieces_coded_together * F::BYTE_LEN + piece_byte_length`.
+    ///
+    /// # Returns
+    /// * Ok(true) - If the row was linearly independent of the stored rows, increasing the rank by one.
+    /// * Ok(false) - If the row was linearly dependent on the stored rows, leaving the matrix unchanged.
+    /// * Err(RLNCError::InvalidPieceLength) - If full erasure-coded piece length doesn't match expected value.
+    pub fn add_row_and_reduce(&mut self, row: &[u8]) -> Result<bool, RLNCError> {
+        self.add_row_and_reduce_with(row, Elimination::default())
+    }
+
+    /// Same as `Self::add_row_and_reduce`, but eliminating the new pivot column from the stored rows as `elimination` says.
+    fn add_row_and_reduce_with(&mut self, row: &[u8], elimination: Elimination) -> Result<bool, RLNCError> {
+        debug_assert_eq!(self.pivot_cols.len(), self.rows, "matrix must be in RREF");
+
+        if row.len() != self.cols {
+            return Err(RLNCError::InvalidPieceLength {
+                expected: self.cols,
+                got: row.len(),
+            });
+        }
+        if self.rows == self.num_pieces_coded_together {
+            return Ok(false);
+        }
+
+        self.add_row(row)?;
+        Ok(self.reduce_last_row(elimination))
+    }
+
+    /// Reduces the last row against the pivots of the rows above it, which must be in RREF, either placing it in RREF,
+    /// as a new pivot row, or dropping it, if it zeroes out, as `Self::add_row_and_reduce` describes.
+    ///
+    /// Returns `true` if the last row was linearly independent of the rows above it, otherwise `false`.
+    fn reduce_last_row(&mut self, elimination: Elimination) -> bool {
+        debug_assert_eq!(self.pivot_cols.len() + 1, self.rows, "rows above the last row must be in RREF");
+        let new_row_idx = self.rows - 1;
+
+        // Systematic fast path: a unit vector, whose only non-zero coefficient doesn't fall at any pivot column, is
+        // already reduced against the stored rows and normalized, so it only needs to be eliminated from them.
+        if let Some(pivot_col_idx) = self
+            .unit_vector_col_idx(new_row_idx)
+            .filter(|col_idx| self.pivot_cols.binary_search(col_idx).is_err())
+        {
+            self.place_new_pivot_row(new_row_idx, pivot_col_idx, elimination);
+            return true;
+        }
+
+        // Stored rows are zero at each other's pivot column, so eliminating one of them from the new row
+        // never brings back a non-zero entry at a pivot column, which was already eliminated.
+        for row_idx in 0..new_row_idx {
+            let pivot_col_idx = self.pivot_cols[row_idx];
+
+            let quotient = self.coefficient(new_row_idx, pivot_col_idx);
+            if quotient == F::zero() {
+                continue;
+            }
+
+            self.mul_row_then_add_into_row(new_row_idx, row_idx, pivot_col_idx, quotient);
+        }
+
+        let Some(pivot_col_idx) = (0..self.num_pieces_coded_together).find(|&col_idx| self.coefficient(new_row_idx, col_idx) != F::zero()) else {
+            self.rows -= 1;
+            self.elements.truncate(self.rows * self.elements_row_len);
+            self.payloads.truncate(self.rows * self.payloads_row_len);
+
+            return false;
+        };
+
+        let pivot = self.coefficient(new_row_idx, pivot_col_idx);
+        if pivot != F::one() {
+            let inv = unsafe { pivot.inv().unwrap_unchecked() };
+            self.set_coefficient(new_row_idx, pivot_col_idx, F::one());
+            self.mul_row_by_scalar(new_row_idx, pivot_col_idx + 1, inv);
+        }
+
+        self.place_new_pivot_row(new_row_idx, pivot_col_idx, elimination);
+        true
+    }
+
+    /// Returns the column index of the only non-zero coefficient of row at `row_idx`, if that coefficient is 1,
+    /// i.e. if the row's coding vector is a unit vector, as the systematic pieces' ones are.
+    fn unit_vector_col_idx(&self, row_idx: usize) -> Option<usize> {
+        let mut non_zero_col_idxs = (0..self.num_pieces_coded_together).filter(|&col_idx| self.coefficient(row_idx, col_idx) != F::zero());
+
+        let col_idx = non_zero_col_idxs.next()?;
+        (non_zero_col_idxs.next().is_none() && self.coefficient(row_idx, col_idx) == F::one()).then_some(col_idx)
+    }
+
+    /// Eliminates the normalized pivot, at `pivot_col_idx`, of the last row, at `new_row_idx`, from the stored rows and
+    /// moves the last row in place, so that rows stay sorted by their pivot column.
+    fn place_new_pivot_row(&mut self, new_row_idx: usize, pivot_col_idx: usize, elimination: Elimination) {
+        self.eliminate_column_from_rows(new_row_idx, pivot_col_idx, F::one(), 0..new_row_idx, elimination);
+
+        let insert_at_row_idx = self.pivot_cols.partition_point(|&col_idx| col_idx < pivot_col_idx);
+        self.elements[(insert_at_row_idx * self.elements_row_len)..].rotate_right(self.elements_row_len);
+        self.payloads[(insert_at_row_idx * self.payloads_row_len)..].rotate_right(self.payloads_row_len);
+        self.pivot_cols.insert(insert_at_row_idx, pivot_col_idx);
+    }
+
+    /// Checks whether a row, with given coefficients, would be linearly independent of the rows stored in the
+    /// matrix, which must be in RREF, i.e. whether `Self::add_row_and_reduce` would increase the rank, without
+    /// mutating the matrix. Only a copy of the coefficients is reduced against the pivots of the stored rows.
+    ///
+    /// # Panics
+    /// Panics if `coefficients` is not `num_pieces_coded_together * F::BYTE_LEN` bytes long.
+    pub fn is_independent(&self, coefficients: &[u8]) -> bool {
+        self.dependency_coefficients(coefficients).is_none()
+    }
+
+    /// Expresses a row, with given coefficients, as a linear combination of the rows stored in the matrix, which must
+    /// be in RREF, by reducing a copy of the coefficients against the pivots of the stored rows and reading off the
+    /// multiplier of each row.
+    ///
+    /// # Returns
+    /// * Some(multipliers) - If the row is linearly dependent on the stored rows. `multipliers` holds `Self::rank`
+    ///   field elements, `F::BYTE_LEN` bytes each, the i-th one being the multiplier of the i-th row. Adding up stored
+    ///   rows, each multiplied by its multiplier, gives back the given coefficients.
+    /// * None - If the row is linearly independent of the stored rows.
+    ///
+    /// # Panics
+    /// Panics if `coefficients` is not `num_pieces_coded_together * F::BYTE_LEN` bytes long.
+    pub fn dependency_coefficients(&self, coefficients: &[u8]) -> Option<Vec<u8>> {
+        debug_assert_eq!(self.pivot_cols.len(), self.rows, "matrix must be in RREF");
+        assert_eq!(coefficients.len(), self.num_pieces_coded_together * F::BYTE_LEN);
+
+        let mut reduced = coefficients.to_vec();
+        let mut multipliers = Vec::with_capacity(self.rows * F::BYTE_LEN);
+
+        for (row_idx, &pivot_col_idx) in self.pivot_cols.iter().enumerate() {
+            let pivot_begins_at = pivot_col_idx * F::BYTE_LEN;
+            let pivot_ends_at = pivot_begins_at + F::BYTE_LEN;
+
+            // Pivot of each stored row is 1, so the remaining coefficient at its pivot column is its multiplier.
+            multipliers.extend_from_slice(&reduced[pivot_begins_at..pivot_ends_at]);
+
+            let quotient = F::from_le_bytes(&reduced[pivot_begins_at..]);
+            if quotient == F::zero() {
+                continue;
+            }
+
+            F::mul_vec_by_scalar_then_add_into_vec(&mut reduced[pivot_begins_at..], &self.coefficients(row_idx)[pivot_begins_at..], quotient);
+        }
+
+        if reduced.iter().any(|&coefficient| coefficient != 0) {
+            None
+        } else {
+            Some(multipliers)
+        }
+    }
+
     /// Swaps two rows in the decoder's matrix.
     ///
     /// # Arguments
@@ -70,18 +367,15 @@ impl DecoderMatrix {
     /// # Panics
     /// Panics if either row index is out of bounds.
     pub fn swap_rows(&mut self, row1_idx: usize, row2_idx: usize) -> &mut Self {
-        let row1_begins_at = row1_idx * self.cols;
-        let row1_ends_at = row1_begins_at + self.cols;
+        assert!(
+            row1_idx < self.rows && row2_idx < self.rows,
+            "row indices ({row1_idx}, {row2_idx}) out of bounds for matrix of {} rows",
+            self.rows
+        );
 
-        let row2_begins_at = row2_idx * self.cols;
-        let row2_ends_at = row2_begins_at + self.cols;
-
-        let (left, right) = unsafe { self.elements.split_at_mut_unchecked(row1_ends_at) };
-
-        let left_slice = &mut left[row1_begins_at..];
-        let right_slice = &mut right[(row2_begins_at - row1_ends_at)..(row2_ends_at - row1_ends_at)];
-
-        left_slice.swap_with_slice(right_slice);
+        swap_rows_of(&mut self.elements, self.elements_row_len, row1_idx, row2_idx);
+        swap_rows_of(&mut self.payloads, self.payloads_row_len, row1_idx, row2_idx);
+        self.forget_pivot_cols_from(row1_idx.min(row2_idx));
 
         self
     }
@@ -93,119 +387,343 @@ impl DecoderMatrix {
     ///
     /// This function updates the number of rows to reflect the current rank of the matrix.
     /// It is safe to call `Self::rank` after calling this function.
+    ///
+    /// RREF of a matrix is unique, given the space spanned by its rows, while rows are stored sorted by their pivot
+    /// column, so that the result is canonical. Two matrices, which got the same rows added, in whatever order, hold
+    /// byte-for-byte identical buffers after this call, and compare equal, which makes it safe to compare decoder states
+    /// across replicas. Same holds for matrices kept in RREF using `Self::add_row_and_reduce`. This relies on linearly
+    /// dependent rows carrying consistent coded data, as coded pieces of the same source pieces do, otherwise coded data
+    /// of the stored rows depends on which of the dependent rows got dropped.
+    ///
+    /// Leading rows, which are already known to be in RREF, with their pivot columns tracked, e.g. after an earlier call
+    /// to this function, are not scanned again. A matrix already in RREF is left as is, while rows added to a partially
+    /// reduced matrix, using `Self::add_row`, are reduced one by one, against the pivots of the reduced rows, the way
+    /// `Self::add_row_and_reduce` does, instead of running Gaussian elimination over the whole matrix. This keeps the
+    /// cost of reducing the last few pieces, close to full rank, at O(rank * row length) per piece.
     pub fn rref(&mut self) -> &mut Self {
-        self.clean_forward().clean_backward().remove_zero_rows()
+        self.rref_with(Elimination::default())
+    }
+
+    /// Same as `Self::rref`, but eliminating pivot columns from rows as `elimination` says.
+    fn rref_with(&mut self, elimination: Elimination) -> &mut Self {
+        let num_reduced_rows = self.pivot_cols.len();
+        if num_reduced_rows == self.rows {
+            return self;
+        }
+        if num_reduced_rows == 0 {
+            return self.clean_forward(elimination).clean_backward(elimination).remove_zero_rows().find_pivot_cols();
+        }
+
+        let pending_elements = self.elements.split_off(num_reduced_rows * self.elements_row_len);
+        let pending_payloads = self.payloads.split_off(num_reduced_rows * self.payloads_row_len);
+        let num_pending_rows = self.rows - num_reduced_rows;
+        self.rows = num_reduced_rows;
+
+        for row_idx in 0..num_pending_rows {
+            // Any row is linearly dependent on the rows of a full rank matrix, so that the remaining rows are dropped.
+            if self.rows == self.num_pieces_coded_together {
+                break;
+            }
+
+            let elements_row = &pending_elements[(row_idx * self.elements_row_len)..((row_idx + 1) * self.elements_row_len)];
+            let payloads_row = &pending_payloads[(row_idx * self.payloads_row_len)..((row_idx + 1) * self.payloads_row_len)];
+
+            self.push_row(elements_row, payloads_row);
+            self.reduce_last_row(elimination);
+        }
+
+        self
     }
 
     /// Returns the current rank of the matrix, which is same as the number
-    /// of rows, after calling `Self::rref`.
+    /// of rows, as long as the matrix is kept in RREF.
     pub fn rank(&self) -> usize {
         self.rows
     }
 
-    /// Returns underlying data i.e. `self.rows` many full erasure-coded pieces.
-    /// Calling this function, consumes the decoder matrix instance.
-    pub fn extract_data(self) -> Vec<u8> {
-        self.elements
+    /// Returns the pivot column, i.e. the leading non-zero coefficient column, of each row, in ascending order, as
+    /// long as the matrix is kept in RREF.
+    pub fn pivot_cols(&self) -> &[usize] {
+        &self.pivot_cols
     }
 
-    /// Performs the forward phase of Gaussian elimination (to row echelon form).
-    ///
-    /// Pivots are selected, rows are swapped if necessary to get a non-zero
-    /// pivot, and rows below the pivot are cleared by subtracting a multiple
-    /// of the pivot row.
-    fn clean_forward(&mut self) -> &mut Self {
-        let boundary = self.rows.min(self.cols);
-
-        for i in 0..boundary {
-            if self[(i, i)] == Gf256::zero() {
-                let mut is_non_zero_col = false;
-                let mut pivot_row_idx = i + 1;
-
-                while pivot_row_idx < self.rows {
-                    if self[(pivot_row_idx, i)] != Gf256::zero() {
-                        is_non_zero_col = true;
-                        break;
-                    }
-                    pivot_row_idx += 1;
-                }
+    /// Removes all rows from the matrix, while keeping the allocated memory, so that the matrix
+    /// can be reused for decoding another batch of erasure-coded pieces, with same configuration.
+    pub fn clear(&mut self) -> &mut Self {
+        self.elements.clear();
+        self.payloads.clear();
+        self.pivot_cols.clear();
+        self.rows = 0;
 
-                if !is_non_zero_col {
-                    continue;
-                }
+        self
+    }
 
-                self.swap_rows(i, pivot_row_idx);
+    /// Removes rows, for which `should_remove` returns `true`, given their row index, from the matrix, which must be in
+    /// RREF, keeping the order of the remaining rows, which stay in RREF, as any subset of the rows of a matrix in RREF
+    /// does. Unlike `Self::clear`, memory no longer needed by the remaining rows is released.
+    pub(crate) fn remove_rows(&mut self, mut should_remove: impl FnMut(usize) -> bool) -> &mut Self {
+        debug_assert_eq!(self.pivot_cols.len(), self.rows, "matrix must be in RREF");
+        let mut num_kept_rows = 0;
+
+        for row_idx in 0..self.rows {
+            if should_remove(row_idx) {
+                continue;
             }
 
-            for j in (i + 1)..self.rows {
-                if self[(j, i)] == Gf256::zero() {
-                    continue;
-                }
+            move_row_of(&mut self.elements, self.elements_row_len, row_idx, num_kept_rows);
+            move_row_of(&mut self.payloads, self.payloads_row_len, row_idx, num_kept_rows);
+            self.pivot_cols[num_kept_rows] = self.pivot_cols[row_idx];
+            num_kept_rows += 1;
+        }
+
+        self.rows = num_kept_rows;
+        self.elements.truncate(self.rows * self.elements_row_len);
+        self.elements.shrink_to_fit();
+        self.payloads.truncate(self.rows * self.payloads_row_len);
+        self.payloads.shrink_to_fit();
+        self.pivot_cols.truncate(self.rows);
+
+        self
+    }
+
+    /// Returns number of bytes the matrix can hold, without reallocating.
+    #[cfg(test)]
+    pub fn capacity(&self) -> usize {
+        self.elements.capacity() + self.payloads.capacity()
+    }
+
+    /// Returns an immutable view of the coefficients, stored at the specified row of the matrix, as
+    /// `num_pieces_coded_together * F::BYTE_LEN` bytes.
+    ///
+    /// # Panics
+    /// Panics if `row_idx` is out of bounds.
+    pub fn coefficients(&self, row_idx: usize) -> &[u8] {
+        let row_begins_at = row_idx * self.elements_row_len;
+        &self.elements[row_begins_at..(row_begins_at + self.num_pieces_coded_together * F::BYTE_LEN)]
+    }
 
-                let quotient = unsafe { (self[(j, i)] / self[(i, i)]).unwrap_unchecked().get() };
+    /// Returns the coefficient of matrix at the specified row and column, as a field element. Column index must be
+    /// less than `num_pieces_coded_together`.
+    ///
+    /// # Panics
+    /// Panics, in debug builds, if the index is out of bounds. Release builds skip the bounds check, so that passing
+    /// an out of bounds index is undefined behaviour.
+    pub fn coefficient(&self, row_idx: usize, col_idx: usize) -> F {
+        self.debug_assert_in_bounds(row_idx, col_idx);
+
+        let lin_idx = row_idx * self.elements_row_len + col_idx * F::BYTE_LEN;
+        F::from_le_bytes(unsafe { self.elements.get_unchecked(lin_idx..(lin_idx + F::BYTE_LEN)) })
+    }
+
+    /// Sets the coefficient of matrix at the specified row and column to `val`. Column index must be less than
+    /// `num_pieces_coded_together`.
+    ///
+    /// # Panics
+    /// Panics, in debug builds, if the index is out of bounds. Release builds skip the bounds check, so that passing
+    /// an out of bounds index is undefined behaviour.
+    pub fn set_coefficient(&mut self, row_idx: usize, col_idx: usize, val: F) {
+        self.debug_assert_in_bounds(row_idx, col_idx);
+
+        let lin_idx = row_idx * self.elements_row_len + col_idx * F::BYTE_LEN;
+        val.write_le_bytes(unsafe { self.elements.get_unchecked_mut(lin_idx..(lin_idx + F::BYTE_LEN)) });
+        self.forget_pivot_cols_from(row_idx);
+    }
+
+    /// Forgets the pivot columns of rows from `row_idx` onwards, after they got modified, so that `Self::rref` reduces
+    /// them again. Rows above them are still in RREF, as any subset of the rows of a matrix in RREF is.
+    fn forget_pivot_cols_from(&mut self, row_idx: usize) {
+        self.pivot_cols.truncate(row_idx);
+    }
 
-                let i_th_row_starts_at = i * self.cols;
-                let i_th_row_ends_at = i_th_row_starts_at + self.cols;
+    /// Checks, only in debug builds, that the coefficient at the specified row and column lies within the matrix.
+    #[inline(always)]
+    fn debug_assert_in_bounds(&self, row_idx: usize, col_idx: usize) {
+        debug_assert!(
+            row_idx < self.rows && col_idx < self.num_pieces_coded_together,
+            "coefficient index ({row_idx}, {col_idx}) out of bounds for matrix of {} rows and {} columns",
+            self.rows,
+            self.num_pieces_coded_together
+        );
+    }
 
-                let j_th_row_starts_at = j * self.cols;
-                let j_th_row_ends_at = j_th_row_starts_at + self.cols;
+    /// Returns an immutable view of the coded data, stored at the specified row of the matrix.
+    ///
+    /// # Panics
+    /// Panics if `row_idx` is out of bounds.
+    pub fn payload(&self, row_idx: usize) -> &[u8] {
+        match self.layout {
+            MatrixLayout::Interleaved => {
+                let row_begins_at = row_idx * self.elements_row_len;
+                &self.elements[(row_begins_at + self.num_pieces_coded_together * F::BYTE_LEN)..(row_begins_at + self.elements_row_len)]
+            }
+            MatrixLayout::CoefficientSeparated => {
+                let row_begins_at = row_idx * self.payloads_row_len;
+                &self.payloads[row_begins_at..(row_begins_at + self.payloads_row_len)]
+            }
+        }
+    }
 
-                let (left, right) = self.elements.split_at_mut(i_th_row_ends_at);
+    /// Returns underlying data i.e. `self.rows` many full erasure-coded pieces, each of them being coefficients
+    /// followed by the coded data, irrespective of the matrix layout. Calling this function, consumes the decoder
+    /// matrix instance.
+    pub fn extract_data(self) -> Vec<u8> {
+        match self.layout {
+            MatrixLayout::Interleaved => self.elements,
+            MatrixLayout::CoefficientSeparated => {
+                let mut data = Vec::with_capacity(self.rows * self.cols);
 
-                let i_th_row = &left[(i_th_row_starts_at + i)..];
-                let j_th_row = &mut right[(j_th_row_starts_at - i_th_row_ends_at + i)..(j_th_row_ends_at - i_th_row_ends_at)];
+                self.elements
+                    .chunks_exact(self.elements_row_len)
+                    .zip(self.payloads.chunks_exact(self.payloads_row_len))
+                    .for_each(|(coefficients, payload)| {
+                        data.extend_from_slice(coefficients);
+                        data.extend_from_slice(payload);
+                    });
 
-                gf256_mul_vec_by_scalar_then_add_into_vec(j_th_row, i_th_row, quotient);
+                data
             }
         }
+    }
 
-        self
+    /// Adds `scalar` multiple of `src_row_idx` -th row to `dst_row_idx` -th row, skipping first `from_col_idx` coefficient
+    /// columns, which must be zero in the `src_row_idx` -th row.
+    fn mul_row_then_add_into_row(&mut self, dst_row_idx: usize, src_row_idx: usize, from_col_idx: usize, scalar: F) {
+        let from_byte_idx = from_col_idx * F::BYTE_LEN;
+
+        mul_row_then_add_into_row_of(&mut self.elements, self.elements_row_len, dst_row_idx, src_row_idx, from_byte_idx, scalar);
+        mul_row_then_add_into_row_of(&mut self.payloads, self.payloads_row_len, dst_row_idx, src_row_idx, 0, scalar);
     }
 
-    /// Performs the backward phase of Gaussian elimination (to reduced row echelon form).
+    /// Multiplies `row_idx` -th row by `scalar`, skipping first `from_col_idx` coefficient columns.
+    fn mul_row_by_scalar(&mut self, row_idx: usize, from_col_idx: usize, scalar: F) {
+        let row_begins_at = row_idx * self.elements_row_len;
+        F::inplace_mul_vec_by_scalar(
+            &mut self.elements[(row_begins_at + from_col_idx * F::BYTE_LEN)..(row_begins_at + self.elements_row_len)],
+            scalar,
+        );
+
+        let row_begins_at = row_idx * self.payloads_row_len;
+        F::inplace_mul_vec_by_scalar(&mut self.payloads[row_begins_at..(row_begins_at + self.payloads_row_len)], scalar);
+    }
+
+    /// Clears `col_idx` -th coefficient column of each row in `row_range`, by adding `entry * pivot_inv` multiple of the
+    /// `pivot_row_idx` -th row into it, where `entry` is the coefficient of that row at `col_idx` -th column. Rows in
+    /// `row_range` must all be either below or above the pivot row, whose first `col_idx` coefficient columns must be zero.
     ///
-    /// Clears entries above the pivots and normalizes pivots to 1.
-    fn clean_backward(&mut self) -> &mut Self {
-        let boundary = self.rows.min(self.cols);
+    /// Updates of different rows are independent of each other, so they are carried out in parallel, when `elimination`
+    /// says so, producing exactly the same matrix as the serial elimination does.
+    fn eliminate_column_from_rows(&mut self, pivot_row_idx: usize, col_idx: usize, pivot_inv: F, row_range: Range<usize>, elimination: Elimination) {
+        debug_assert!(row_range.end <= pivot_row_idx || row_range.start > pivot_row_idx);
 
-        for i in (0..boundary).rev() {
-            if self[(i, i)] == Gf256::zero() {
-                continue;
-            }
+        match elimination {
+            Elimination::Serial => {
+                for row_idx in row_range {
+                    let entry = self.coefficient(row_idx, col_idx);
+                    if entry == F::zero() {
+                        continue;
+                    }
 
-            for j in 0..i {
-                if self[(j, i)] == Gf256::zero() {
-                    continue;
+                    self.mul_row_then_add_into_row(row_idx, pivot_row_idx, col_idx, entry * pivot_inv);
+                }
+            }
+            #[cfg(feature = "parallel")]
+            Elimination::Parallel => {
+                // Distributing a few small row updates over threads costs more than it saves.
+                let num_bytes_to_update = row_range.len() * (self.elements_row_len + self.payloads_row_len);
+                if rayon::current_num_threads() == 1 || num_bytes_to_update < MIN_PARALLEL_ELIMINATION_BYTE_LEN {
+                    return self.eliminate_column_from_rows(pivot_row_idx, col_idx, pivot_inv, row_range, Elimination::Serial);
                 }
 
-                let quotient = unsafe { (self[(j, i)] / self[(i, i)]).unwrap_unchecked().get() };
+                let from_byte_idx = col_idx * F::BYTE_LEN;
+                let (pivot_row, rows) = split_pivot_row_of(&mut self.elements, self.elements_row_len, pivot_row_idx, row_range.clone());
 
-                let j_th_row_starts_at = j * self.cols;
-                let j_th_row_ends_at = j_th_row_starts_at + self.cols;
+                // Returns the scalar, the pivot row's payload is to be multiplied with, before adding it into the row's payload.
+                let eliminate_from_row = |row: &mut [u8]| -> Option<F> {
+                    let entry = F::from_le_bytes(&row[from_byte_idx..]);
+                    if entry == F::zero() {
+                        return None;
+                    }
 
-                let i_th_row_starts_at = i * self.cols;
-                let i_th_row_ends_at = i_th_row_starts_at + self.cols;
+                    let scalar = entry * pivot_inv;
+                    F::mul_vec_by_scalar_then_add_into_vec(&mut row[from_byte_idx..], &pivot_row[from_byte_idx..], scalar);
 
-                let (left, right) = self.elements.split_at_mut(j_th_row_ends_at);
+                    Some(scalar)
+                };
 
-                let j_th_row = &mut left[(j_th_row_starts_at + i)..];
-                let i_th_row = &right[(i_th_row_starts_at - j_th_row_ends_at + i)..(i_th_row_ends_at - j_th_row_ends_at)];
+                match self.layout {
+                    MatrixLayout::Interleaved => {
+                        rows.par_chunks_exact_mut(self.elements_row_len).for_each(|row| {
+                            eliminate_from_row(row);
+                        });
+                    }
+                    MatrixLayout::CoefficientSeparated => {
+                        let (pivot_payload, payloads) = split_pivot_row_of(&mut self.payloads, self.payloads_row_len, pivot_row_idx, row_range);
 
-                gf256_mul_vec_by_scalar_then_add_into_vec(j_th_row, i_th_row, quotient);
+                        rows.par_chunks_exact_mut(self.elements_row_len)
+                            .zip(payloads.par_chunks_exact_mut(self.payloads_row_len))
+                            .for_each(|(row, payload)| {
+                                if let Some(scalar) = eliminate_from_row(row) {
+                                    F::mul_vec_by_scalar_then_add_into_vec(payload, pivot_payload, scalar);
+                                }
+                            });
+                    }
+                }
+            }
+        }
+    }
+
+    /// Performs the forward phase of Gaussian elimination (to row echelon form).
+    ///
+    /// Walks over the coefficient columns, selecting the first row at or below the current pivot row
+    /// which has a non-zero entry in that column. Rows are swapped if necessary to bring the pivot up,
+    /// and rows below the pivot are cleared by subtracting a multiple of the pivot row. Columns without
+    /// any non-zero entry are skipped, without consuming a pivot row, so that rank deficient matrices
+    /// also end up in proper row echelon form.
+    fn clean_forward(&mut self, elimination: Elimination) -> &mut Self {
+        let mut pivot_row_idx = 0;
+
+        for col_idx in 0..self.num_pieces_coded_together {
+            if pivot_row_idx == self.rows {
+                break;
             }
 
-            if self[(i, i)] == Gf256::one() {
+            let Some(non_zero_row_idx) = (pivot_row_idx..self.rows).find(|&row_idx| self.coefficient(row_idx, col_idx) != F::zero()) else {
                 continue;
+            };
+
+            if non_zero_row_idx != pivot_row_idx {
+                self.swap_rows(pivot_row_idx, non_zero_row_idx);
             }
 
-            let inv = unsafe { self[(i, i)].inv().unwrap_unchecked().get() };
-            self[(i, i)] = Gf256::one();
+            let pivot_inv = unsafe { self.coefficient(pivot_row_idx, col_idx).inv().unwrap_unchecked() };
+            self.eliminate_column_from_rows(pivot_row_idx, col_idx, pivot_inv, (pivot_row_idx + 1)..self.rows, elimination);
 
-            let i_th_row_starts_at = i * self.cols;
-            let i_th_row_ends_at = i_th_row_starts_at + self.cols;
+            pivot_row_idx += 1;
+        }
 
-            let i_th_row = &mut self.elements[(i_th_row_starts_at + (i + 1))..i_th_row_ends_at];
-            gf256_inplace_mul_vec_by_scalar(i_th_row, inv);
+        self
+    }
+
+    /// Performs the backward phase of Gaussian elimination (to reduced row echelon form).
+    ///
+    /// For each non-zero row, starting from the bottom one, the leading non-zero coefficient column is
+    /// its pivot. Entries above the pivots are cleared and pivots are normalized to 1.
+    fn clean_backward(&mut self, elimination: Elimination) -> &mut Self {
+        for i in (0..self.rows).rev() {
+            let Some(pivot_col_idx) = (0..self.num_pieces_coded_together).find(|&col_idx| self.coefficient(i, col_idx) != F::zero()) else {
+                continue;
+            };
+
+            let pivot = self.coefficient(i, pivot_col_idx);
+            let pivot_inv = unsafe { pivot.inv().unwrap_unchecked() };
+            self.eliminate_column_from_rows(i, pivot_col_idx, pivot_inv, 0..i, elimination);
+
+            if pivot == F::one() {
+                continue;
+            }
+
+            self.set_coefficient(i, pivot_col_idx, F::one());
+            self.mul_row_by_scalar(i, pivot_col_idx + 1, pivot_inv);
         }
 
         self
@@ -216,78 +734,194 @@ impl DecoderMatrix {
     /// A row is considered a zero row if all its coefficient columns are zero.
     /// This step is crucial after RREF to determine the true rank and compact
     /// the matrix to only the useful rows.
+    ///
+    /// Non-zero rows are moved towards the front, in a single pass, keeping their order, and the buffers are truncated
+    /// once, so that each surviving row is copied at most once, no matter how many rows are removed.
     fn remove_zero_rows(&mut self) -> &mut Self {
-        let mut i = 0;
-        while i < self.rows {
-            let is_nonzero_row = (0..self.num_pieces_coded_together).any(|cidx| self[(i, cidx)] != Gf256::zero());
-            if is_nonzero_row {
-                i += 1;
+        let mut num_nonzero_rows = 0;
+
+        for row_idx in 0..self.rows {
+            let is_nonzero_row = (0..self.num_pieces_coded_together).any(|cidx| self.coefficient(row_idx, cidx) != F::zero());
+            if !is_nonzero_row {
                 continue;
             }
 
-            let start_idx_of_row_to_remove = i * self.cols;
-            let start_idx_of_next_row = (i + 1) * self.cols;
-
-            if start_idx_of_next_row < self.elements.len() {
-                self.elements.copy_within(start_idx_of_next_row.., start_idx_of_row_to_remove);
-            }
-            self.rows -= 1;
+            move_row_of(&mut self.elements, self.elements_row_len, row_idx, num_nonzero_rows);
+            move_row_of(&mut self.payloads, self.payloads_row_len, row_idx, num_nonzero_rows);
+            num_nonzero_rows += 1;
         }
 
-        let updated_num_elements = self.rows * self.cols;
-        self.elements.truncate(updated_num_elements);
+        self.rows = num_nonzero_rows;
+        self.elements.truncate(self.rows * self.elements_row_len);
+        self.payloads.truncate(self.rows * self.payloads_row_len);
+
+        self
+    }
+
+    /// Records the pivot column of each row, i.e. its leading non-zero coefficient column. Must be called
+    /// only after zero rows are removed.
+    fn find_pivot_cols(&mut self) -> &mut Self {
+        self.pivot_cols.clear();
+
+        for row_idx in 0..self.rows {
+            let pivot_col_idx = unsafe {
+                (0..self.num_pieces_coded_together)
+                    .find(|&col_idx| self.coefficient(row_idx, col_idx) != F::zero())
+                    .unwrap_unchecked()
+            };
+            self.pivot_cols.push(pivot_col_idx);
+        }
 
         self
     }
 }
 
-impl Index<(usize, usize)> for DecoderMatrix {
-    type Output = Gf256;
+/// Swaps two rows of a row-major buffer, with `row_len` -bytes rows.
+fn swap_rows_of(buffer: &mut [u8], row_len: usize, row1_idx: usize, row2_idx: usize) {
+    if row1_idx == row2_idx {
+        return;
+    }
 
-    /// Returns an immutable reference to an element of matrix at the specified row and column,
-    /// converting it to a `Gf256` element.
-    ///
-    /// # Arguments
-    /// * `index` - A tuple `(row_index, col_index)` specifying the position.
-    ///
-    /// # Returns
-    /// Returns the element as a `Gf256`.
-    ///
-    /// # Panics
-    /// Panics if the index is out of bounds.
-    fn index(&self, index: (usize, usize)) -> &Self::Output {
-        let (row_idx, col_idx) = index;
-        let lin_idx = row_idx * self.cols + col_idx;
+    let (row1_idx, row2_idx) = (row1_idx.min(row2_idx), row1_idx.max(row2_idx));
+
+    let row1_begins_at = row1_idx * row_len;
+    let row2_begins_at = row2_idx * row_len;
+
+    let (left, right) = buffer.split_at_mut(row2_begins_at);
+    left[row1_begins_at..(row1_begins_at + row_len)].swap_with_slice(&mut right[..row_len]);
+}
+
+/// Adds `scalar` multiple of `src_row_idx` -th row to `dst_row_idx` -th row of a row-major buffer, with `row_len` -bytes
+/// rows, skipping first `from_col_idx` bytes of both rows.
+fn mul_row_then_add_into_row_of<F: Field>(buffer: &mut [u8], row_len: usize, dst_row_idx: usize, src_row_idx: usize, from_col_idx: usize, scalar: F) {
+    let dst_row_begins_at = dst_row_idx * row_len;
+    let src_row_begins_at = src_row_idx * row_len;
+
+    if dst_row_idx < src_row_idx {
+        let (left, right) = buffer.split_at_mut(src_row_begins_at);
+
+        let dst_row = &mut left[(dst_row_begins_at + from_col_idx)..(dst_row_begins_at + row_len)];
+        let src_row = &right[from_col_idx..row_len];
+
+        F::mul_vec_by_scalar_then_add_into_vec(dst_row, src_row, scalar);
+    } else {
+        let (left, right) = buffer.split_at_mut(dst_row_begins_at);
+
+        let src_row = &left[(src_row_begins_at + from_col_idx)..(src_row_begins_at + row_len)];
+        let dst_row = &mut right[from_col_idx..row_len];
 
-        unsafe { std::mem::transmute(self.elements.get_unchecked(lin_idx)) }
+        F::mul_vec_by_scalar_then_add_into_vec(dst_row, src_row, scalar);
     }
 }
 
-impl IndexMut<(usize, usize)> for DecoderMatrix {
-    /// Returns a mutable reference to an element of matrix at the specified row and column,
-    /// converting it to a `Gf256` element.
-    ///
-    /// # Arguments
-    /// * `index` - A tuple `(row_index, col_index)` specifying the position.
-    /// * `val` - The `Gf256` value to set.
-    ///
-    /// # Panics
-    /// Panics if the index is out of bounds.
-    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
-        let (row_idx, col_idx) = index;
-        let lin_idx = row_idx * self.cols + col_idx;
+/// Splits a row-major buffer, with `row_len` -bytes rows, into an immutable view of the `pivot_row_idx` -th row and
+/// a mutable view of the rows in `row_range`, which must not contain the pivot row.
+#[cfg(feature = "parallel")]
+fn split_pivot_row_of(buffer: &mut [u8], row_len: usize, pivot_row_idx: usize, row_range: Range<usize>) -> (&[u8], &mut [u8]) {
+    let pivot_row_begins_at = pivot_row_idx * row_len;
+
+    if row_range.start > pivot_row_idx {
+        let (left, right) = buffer.split_at_mut(row_range.start * row_len);
+        (
+            &left[pivot_row_begins_at..(pivot_row_begins_at + row_len)],
+            &mut right[..(row_range.len() * row_len)],
+        )
+    } else {
+        let (left, right) = buffer.split_at_mut(pivot_row_begins_at);
+        (&right[..row_len], &mut left[(row_range.start * row_len)..(row_range.end * row_len)])
+    }
+}
+
+/// Copies `src_row_idx` -th row of a row-major buffer, with `row_len` -bytes rows, over its `dst_row_idx` -th row.
+fn move_row_of(buffer: &mut [u8], row_len: usize, src_row_idx: usize, dst_row_idx: usize) {
+    if src_row_idx == dst_row_idx {
+        return;
+    }
+
+    let src_row_begins_at = src_row_idx * row_len;
+    buffer.copy_within(src_row_begins_at..(src_row_begins_at + row_len), dst_row_idx * row_len);
+}
+
+/// Deserialized, but not yet validated, `DecoderMatrix`.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct UncheckedDecoderMatrix {
+    layout: MatrixLayout,
+    num_pieces_coded_together: usize,
+    rows: usize,
+    cols: usize,
+    elements: Vec<u8>,
+    elements_row_len: usize,
+    payloads: Vec<u8>,
+    payloads_row_len: usize,
+    pivot_cols: Vec<usize>,
+}
+
+#[cfg(feature = "serde")]
+impl<F: Field> TryFrom<UncheckedDecoderMatrix> for DecoderMatrix<F> {
+    type Error = &'static str;
+
+    /// Accepts only a matrix in RREF, with buffers of lengths consistent with its configuration, so that unchecked
+    /// indexing into a deserialized matrix stays in bounds.
+    fn try_from(matrix: UncheckedDecoderMatrix) -> Result<Self, Self::Error> {
+        let coding_vector_byte_len = matrix.num_pieces_coded_together.saturating_mul(F::BYTE_LEN);
+        if matrix.num_pieces_coded_together == 0 || matrix.cols <= coding_vector_byte_len || (matrix.cols - coding_vector_byte_len) % F::BYTE_LEN != 0 {
+            return Err("invalid decoder matrix configuration");
+        }
+
+        let expected = Self::with_layout(matrix.num_pieces_coded_together, matrix.cols - coding_vector_byte_len, matrix.layout)
+            .map_err(|_| "decoder matrix configuration is too large")?;
+        if matrix.elements_row_len != expected.elements_row_len || matrix.payloads_row_len != expected.payloads_row_len {
+            return Err("decoder matrix row lengths don't match its layout");
+        }
+        if matrix.rows > matrix.num_pieces_coded_together
+            || matrix.elements.len() != matrix.rows * matrix.elements_row_len
+            || matrix.payloads.len() != matrix.rows * matrix.payloads_row_len
+            || matrix.pivot_cols.len() != matrix.rows
+        {
+            return Err("decoder matrix buffer lengths don't match its number of rows");
+        }
 
-        unsafe { std::mem::transmute(self.elements.get_unchecked_mut(lin_idx)) }
+        let decoded = DecoderMatrix {
+            elements: matrix.elements,
+            payloads: matrix.payloads,
+            rows: matrix.rows,
+            pivot_cols: matrix.pivot_cols,
+            ..expected
+        };
+
+        let is_rref = decoded.pivot_cols.iter().enumerate().all(|(row_idx, &pivot_col_idx)| {
+            pivot_col_idx < decoded.num_pieces_coded_together
+                && (row_idx == 0 || decoded.pivot_cols[row_idx - 1] < pivot_col_idx)
+                && decoded.coefficients(row_idx)[..(pivot_col_idx * F::BYTE_LEN)].iter().all(|&byte| byte == 0)
+                && (0..decoded.rows).all(|other_row_idx| {
+                    let expected = if other_row_idx == row_idx { F::one() } else { F::zero() };
+                    decoded.coefficient(other_row_idx, pivot_col_idx) == expected
+                })
+        });
+        if !is_rref {
+            return Err("decoder matrix is not in reduced row echelon form");
+        }
+
+        Ok(decoded)
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::full::decoder_matrix::DecoderMatrix;
-    use rand::Rng;
+    use crate::RLNCError;
+    use crate::common::{gf256::Gf256, gf65536::Gf65536};
+    #[cfg(feature = "parallel")]
+    use crate::full::decoder_matrix::Elimination;
+    use crate::full::{
+        decoder_matrix::{DecoderMatrix, MatrixLayout},
+        encoder::Encoder,
+    };
+    use rand::{Rng, seq::SliceRandom};
 
     fn make_random_matrix<R: Rng + ?Sized>(num_rows: usize, num_cols: usize, rng: &mut R) -> DecoderMatrix {
-        let mut matrix = DecoderMatrix::new(num_cols, 0);
+        debug_assert!(num_rows <= num_cols);
+        let mut matrix = DecoderMatrix::new(num_cols, 0).expect("creating matrix must not fail");
 
         (0..num_rows).for_each(|_| {
             let random_row = (0..num_cols).map(|_| rng.random()).collect::<Vec<u8>>();
@@ -297,6 +931,83 @@ mod test {
         matrix
     }
 
+    #[test]
+    fn test_new_rejects_too_large_parameters() {
+        let half_width = 1usize << (usize::BITS / 2);
+
+        assert_eq!(
+            DecoderMatrix::<Gf256>::new(half_width, half_width).map(|_| ()),
+            Err(RLNCError::ParametersTooLarge)
+        );
+        assert_eq!(
+            DecoderMatrix::<Gf256>::with_layout(half_width, half_width, MatrixLayout::CoefficientSeparated).map(|_| ()),
+            Err(RLNCError::ParametersTooLarge)
+        );
+        assert_eq!(DecoderMatrix::<Gf256>::new(1, usize::MAX).map(|_| ()), Err(RLNCError::ParametersTooLarge));
+
+        // Coding vector alone overflows, as each coefficient takes two bytes.
+        assert_eq!(
+            DecoderMatrix::<Gf65536>::new(usize::MAX / 2 + 1, 2).map(|_| ()),
+            Err(RLNCError::ParametersTooLarge)
+        );
+    }
+
+    #[test]
+    fn test_add_row_rejects_rows_beyond_capacity() {
+        let mut rng = rand::rng();
+
+        let num_pieces = 4;
+        let piece_byte_len = 8;
+
+        let mut matrix: DecoderMatrix = DecoderMatrix::new(num_pieces, piece_byte_len).expect("creating matrix must not fail");
+        let mut make_random_row = || (0..(num_pieces + piece_byte_len)).map(|_| rng.random()).collect::<Vec<u8>>();
+
+        (0..num_pieces).for_each(|_| {
+            matrix.add_row(&make_random_row()).expect("adding new must not fail");
+        });
+        assert_eq!(matrix.add_row(&make_random_row()).map(|_| ()), Err(RLNCError::ReceivedAllPieces));
+
+        // Once brought back to RREF, a full rank matrix drops any further row, as it is linearly dependent.
+        matrix.clear();
+        while matrix.rank() < num_pieces {
+            matrix.add_row_and_reduce(&make_random_row()).expect("adding new must not fail");
+        }
+
+        let full_rank = matrix.clone();
+        assert_eq!(matrix.add_row_and_reduce(&make_random_row()), Ok(false));
+        assert_eq!(
+            matrix.add_row_and_reduce(&[0u8; 1]),
+            Err(RLNCError::InvalidPieceLength {
+                expected: full_rank.cols,
+                got: 1
+            })
+        );
+        assert_eq!(matrix, full_rank);
+    }
+
+    #[test]
+    #[should_panic(expected = "row indices (0, 2) out of bounds for matrix of 2 rows")]
+    fn test_swap_rows_out_of_bounds_panics() {
+        let mut matrix = make_random_matrix(2, 4, &mut rand::rng());
+        matrix.swap_rows(0, 2);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "coefficient index (1, 4) out of bounds for matrix of 2 rows and 4 columns")]
+    fn test_coefficient_out_of_bounds_panics_in_debug_builds() {
+        let matrix = make_random_matrix(2, 4, &mut rand::rng());
+        matrix.coefficient(1, 4);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "coefficient index (2, 0) out of bounds for matrix of 2 rows and 4 columns")]
+    fn test_set_coefficient_out_of_bounds_panics_in_debug_builds() {
+        let mut matrix = make_random_matrix(2, 4, &mut rand::rng());
+        matrix.set_coefficient(2, 0, Gf256::one());
+    }
+
     #[test]
     fn prop_test_rref_is_idempotent() {
         const NUM_TEST_ITERATIONS: usize = 1000;
@@ -310,8 +1021,8 @@ mod test {
         let mut rng = rand::rng();
 
         (0..NUM_TEST_ITERATIONS).for_each(|_| {
-            let num_rows = rng.random_range(MIN_NUM_ROWS..=MAX_NUM_ROWS);
             let num_cols = rng.random_range(MIN_NUM_COLS..=MAX_NUM_COLS);
+            let num_rows = rng.random_range(MIN_NUM_ROWS..=MAX_NUM_ROWS.min(num_cols));
 
             let mut matrix = make_random_matrix(num_rows, num_cols, &mut rng);
             let rrefed = matrix.rref().clone().rref().to_owned();
@@ -319,4 +1030,323 @@ mod test {
             assert_eq!(matrix, rrefed);
         });
     }
+
+    #[test]
+    fn prop_test_rref_is_independent_of_layout() {
+        const NUM_TEST_ITERATIONS: usize = 100;
+
+        const MIN_NUM_PIECES: usize = 1;
+        const MAX_NUM_PIECES: usize = 128;
+
+        const MIN_PIECE_BYTE_LEN: usize = 1;
+        const MAX_PIECE_BYTE_LEN: usize = 256;
+
+        let mut rng = rand::rng();
+
+        (0..NUM_TEST_ITERATIONS).for_each(|_| {
+            let num_pieces = rng.random_range(MIN_NUM_PIECES..=MAX_NUM_PIECES);
+            let piece_byte_len = rng.random_range(MIN_PIECE_BYTE_LEN..=MAX_PIECE_BYTE_LEN);
+            let num_rows = rng.random_range(1..=(2 * num_pieces));
+
+            let mut interleaved: DecoderMatrix =
+                DecoderMatrix::with_layout(num_pieces, piece_byte_len, MatrixLayout::Interleaved).expect("creating matrix must not fail");
+            let mut coefficient_separated: DecoderMatrix =
+                DecoderMatrix::with_layout(num_pieces, piece_byte_len, MatrixLayout::CoefficientSeparated).expect("creating matrix must not fail");
+
+            (0..num_rows).for_each(|_| {
+                // Sparse coefficients make rank deficient matrices, with pivot-less columns, likely.
+                let row = (0..(num_pieces + piece_byte_len))
+                    .map(|col_idx| if col_idx < num_pieces && rng.random_bool(0.5) { 0 } else { rng.random() })
+                    .collect::<Vec<u8>>();
+
+                if interleaved.rank() == num_pieces {
+                    return;
+                }
+
+                interleaved.add_row(&row).expect("adding new must not fail").rref();
+                coefficient_separated.add_row(&row).expect("adding new must not fail").rref();
+            });
+
+            assert_eq!(interleaved.rank(), coefficient_separated.rank());
+            (0..interleaved.rank()).for_each(|row_idx| {
+                assert_eq!(interleaved.coefficients(row_idx), coefficient_separated.coefficients(row_idx));
+                assert_eq!(interleaved.payload(row_idx), coefficient_separated.payload(row_idx));
+            });
+            assert_eq!(interleaved.extract_data(), coefficient_separated.extract_data());
+        });
+    }
+
+    #[test]
+    fn prop_test_rref_is_independent_of_row_order() {
+        const NUM_TEST_ITERATIONS: usize = 100;
+
+        const MIN_NUM_PIECES: usize = 1;
+        const MAX_NUM_PIECES: usize = 128;
+
+        const MIN_PIECE_BYTE_LEN: usize = 1;
+        const MAX_PIECE_BYTE_LEN: usize = 256;
+
+        let mut rng = rand::rng();
+
+        (0..NUM_TEST_ITERATIONS).for_each(|_| {
+            let num_pieces = rng.random_range(MIN_NUM_PIECES..=MAX_NUM_PIECES);
+            let data_byte_len = rng.random_range(MIN_PIECE_BYTE_LEN..=MAX_PIECE_BYTE_LEN) * num_pieces;
+            let num_rows = rng.random_range(1..=num_pieces);
+
+            // Linearly dependent rows must be coded pieces of the same source pieces, as received ones are, or else their
+            // coded data would contradict each other, making the RREF of the coded data depend on which rows are kept.
+            let encoder = Encoder::new((0..data_byte_len).map(|_| rng.random()).collect(), num_pieces).expect("creating encoder must not fail");
+            let piece_byte_len = encoder.get_piece_byte_len();
+
+            // Sparse coefficients make linearly dependent rows and pivot-less columns likely.
+            let mut rows = (0..num_rows)
+                .map(|_| {
+                    let coding_vector = (0..num_pieces)
+                        .map(|_| if rng.random_bool(0.75) { 0 } else { rng.random() })
+                        .collect::<Vec<u8>>();
+                    encoder.code_with_coding_vector(&coding_vector).expect("coding must not fail").into_bytes()
+                })
+                .collect::<Vec<_>>();
+
+            for layout in [MatrixLayout::Interleaved, MatrixLayout::CoefficientSeparated] {
+                let mut in_order: DecoderMatrix = DecoderMatrix::with_layout(num_pieces, piece_byte_len, layout).expect("creating matrix must not fail");
+                let mut reordered = in_order.clone();
+                let mut incremental = in_order.clone();
+
+                rows.iter().for_each(|row| {
+                    in_order.add_row(row).expect("adding new must not fail");
+                });
+
+                rows.shuffle(&mut rng);
+                rows.iter().for_each(|row| {
+                    reordered.add_row(row).expect("adding new must not fail");
+                    incremental.add_row_and_reduce(row).expect("adding new must not fail");
+                });
+
+                in_order.rref();
+                reordered.rref();
+
+                assert_eq!(in_order.elements, reordered.elements);
+                assert_eq!(in_order.payloads, reordered.payloads);
+                assert_eq!(in_order, reordered);
+                assert_eq!(in_order, incremental);
+            }
+        });
+    }
+
+    #[test]
+    fn prop_test_rref_of_partially_reduced_matrix_matches_rref_from_scratch() {
+        const NUM_TEST_ITERATIONS: usize = 100;
+
+        const MIN_NUM_PIECES: usize = 1;
+        const MAX_NUM_PIECES: usize = 128;
+
+        const MIN_PIECE_BYTE_LEN: usize = 1;
+        const MAX_PIECE_BYTE_LEN: usize = 256;
+
+        let mut rng = rand::rng();
+
+        (0..NUM_TEST_ITERATIONS).for_each(|_| {
+            let num_pieces = rng.random_range(MIN_NUM_PIECES..=MAX_NUM_PIECES);
+            let data_byte_len = rng.random_range(MIN_PIECE_BYTE_LEN..=MAX_PIECE_BYTE_LEN) * num_pieces;
+            let num_rows = rng.random_range(1..=num_pieces);
+
+            let encoder = Encoder::new((0..data_byte_len).map(|_| rng.random()).collect(), num_pieces).expect("creating encoder must not fail");
+            let piece_byte_len = encoder.get_piece_byte_len();
+
+            // Sparse coefficients make linearly dependent rows and pivot-less columns likely.
+            let rows = (0..num_rows)
+                .map(|_| {
+                    let coding_vector = (0..num_pieces)
+                        .map(|_| if rng.random_bool(0.75) { 0 } else { rng.random() })
+                        .collect::<Vec<u8>>();
+                    encoder.code_with_coding_vector(&coding_vector).expect("coding must not fail").into_bytes()
+                })
+                .collect::<Vec<_>>();
+
+            for layout in [MatrixLayout::Interleaved, MatrixLayout::CoefficientSeparated] {
+                let mut from_scratch: DecoderMatrix = DecoderMatrix::with_layout(num_pieces, piece_byte_len, layout).expect("creating matrix must not fail");
+                let mut batched = from_scratch.clone();
+
+                rows.iter().for_each(|row| {
+                    from_scratch.add_row(row).expect("adding new must not fail");
+                });
+                from_scratch.rref();
+
+                // Test case 1: Rows added in batches, reducing the matrix in between, only the new rows getting reduced
+                rows.iter().for_each(|row| {
+                    batched.add_row(row).expect("adding new must not fail");
+                    if rng.random_bool(0.25) {
+                        batched.rref();
+                        assert_eq!(batched.pivot_cols().len(), batched.rank());
+                    }
+                });
+                batched.rref();
+
+                assert_eq!(batched, from_scratch);
+
+                // Test case 2: Rows modified after reduction are reduced again
+                let rank = batched.rank();
+                if rank == 0 {
+                    continue;
+                }
+
+                batched.swap_rows(0, rank - 1);
+                assert_eq!(batched.pivot_cols().len(), 0);
+                assert_eq!(batched.rref(), &from_scratch);
+
+                let pivot_col_idx = batched.pivot_cols()[rank - 1];
+                batched.set_coefficient(rank - 1, pivot_col_idx, Gf256::one());
+                assert_eq!(batched.pivot_cols().len(), rank - 1);
+                assert_eq!(batched.rref(), &from_scratch);
+            }
+        });
+    }
+
+    #[test]
+    fn prop_test_add_row_and_reduce_matches_rref() {
+        const NUM_TEST_ITERATIONS: usize = 100;
+
+        const MIN_NUM_PIECES: usize = 1;
+        const MAX_NUM_PIECES: usize = 128;
+
+        const MIN_PIECE_BYTE_LEN: usize = 1;
+        const MAX_PIECE_BYTE_LEN: usize = 256;
+
+        let mut rng = rand::rng();
+
+        (0..NUM_TEST_ITERATIONS).for_each(|_| {
+            let num_pieces = rng.random_range(MIN_NUM_PIECES..=MAX_NUM_PIECES);
+            let piece_byte_len = rng.random_range(MIN_PIECE_BYTE_LEN..=MAX_PIECE_BYTE_LEN);
+            let num_rows = rng.random_range(1..=(2 * num_pieces));
+
+            for layout in [MatrixLayout::Interleaved, MatrixLayout::CoefficientSeparated] {
+                let mut incremental: DecoderMatrix = DecoderMatrix::with_layout(num_pieces, piece_byte_len, layout).expect("creating matrix must not fail");
+                let mut full = DecoderMatrix::with_layout(num_pieces, piece_byte_len, layout).expect("creating matrix must not fail");
+
+                (0..num_rows).for_each(|_| {
+                    // Sparse coefficients make linearly dependent rows and pivot-less columns likely.
+                    let row = (0..(num_pieces + piece_byte_len))
+                        .map(|col_idx| if col_idx < num_pieces && rng.random_bool(0.75) { 0 } else { rng.random() })
+                        .collect::<Vec<u8>>();
+
+                    let rank_before = full.rank();
+                    if rank_before < num_pieces {
+                        full.add_row(&row).expect("adding new must not fail").rref();
+                    }
+
+                    let is_useful = incremental.add_row_and_reduce(&row).expect("adding new must not fail");
+
+                    assert_eq!(is_useful, full.rank() > rank_before);
+                    assert_eq!(incremental, full);
+                });
+            }
+        });
+    }
+
+    #[test]
+    fn prop_test_add_row_and_reduce_systematic_rows_matches_rref() {
+        const NUM_TEST_ITERATIONS: usize = 100;
+
+        const MIN_NUM_PIECES: usize = 1;
+        const MAX_NUM_PIECES: usize = 64;
+
+        const MIN_PIECE_BYTE_LEN: usize = 1;
+        const MAX_PIECE_BYTE_LEN: usize = 256;
+
+        let mut rng = rand::rng();
+
+        (0..NUM_TEST_ITERATIONS).for_each(|_| {
+            let num_pieces = rng.random_range(MIN_NUM_PIECES..=MAX_NUM_PIECES);
+            let piece_byte_len = rng.random_range(MIN_PIECE_BYTE_LEN..=MAX_PIECE_BYTE_LEN);
+            let num_rows = rng.random_range(1..=(2 * num_pieces));
+
+            for layout in [MatrixLayout::Interleaved, MatrixLayout::CoefficientSeparated] {
+                let mut incremental: DecoderMatrix = DecoderMatrix::with_layout(num_pieces, piece_byte_len, layout).expect("creating matrix must not fail");
+                let mut full = DecoderMatrix::with_layout(num_pieces, piece_byte_len, layout).expect("creating matrix must not fail");
+
+                (0..num_rows).for_each(|_| {
+                    // Mix of unit vectors, possibly at already pivot columns, and random rows, which are stored with
+                    // non-zero entries at pivot-less columns, so that the fast path still needs to eliminate.
+                    let mut row = (0..(num_pieces + piece_byte_len)).map(|_| rng.random()).collect::<Vec<u8>>();
+                    if rng.random_bool(0.75) {
+                        row[..num_pieces].fill(0);
+                        row[rng.random_range(0..num_pieces)] = 1;
+                    }
+
+                    let rank_before = full.rank();
+                    if rank_before < num_pieces {
+                        full.add_row(&row).expect("adding new must not fail").rref();
+                    }
+
+                    let is_useful = incremental.add_row_and_reduce(&row).expect("adding new must not fail");
+
+                    assert_eq!(is_useful, full.rank() > rank_before);
+                    assert_eq!(incremental, full);
+                });
+            }
+        });
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn prop_test_parallel_elimination_matches_serial() {
+        const NUM_TEST_ITERATIONS: usize = 20;
+
+        const MIN_NUM_PIECES: usize = 16;
+        const MAX_NUM_PIECES: usize = 96;
+
+        // Rows must be long enough, for the parallel elimination not to fall back to the serial one.
+        const MIN_PIECE_BYTE_LEN: usize = 1024;
+        const MAX_PIECE_BYTE_LEN: usize = 4096;
+
+        let mut rng = rand::rng();
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build().expect("Failed to build thread pool");
+
+        (0..NUM_TEST_ITERATIONS).for_each(|_| {
+            let num_pieces = rng.random_range(MIN_NUM_PIECES..=MAX_NUM_PIECES);
+            let piece_byte_len = rng.random_range(MIN_PIECE_BYTE_LEN..=MAX_PIECE_BYTE_LEN);
+            let num_rows = rng.random_range(1..=(2 * num_pieces));
+
+            for layout in [MatrixLayout::Interleaved, MatrixLayout::CoefficientSeparated] {
+                let mut serial: DecoderMatrix = DecoderMatrix::with_layout(num_pieces, piece_byte_len, layout).expect("creating matrix must not fail");
+                let mut parallel: DecoderMatrix = DecoderMatrix::with_layout(num_pieces, piece_byte_len, layout).expect("creating matrix must not fail");
+
+                let mut serial_incremental: DecoderMatrix =
+                    DecoderMatrix::with_layout(num_pieces, piece_byte_len, layout).expect("creating matrix must not fail");
+                let mut parallel_incremental: DecoderMatrix =
+                    DecoderMatrix::with_layout(num_pieces, piece_byte_len, layout).expect("creating matrix must not fail");
+
+                (0..num_rows).for_each(|row_idx| {
+                    // Sparse coefficients make linearly dependent rows and pivot-less columns likely.
+                    let row = (0..(num_pieces + piece_byte_len))
+                        .map(|col_idx| if col_idx < num_pieces && rng.random_bool(0.5) { 0 } else { rng.random() })
+                        .collect::<Vec<u8>>();
+
+                    // Unreduced matrices can't hold more rows than there are pieces coded together.
+                    if row_idx < num_pieces {
+                        serial.add_row(&row).expect("adding new must not fail");
+                        parallel.add_row(&row).expect("adding new must not fail");
+                    }
+
+                    let is_useful_serial = serial_incremental
+                        .add_row_and_reduce_with(&row, Elimination::Serial)
+                        .expect("adding new must not fail");
+                    let is_useful_parallel = pool.install(|| {
+                        parallel_incremental
+                            .add_row_and_reduce_with(&row, Elimination::Parallel)
+                            .expect("adding new must not fail")
+                    });
+
+                    assert_eq!(is_useful_serial, is_useful_parallel);
+                    assert_eq!(serial_incremental, parallel_incremental);
+                });
+
+                serial.rref_with(Elimination::Serial);
+                pool.install(|| parallel.rref_with(Elimination::Parallel));
+
+                assert_eq!(serial, parallel);
+            }
+        });
+    }
 }
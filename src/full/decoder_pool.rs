@@ -0,0 +1,227 @@
+use super::{coded_piece::CodedPiece, decoder::Decoder};
+use crate::RLNCError;
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    hash::Hash,
+};
+
+/// Pool of decoders, one per object, for decoding many independent objects, whose coded pieces are interleaved over a
+/// single connection, e.g. tagged by an object id of type `K`. All objects share the same piece byte length and number
+/// of pieces coded together, so that a decoder is lazily created, using `Decoder::new`, on the first piece of an object.
+///
+/// Once an object is decoded, its data is taken out of the pool, using `Self::take_decoded`, which also frees the decoder.
+/// A decoder is only created on the first piece which it accepts, while the pool doesn't bound the number of keys it
+/// holds otherwise, so that objects which never get decoded, e.g. abandoned by their sender, must be dropped using
+/// `Self::remove`.
+#[derive(Clone, Debug)]
+pub struct DecoderPool<K> {
+    decoders: HashMap<K, Decoder>,
+    piece_byte_len: usize,
+    required_piece_count: usize,
+}
+
+impl<K: Eq + Hash> DecoderPool<K> {
+    /// Creates a new, empty, `DecoderPool`, whose decoders decode `required_piece_count` many pieces, of `piece_byte_len`
+    /// bytes each.
+    ///
+    /// # Returns
+    /// Returns `Ok(DecoderPool)` on successful creation.
+    /// Returns `Err(RLNCError::PieceLengthZero)` if `piece_byte_len` is zero.
+    /// Returns `Err(RLNCError::PieceCountZero)` if `required_piece_count` is zero.
+    /// Returns `Err(RLNCError::ParametersTooLarge)` if the decoder's matrix would be larger than `isize::MAX` bytes.
+    pub fn new(piece_byte_len: usize, required_piece_count: usize) -> Result<DecoderPool<K>, RLNCError> {
+        // Validates the parameters upfront, so that lazily creating a decoder, per object, never fails.
+        Decoder::new(piece_byte_len, required_piece_count)?;
+
+        Ok(DecoderPool {
+            decoders: HashMap::new(),
+            piece_byte_len,
+            required_piece_count,
+        })
+    }
+
+    /// Byte length of each piece, the original data of every object got splitted into.
+    pub fn get_piece_byte_len(&self) -> usize {
+        self.piece_byte_len
+    }
+
+    /// Number of pieces, the original data of every object got splitted into and coded together.
+    pub fn get_num_pieces_coded_together(&self) -> usize {
+        self.required_piece_count
+    }
+
+    /// Number of objects held by the pool, being decoded or decoded but not yet taken out.
+    pub fn len(&self) -> usize {
+        self.decoders.len()
+    }
+
+    /// Checks if the pool holds no object.
+    pub fn is_empty(&self) -> bool {
+        self.decoders.is_empty()
+    }
+
+    /// Returns the decoder of the object identified by `key`, e.g. for inspecting its progress, if the pool holds it.
+    pub fn get_decoder(&self, key: &K) -> Option<&Decoder> {
+        self.decoders.get(key)
+    }
+
+    /// Checks if the object identified by `key` is decoded, and not yet taken out of the pool.
+    pub fn is_decoded(&self, key: &K) -> bool {
+        self.decoders.get(key).is_some_and(Decoder::is_already_decoded)
+    }
+
+    /// Routes a full coded piece of the object identified by `key` to its decoder, creating the decoder if this is the
+    /// first piece of that object.
+    ///
+    /// # Returns
+    /// Returns `Ok(true)` if the object is decoded, after adding this piece, or `Ok(false)` if more useful pieces are needed.
+    /// Returns `Err(RLNCError::CodingVectorLengthMismatch)`, `Err(RLNCError::InvalidPieceLength)` or
+    /// `Err(RLNCError::TaggingMismatch)` if the `full_coded_piece` was produced with a different configuration, same as
+    /// `Decoder::decode` does.
+    /// Returns any other error returned by `Decoder::decode`, e.g. `Err(RLNCError::ZeroCodingVector)`, or
+    /// `Err(RLNCError::ReceivedAllPieces)`, if the object is already decoded.
+    /// No decoder is created for `key` if this is the first piece of that object, and it gets rejected.
+    pub fn decode(&mut self, key: K, full_coded_piece: &CodedPiece) -> Result<bool, RLNCError> {
+        full_coded_piece.check_configuration(self.required_piece_count, self.piece_byte_len, false)?;
+
+        match self.decoders.entry(key) {
+            Entry::Occupied(entry) => {
+                let decoder = entry.into_mut();
+                decoder.decode(full_coded_piece)?;
+
+                Ok(decoder.is_already_decoded())
+            }
+            Entry::Vacant(entry) => {
+                // Decoder is inserted only after it accepts the piece, so that a rejected first piece doesn't leave
+                // behind a decoder, which may never gain rank.
+                let mut decoder = Decoder::new(self.piece_byte_len, self.required_piece_count)?;
+                decoder.decode(full_coded_piece)?;
+
+                Ok(entry.insert(decoder).is_already_decoded())
+            }
+        }
+    }
+
+    /// Takes the decoded data of the object identified by `key` out of the pool, freeing its decoder, once it's decoded.
+    ///
+    /// # Returns
+    /// Returns `Some(Vec<u8>)`, the original data of the object, if it is decoded.
+    /// Returns `None` if the pool doesn't hold the object, or it is not yet decoded, in which case the pool is left as is,
+    /// or if the decoded data turned out to be malformed, see `Decoder::get_decoded_data`, in which case the object is
+    /// dropped from the pool.
+    pub fn take_decoded(&mut self, key: &K) -> Option<Vec<u8>> {
+        if !self.is_decoded(key) {
+            return None;
+        }
+
+        self.decoders.remove(key)?.get_decoded_data().ok()
+    }
+
+    /// Removes the object identified by `key` from the pool, e.g. when giving up on it, returning its decoder, if any.
+    pub fn remove(&mut self, key: &K) -> Option<Decoder> {
+        self.decoders.remove(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DecoderPool;
+    use crate::{
+        RLNCError,
+        full::{coded_piece::CodedPiece, encoder::Encoder},
+    };
+    use rand::{Rng, seq::SliceRandom};
+
+    #[test]
+    fn test_decoder_pool_new_invalid_inputs() {
+        assert_eq!(DecoderPool::<u32>::new(0, 16).map(|_| ()), Err(RLNCError::PieceLengthZero));
+        assert_eq!(DecoderPool::<u32>::new(16, 0).map(|_| ()), Err(RLNCError::PieceCountZero));
+        assert_eq!(DecoderPool::<u32>::new(usize::MAX, 2).map(|_| ()), Err(RLNCError::ParametersTooLarge));
+    }
+
+    #[test]
+    fn test_decoder_pool_interleaved_objects() {
+        let mut rng = rand::rng();
+
+        let num_objects = 8u32;
+        let piece_count = 16usize;
+        let piece_byte_len = 64usize;
+
+        // Objects are made to split into pieces of the same byte length, as all decoders of the pool share it.
+        let objects = (0..num_objects)
+            .map(|_| {
+                let data_byte_len = rng.random_range((piece_count * (piece_byte_len - 1))..(piece_count * piece_byte_len));
+                (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>()
+            })
+            .collect::<Vec<_>>();
+        let encoders = objects
+            .iter()
+            .map(|data| Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder"))
+            .collect::<Vec<_>>();
+        assert!(encoders.iter().all(|encoder| encoder.get_piece_byte_len() == piece_byte_len));
+
+        let mut pool = DecoderPool::new(piece_byte_len, piece_count).expect("Failed to create DecoderPool");
+        assert!(pool.is_empty());
+
+        // Test case 1: Pieces of a different configuration are rejected, without creating a decoder
+        let mismatching_encoder = Encoder::new(objects[0].clone(), piece_count / 2).expect("Failed to create Encoder");
+        assert_eq!(
+            pool.decode(0, &mismatching_encoder.code(&mut rng)),
+            Err(RLNCError::CodingVectorLengthMismatch {
+                expected: piece_count,
+                got: piece_count / 2
+            })
+        );
+
+        let tagged_encoder = Encoder::new_tagged(objects[0].clone(), piece_count, 1).expect("Failed to create Encoder");
+        assert_eq!(pool.decode(0, &tagged_encoder.code(&mut rng)), Err(RLNCError::TaggingMismatch));
+
+        let short_coded_piece =
+            CodedPiece::from_bytes(vec![1u8; piece_count + piece_byte_len - 1], piece_count, piece_byte_len - 1).expect("Failed to parse short piece");
+        assert_eq!(
+            pool.decode(0, &short_coded_piece),
+            Err(RLNCError::InvalidPieceLength {
+                expected: piece_count + piece_byte_len,
+                got: piece_count + piece_byte_len - 1
+            })
+        );
+        assert!(pool.is_empty());
+
+        // Test case 2: First piece of an object, with an all-zero coding vector, is rejected, without creating a decoder
+        let zero_coded_piece = encoders[0]
+            .code_with_coding_vector(&vec![0u8; piece_count])
+            .expect("Coding with zero coding vector must not fail");
+        assert_eq!(pool.decode(0, &zero_coded_piece), Err(RLNCError::ZeroCodingVector));
+        assert!(pool.is_empty());
+
+        // Test case 3: Interleaved pieces of all objects are routed to their own decoders
+        let mut keys = (0..num_objects).flat_map(|key| core::iter::repeat_n(key, piece_count + 4)).collect::<Vec<_>>();
+        keys.shuffle(&mut rng);
+
+        for key in keys {
+            match pool.decode(key, &encoders[key as usize].code(&mut rng)) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) | Err(RLNCError::ReceivedAllPieces) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+        }
+
+        assert_eq!(pool.len(), num_objects as usize);
+        assert!((0..num_objects).all(|key| pool.is_decoded(&key)));
+        assert_eq!(pool.decode(0, &encoders[0].code(&mut rng)), Err(RLNCError::ReceivedAllPieces));
+
+        // Test case 4: Decoded objects are taken out of the pool, exactly once
+        (0..num_objects).for_each(|key| {
+            assert_eq!(pool.take_decoded(&key), Some(objects[key as usize].clone()));
+            assert_eq!(pool.take_decoded(&key), None);
+        });
+        assert!(pool.is_empty());
+
+        // Test case 5: Objects not yet decoded stay in the pool
+        assert_eq!(pool.decode(1, &encoders[1].code(&mut rng)), Ok(false));
+        assert_eq!(pool.take_decoded(&1), None);
+        assert_eq!(pool.get_decoder(&1).map(|decoder| decoder.rank()), Some(1));
+
+        assert!(pool.remove(&1).is_some());
+        assert!(pool.is_empty());
+    }
+}
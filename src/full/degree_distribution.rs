@@ -0,0 +1,165 @@
+//! Degree distributions, for `Encoder::code_with_degree_distribution`, which make RLNC behave like an LT/ fountain code,
+//! where each coded piece combines a randomly sampled number of source pieces, instead of all of them.
+
+use crate::RLNCError;
+use alloc::vec::Vec;
+use rand::{Rng, distr::Distribution};
+
+/// Robust soliton distribution, as defined by M. Luby in "LT Codes", over degrees `1..=k`, parameterized by `c` and `delta`.
+///
+/// It mixes the ideal soliton distribution, where degree `1` has probability `1/k` and degree `d > 1` has probability
+/// `1/(d(d-1))`, with an extra distribution, which boosts low degrees and adds a spike at degree `k/R`, where
+/// `R = c * ln(k/delta) * sqrt(k)`, so that the decoding process doesn't stall. Luby shows that `k + O(sqrt(k) * ln^2(k/delta))`
+/// coded pieces suffice for recovering all source pieces, with probability at least `1 - delta`, when belief propagation
+/// decoding is used. Gaussian elimination, as done by `Decoder`, needs no more than that.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RobustSoliton {
+    k: usize,
+    /// Cumulative distribution function, `cdf[d - 1]` being the probability of sampling a degree at most `d`.
+    cdf: Vec<f64>,
+}
+
+impl RobustSoliton {
+    /// Creates a robust soliton distribution over degrees `1..=k`.
+    ///
+    /// # Arguments
+    /// * `k` - Number of source pieces, coded together, i.e. `Encoder::get_piece_count`.
+    /// * `c` - Positive constant, tuning the expected number of degree `1` coded pieces. Values in `0.01..=0.5` are
+    ///   common in literature.
+    /// * `delta` - Bound on the probability of decoding failure, after receiving `k + O(sqrt(k) * ln^2(k/delta))` coded
+    ///   pieces. Must be in `(0, 1)`.
+    ///
+    /// # Returns
+    /// Returns `Ok(RobustSoliton)` on success.
+    /// Returns `Err(RLNCError::PieceCountZero)` if `k` is zero.
+    /// Returns `Err(RLNCError::InvalidDegreeDistribution)` if `c` is not positive, or `delta` is not in `(0, 1)`.
+    pub fn new(k: usize, c: f64, delta: f64) -> Result<RobustSoliton, RLNCError> {
+        if k == 0 {
+            return Err(RLNCError::PieceCountZero);
+        }
+
+        let is_valid_c = c > 0.0 && c.is_finite();
+        let is_valid_delta = delta > 0.0 && delta < 1.0;
+        if !is_valid_c || !is_valid_delta {
+            return Err(RLNCError::InvalidDegreeDistribution);
+        }
+
+        let k_f64 = k as f64;
+        let r = c * (k_f64 / delta).ln() * k_f64.sqrt();
+
+        // Degree at which the extra distribution spikes, kept within `1..=k`.
+        let spike_degree = ((k_f64 / r).floor() as usize).clamp(1, k);
+
+        let ideal_soliton = |d: usize| if d == 1 { 1.0 / k_f64 } else { 1.0 / (d as f64 * (d as f64 - 1.0)) };
+        let extra = |d: usize| match d.cmp(&spike_degree) {
+            core::cmp::Ordering::Less => r / (d as f64 * k_f64),
+            core::cmp::Ordering::Equal => r * (r / delta).ln() / k_f64,
+            core::cmp::Ordering::Greater => 0.0,
+        };
+
+        let mut cdf = (1..=k)
+            .scan(0.0, |cumulative_weight, d| {
+                // Spike weight is negative when `R < delta`, i.e. for tiny `c`, which is clamped.
+                *cumulative_weight += ideal_soliton(d) + extra(d).max(0.0);
+                Some(*cumulative_weight)
+            })
+            .collect::<Vec<f64>>();
+
+        let normalizer = cdf[k - 1];
+        cdf.iter_mut().for_each(|cumulative_probability| *cumulative_probability /= normalizer);
+
+        Ok(RobustSoliton { k, cdf })
+    }
+
+    /// Largest degree, this distribution samples, which is the number of source pieces it was created for.
+    pub fn get_piece_count(&self) -> usize {
+        self.k
+    }
+
+    /// Returns the probability of sampling degree `d`, which is zero, unless `d` is in `1..=k`.
+    pub fn probability(&self, d: usize) -> f64 {
+        match d {
+            0 => 0.0,
+            1 => self.cdf[0],
+            d if d <= self.k => self.cdf[d - 1] - self.cdf[d - 2],
+            _ => 0.0,
+        }
+    }
+}
+
+impl Distribution<usize> for RobustSoliton {
+    /// Samples a degree in `1..=k`, by inverting the cumulative distribution function.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        let u = rng.random::<f64>();
+        (self.cdf.partition_point(|&cumulative_probability| cumulative_probability <= u) + 1).min(self.k)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RLNCError, RobustSoliton};
+    use rand::distr::Distribution;
+
+    #[test]
+    fn test_robust_soliton_new_invalid_inputs() {
+        assert_eq!(RobustSoliton::new(0, 0.1, 0.05), Err(RLNCError::PieceCountZero));
+        assert_eq!(RobustSoliton::new(64, 0.0, 0.05), Err(RLNCError::InvalidDegreeDistribution));
+        assert_eq!(RobustSoliton::new(64, f64::NAN, 0.05), Err(RLNCError::InvalidDegreeDistribution));
+        assert_eq!(RobustSoliton::new(64, 0.1, 0.0), Err(RLNCError::InvalidDegreeDistribution));
+        assert_eq!(RobustSoliton::new(64, 0.1, 1.0), Err(RLNCError::InvalidDegreeDistribution));
+    }
+
+    #[test]
+    fn test_robust_soliton_probabilities() {
+        for (k, c, delta) in [(1usize, 0.1, 0.5), (2, 0.1, 0.5), (64, 0.1, 0.05), (1000, 0.03, 0.5), (1000, 0.5, 0.01)] {
+            let distribution = RobustSoliton::new(k, c, delta).expect("Creating distribution with valid parameters must not fail");
+            assert_eq!(distribution.get_piece_count(), k);
+
+            let total_probability = (0..=(k + 1)).map(|d| distribution.probability(d)).sum::<f64>();
+            assert!((total_probability - 1.0).abs() < 1e-9, "{total_probability}");
+
+            assert_eq!(distribution.probability(0), 0.0);
+            assert_eq!(distribution.probability(k + 1), 0.0);
+            assert!((1..=k).all(|d| distribution.probability(d) >= 0.0));
+        }
+
+        // Degree 2 is the most likely one, while the spike at `k/R` stands out from its neighbours.
+        let (k, c, delta) = (1000usize, 0.03, 0.5);
+        let distribution = RobustSoliton::new(k, c, delta).expect("Creating distribution with valid parameters must not fail");
+        assert!((1..=k).all(|d| distribution.probability(d) <= distribution.probability(2)));
+
+        let r = c * (k as f64 / delta).ln() * (k as f64).sqrt();
+        let spike_degree = (k as f64 / r).floor() as usize;
+        assert!(distribution.probability(spike_degree) > distribution.probability(spike_degree - 1));
+        assert!(distribution.probability(spike_degree) > distribution.probability(spike_degree + 1));
+    }
+
+    #[test]
+    fn test_robust_soliton_sampling() {
+        const NUM_SAMPLES: usize = 100_000;
+
+        let mut rng = rand::rng();
+
+        let k = 64usize;
+        let distribution = RobustSoliton::new(k, 0.1, 0.05).expect("Creating distribution with valid parameters must not fail");
+
+        let mut histogram = vec![0usize; k + 1];
+        (0..NUM_SAMPLES).for_each(|_| {
+            let degree = distribution.sample(&mut rng);
+            assert!((1..=k).contains(&degree));
+
+            histogram[degree] += 1;
+        });
+
+        // Empirical frequency of each degree must be close to its probability, within ~5 standard deviations.
+        (1..=k).for_each(|d| {
+            let expected = distribution.probability(d) * NUM_SAMPLES as f64;
+            let tolerance = 5.0 * expected.sqrt() + 1.0;
+            assert!(
+                (histogram[d] as f64 - expected).abs() <= tolerance,
+                "degree {d}: {} vs {expected}",
+                histogram[d]
+            );
+        });
+    }
+}
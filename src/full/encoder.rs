@@ -1,26 +1,42 @@
-use super::consts::BOUNDARY_MARKER;
-use crate::RLNCError;
-use rand::Rng;
-
-#[cfg(all(feature = "parallel", not(any(target_arch = "x86", target_arch = "x86_64"))))]
-use crate::common::gf256::Gf256;
-#[cfg(not(feature = "parallel"))]
-use crate::common::simd::gf256_mul_vec_by_scalar_then_add_into_vec;
-#[cfg(all(feature = "parallel", any(target_arch = "x86", target_arch = "x86_64")))]
-use crate::common::simd::{gf256_inplace_add_vectors, gf256_inplace_mul_vec_by_scalar};
+use super::{
+    checksum::crc32,
+    coded_piece::CodedPiece,
+    consts::{BOUNDARY_MARKER, CHECKSUM_BYTE_LEN, GENERATION_TAG_BYTE_LEN, MAX_BUFFER_BYTE_LEN, VANDERMONDE_EVALUATION_POINT_COUNT},
+    decoder::Decoder,
+    frame::{FRAME_HEADER_BYTE_LEN, encode_header},
+    padding::{pad, pad_into_pieces},
+    seed::coding_vector_from_seed,
+};
+use crate::{
+    RLNCError,
+    common::{field::Field, gf256::Gf256, simd::gf256_mul_vec_by_scalar_then_add_into_vec},
+};
+use alloc::{borrow::Cow, vec, vec::Vec};
+use rand::{Rng, RngCore, distr::Distribution};
+
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
 /// Represents an RLNC encoder, responsible for dividing data into pieces and
 /// generating coded pieces based on random sampled coding vectors.
+///
+/// An encoder either owns the padded data, when created using `Self::new`, or borrows the bulk of it, for lifetime `'a`,
+/// when created using `Self::new_borrowed`, in which case only the trailing pieces, holding the boundary marker and the
+/// padding, are owned.
 #[derive(Clone, Debug)]
-pub struct Encoder {
-    data: Vec<u8>,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(try_from = "UncheckedEncoder"))]
+pub struct Encoder<'a> {
+    /// Leading pieces of the padded data, each of `piece_byte_len` bytes.
+    data: Cow<'a, [u8]>,
+    /// Trailing pieces of the padded data, each of `piece_byte_len` bytes, which are not part of the borrowed data.
+    /// Empty, unless the data is borrowed.
+    tail: Vec<u8>,
     piece_count: usize,
     piece_byte_len: usize,
+    generation_tag: Option<u32>,
 }
 
-impl Encoder {
+impl<'a> Encoder<'a> {
     /// Number of pieces original data got splitted into and being coded together.
     pub fn get_piece_count(&self) -> usize {
         self.piece_count
@@ -31,21 +47,47 @@ impl Encoder {
         self.piece_byte_len
     }
 
+    /// Generation tag prepended to each full coded piece, if the encoder was created using `Self::new_tagged`.
+    pub fn get_generation_tag(&self) -> Option<u32> {
+        self.generation_tag
+    }
+
     /// Each full coded piece consists of `self.get_piece_count()` random coefficients, appended by corresponding encoded piece of `self.get_piece_byte_len()` bytes.
+    /// If the encoder is tagged, the full coded piece is additionally prefixed by a `GENERATION_TAG_BYTE_LEN` -bytes generation tag.
     pub fn get_full_coded_piece_byte_len(&self) -> usize {
-        self.get_piece_count() + self.get_piece_byte_len()
+        self.get_generation_tag_byte_len() + self.get_piece_count() + self.get_piece_byte_len()
+    }
+
+    /// Byte length of the original data, the encoder was created with, i.e. excluding the boundary marker and the zero
+    /// padding. Same as what `Decoder::original_data_len` recovers, once decoding completes, so that it can be sent
+    /// ahead of the coded pieces, letting the receiver allocate the exact receive buffer.
+    pub fn original_data_len(&self) -> usize {
+        let padded_data_len = self.piece_count * self.piece_byte_len;
+
+        // Boundary marker is the last non-zero byte of the padded data, which sits at the original data length.
+        self.tail
+            .iter()
+            .rev()
+            .chain(self.data.iter().rev())
+            .position(|&byte| byte != 0)
+            .map_or(0, |boundary_marker_rev_index| padded_data_len - 1 - boundary_marker_rev_index)
+    }
+
+    fn get_generation_tag_byte_len(&self) -> usize {
+        if self.generation_tag.is_some() { GENERATION_TAG_BYTE_LEN } else { 0 }
     }
 
     /// Creates a new `Encoder` without adding any padding to the input data.
     /// This is suitable if the input data length is already a multiple of the
-    /// desired piece count. This interface is used by Recoder.
+    /// desired piece count. This interface is used when deserializing an Encoder.
     ///
     /// Returns `Ok(Encoder)` on success.
     /// Returns `Err(RLNCError::DataLengthZero)` if `data` is empty.
     /// Returns `Err(RLNCError::PieceCountZero)` if `piece_count` is zero.
     /// Returns `Err(RLNCError::DataLengthMismatch)` if the data length is not a
     /// multiple of the piece count.
-    pub(crate) fn without_padding(data: Vec<u8>, piece_count: usize) -> Result<Encoder, RLNCError> {
+    #[cfg(any(test, feature = "serde"))]
+    pub(crate) fn without_padding(data: Vec<u8>, piece_count: usize) -> Result<Encoder<'a>, RLNCError> {
         if data.is_empty() {
             return Err(RLNCError::DataLengthZero);
         }
@@ -62,12 +104,61 @@ impl Encoder {
         }
 
         Ok(Encoder {
-            data,
+            data: Cow::Owned(data),
+            tail: Vec::new(),
             piece_count,
             piece_byte_len,
+            generation_tag: None,
         })
     }
 
+    /// Creates a new `Encoder` holding no pieces yet, with room for `piece_capacity` pieces of `piece_byte_len` bytes
+    /// each, which can be appended using `Self::push_piece`. This interface is used by Recoder.
+    pub(crate) fn with_piece_capacity(piece_byte_len: usize, piece_capacity: usize) -> Encoder<'a> {
+        Encoder {
+            data: Cow::Owned(Vec::with_capacity(piece_capacity * piece_byte_len)),
+            tail: Vec::new(),
+            piece_count: 0,
+            piece_byte_len,
+            generation_tag: None,
+        }
+    }
+
+    /// Creates a new `Encoder` over `pieces`, holding concatenated pieces of `piece_byte_len` bytes each, taking ownership
+    /// of them, without copying, nor padding. This interface is used by Recoder.
+    pub(crate) fn with_owned_pieces(pieces: Vec<u8>, piece_byte_len: usize) -> Encoder<'a> {
+        debug_assert_eq!(pieces.len() % piece_byte_len, 0);
+
+        Encoder {
+            piece_count: pieces.len() / piece_byte_len,
+            data: Cow::Owned(pieces),
+            tail: Vec::new(),
+            piece_byte_len,
+            generation_tag: None,
+        }
+    }
+
+    /// Returns an iterator over the pieces being coded together, each of `self.get_piece_byte_len()` bytes.
+    pub(crate) fn pieces(&self) -> impl Iterator<Item = &[u8]> {
+        self.data.chunks_exact(self.piece_byte_len).chain(self.tail.chunks_exact(self.piece_byte_len))
+    }
+
+    /// Appends one more piece, of `self.get_piece_byte_len()` bytes, to the pieces being coded together.
+    pub(crate) fn push_piece(&mut self, piece: &[u8]) {
+        debug_assert_eq!(piece.len(), self.piece_byte_len);
+
+        self.data.to_mut().extend_from_slice(piece);
+        self.piece_count += 1;
+    }
+
+    /// Overwrites `piece_idx` -th piece, being coded together, with `piece`, of `self.get_piece_byte_len()` bytes.
+    pub(crate) fn replace_piece(&mut self, piece_idx: usize, piece: &[u8]) {
+        debug_assert_eq!(piece.len(), self.piece_byte_len);
+
+        let piece_begins_at = piece_idx * self.piece_byte_len;
+        self.data.to_mut()[piece_begins_at..(piece_begins_at + self.piece_byte_len)].copy_from_slice(piece);
+    }
+
     /// Creates a new `Encoder` while padding the input data.
     ///
     /// The input data is padded with zeros to ensure its length is a multiple
@@ -76,11 +167,168 @@ impl Encoder {
     /// `piece_count` pieces. A boundary marker (`BOUNDARY_MARKER`) is placed
     /// at the end of the original data before zero padding.
     ///
+    /// `piece_count` may exceed `data.len() + 1`, i.e. the byte length of the original data plus the boundary marker,
+    /// in which case each piece is a single byte, and the trailing `piece_count - data.len() - 1` pieces are nothing but
+    /// zero padding. Such an encoder still codes and decodes correctly, so that a fixed generation size can be used
+    /// for arbitrarily small objects, though each coded piece then carries a `piece_count` -bytes coding vector for a
+    /// single byte of coded data. So, prefer `piece_count` well below `data.len()`, unless the generation size is fixed.
+    ///
+    /// # Returns
+    /// Returns `Ok(Encoder)` on success.
+    /// Returns `Err(RLNCError::DataLengthZero)` if `data` is empty.
+    /// Returns `Err(RLNCError::PieceCountZero)` if `piece_count` is zero.
+    /// Returns `Err(RLNCError::ParametersTooLarge)` if the padded data would be longer than `isize::MAX` bytes.
+    pub fn new(mut data: Vec<u8>, piece_count: usize) -> Result<Encoder<'a>, RLNCError> {
+        if data.is_empty() {
+            return Err(RLNCError::DataLengthZero);
+        }
+        if piece_count == 0 {
+            return Err(RLNCError::PieceCountZero);
+        }
+
+        let piece_byte_len = pad(&mut data, piece_count)?;
+
+        Ok(Encoder {
+            data: Cow::Owned(data),
+            tail: Vec::new(),
+            piece_count,
+            piece_byte_len,
+            generation_tag: None,
+        })
+    }
+
+    /// Creates a new `Encoder` while padding the input data, same as `Self::new`, but each full coded piece
+    /// produced by this encoder is prefixed by `generation_tag`, serialized as `GENERATION_TAG_BYTE_LEN`
+    /// little-endian bytes. A decoder created using `Decoder::new_tagged` with the same tag, rejects pieces
+    /// carrying any other tag, which prevents pieces of different generations, multiplexed over the same stream,
+    /// from contaminating each other.
+    ///
+    /// # Returns
+    /// Returns `Ok(Encoder)` on success.
+    /// Returns `Err(RLNCError::DataLengthZero)` if `data` is empty.
+    /// Returns `Err(RLNCError::PieceCountZero)` if `piece_count` is zero.
+    /// Returns `Err(RLNCError::ParametersTooLarge)` if the padded data would be longer than `isize::MAX` bytes.
+    pub fn new_tagged(data: Vec<u8>, piece_count: usize, generation_tag: u32) -> Result<Encoder<'a>, RLNCError> {
+        let mut encoder = Self::new(data, piece_count)?;
+        encoder.generation_tag = Some(generation_tag);
+
+        Ok(encoder)
+    }
+
+    /// Creates a new `Encoder` while padding the input data, same as `Self::new` does, but refusing parameters, which
+    /// would make the padded data longer than `max_buffer_byte_len` bytes, before padding it, e.g. when `piece_count`
+    /// is dictated by an untrusted peer, as even tiny data gets padded to at least `piece_count` bytes.
+    ///
+    /// # Returns
+    /// Same as `Self::new`, while `Err(RLNCError::ParametersTooLarge)` is returned if the padded data would be longer than
+    /// `max_buffer_byte_len` bytes.
+    pub fn new_bounded(data: Vec<u8>, piece_count: usize, max_buffer_byte_len: usize) -> Result<Encoder<'a>, RLNCError> {
+        if data.is_empty() {
+            return Err(RLNCError::DataLengthZero);
+        }
+        if piece_count == 0 {
+            return Err(RLNCError::PieceCountZero);
+        }
+
+        let boundary_marker_len = 1;
+        let piece_byte_len = (data.len() + boundary_marker_len).div_ceil(piece_count);
+        checked_buffer_byte_len_within(piece_count, piece_byte_len, max_buffer_byte_len)?;
+
+        Self::new(data, piece_count)
+    }
+
+    /// Creates a new `Encoder` while padding the input data, same as `Self::new` does, but splitting it into pieces of
+    /// exactly `piece_byte_len` bytes, e.g. dictated by the block size of the underlying storage, instead of into a given
+    /// number of pieces. The piece count is derived as `(data.len() + 1).div_ceil(piece_byte_len)`, so that the original
+    /// data plus the boundary marker fits, and the last piece is zero padded.
+    ///
+    /// # Returns
+    /// Returns `Ok(Encoder)` on success.
+    /// Returns `Err(RLNCError::DataLengthZero)` if `data` is empty.
+    /// Returns `Err(RLNCError::PieceLengthZero)` if `piece_byte_len` is zero.
+    /// Returns `Err(RLNCError::ParametersTooLarge)` if the padded data would be longer than `isize::MAX` bytes.
+    pub fn with_piece_byte_len(mut data: Vec<u8>, piece_byte_len: usize) -> Result<Encoder<'a>, RLNCError> {
+        if data.is_empty() {
+            return Err(RLNCError::DataLengthZero);
+        }
+        if piece_byte_len == 0 {
+            return Err(RLNCError::PieceLengthZero);
+        }
+
+        let boundary_marker_len = 1;
+        let piece_count = (data.len() + boundary_marker_len).div_ceil(piece_byte_len);
+        pad_into_pieces(&mut data, piece_count, piece_byte_len)?;
+
+        Ok(Encoder {
+            data: Cow::Owned(data),
+            tail: Vec::new(),
+            piece_count,
+            piece_byte_len,
+            generation_tag: None,
+        })
+    }
+
+    /// Creates a new `Encoder` from concatenated untagged full coded pieces, e.g. collected by a relay, which has received
+    /// enough linearly independent pieces to promote itself to an origin encoder. Pieces are decoded internally, just like
+    /// `Decoder::from_pieces` does, and the recovered source pieces, including the boundary marker and the zero padding,
+    /// back the new encoder, so that it codes over exactly the same source pieces as the encoder which produced them.
+    ///
+    /// # Arguments
+    /// * `pieces` - Concatenated untagged full coded pieces, each of `full_coded_piece_byte_len` bytes.
+    /// * `full_coded_piece_byte_len` - The byte length of a full coded piece i.e. coding vector ++ coded piece.
+    /// * `piece_count` - The number of source pieces coded together, which is also the length of the coding vector.
+    ///
+    /// # Returns
+    /// Returns `Ok(Encoder)` on success.
+    /// Returns `Err(RLNCError::PieceLengthZero)` if `full_coded_piece_byte_len` is zero.
+    /// Returns `Err(RLNCError::PieceCountZero)` if `piece_count` is zero.
+    /// Returns `Err(RLNCError::PieceLengthTooShort)` if `full_coded_piece_byte_len` is not greater than `piece_count`.
+    /// Returns `Err(RLNCError::ParametersTooLarge)` if `piece_count` many full coded pieces would be longer than `isize::MAX` bytes.
+    /// Returns `Err(RLNCError::InvalidPieceLength)` if length of `pieces` is not a multiple of `full_coded_piece_byte_len`.
+    /// Returns `Err(RLNCError::NotAllPiecesReceivedYet)` if `pieces` don't span all `piece_count` source pieces.
+    /// Returns `Err(RLNCError::InvalidDecodedDataFormat)` if the recovered source pieces don't end with the boundary
+    /// marker, followed by zero padding.
+    pub fn try_from_coded_pieces(pieces: &[u8], full_coded_piece_byte_len: usize, piece_count: usize) -> Result<Encoder<'a>, RLNCError> {
+        if full_coded_piece_byte_len == 0 {
+            return Err(RLNCError::PieceLengthZero);
+        }
+        if piece_count == 0 {
+            return Err(RLNCError::PieceCountZero);
+        }
+        if full_coded_piece_byte_len <= piece_count {
+            return Err(RLNCError::PieceLengthTooShort);
+        }
+
+        let piece_byte_len = full_coded_piece_byte_len - piece_count;
+
+        let (decoder, _) = Decoder::from_pieces(piece_byte_len, piece_count, pieces)?;
+        if !decoder.is_already_decoded() {
+            return Err(RLNCError::NotAllPiecesReceivedYet);
+        }
+        if decoder.original_data_len().is_none() {
+            return Err(RLNCError::InvalidDecodedDataFormat);
+        }
+
+        let mut source_pieces = Vec::with_capacity(piece_count * piece_byte_len);
+        (0..piece_count).for_each(|piece_idx| source_pieces.extend_from_slice(decoder.matrix.payload(piece_idx)));
+
+        Ok(Self::with_owned_pieces(source_pieces, piece_byte_len))
+    }
+
+    /// Creates a new `Encoder` over borrowed `data`, without copying it, padding it the same way as `Self::new` does,
+    /// so that coded pieces are byte-for-byte identical to the ones produced by an encoder created using `Self::new`.
+    ///
+    /// Only the pieces fully covered by `data` are borrowed. The trailing pieces, holding the end of `data`, the boundary
+    /// marker and the zero padding, are copied into a small owned buffer, of at most `piece_count + piece_byte_len`
+    /// bytes. This is useful for serving the same large object, living in a long-lived buffer, to many peers. Same as
+    /// `Self::new`, `piece_count` may exceed `data.len() + 1`.
+    ///
     /// # Returns
     /// Returns `Ok(Encoder)` on success.
     /// Returns `Err(RLNCError::DataLengthZero)` if `data` is empty.
     /// Returns `Err(RLNCError::PieceCountZero)` if `piece_count` is zero.
-    pub fn new(mut data: Vec<u8>, piece_count: usize) -> Result<Encoder, RLNCError> {
+    /// Returns `Err(RLNCError::ParametersTooLarge)` if the padded data would be longer than `isize::MAX` bytes.
+    pub fn new_borrowed(data: &'a [u8], piece_count: usize) -> Result<Encoder<'a>, RLNCError> {
         if data.is_empty() {
             return Err(RLNCError::DataLengthZero);
         }
@@ -91,130 +339,575 @@ impl Encoder {
         let in_data_len = data.len();
         let boundary_marker_len = 1;
         let piece_byte_len = (in_data_len + boundary_marker_len).div_ceil(piece_count);
-        let padded_data_len = piece_count * piece_byte_len;
+        let padded_data_len = checked_buffer_byte_len(piece_count, piece_byte_len)?;
 
-        data.resize(padded_data_len, 0);
-        data[in_data_len] = BOUNDARY_MARKER;
+        let (borrowed_data, data_in_tail) = data.split_at((in_data_len / piece_byte_len) * piece_byte_len);
+
+        let mut tail = Vec::with_capacity(padded_data_len - borrowed_data.len());
+        tail.extend_from_slice(data_in_tail);
+        tail.push(BOUNDARY_MARKER);
+        tail.resize(padded_data_len - borrowed_data.len(), 0);
 
         Ok(Encoder {
-            data,
+            data: Cow::Borrowed(borrowed_data),
+            tail,
             piece_count,
             piece_byte_len,
+            generation_tag: None,
         })
     }
 
+    /// Grows the generation by one source piece, appending `piece`, of `self.get_piece_byte_len()` bytes, to the original
+    /// data, so that the original data, recovered by a decoder for `self.get_piece_count()` pieces, becomes the original
+    /// data the encoder was created with, followed by all the appended pieces, in order. Useful when source data arrives
+    /// incrementally, as there is no need for re-padding all of it, using `Self::new`.
+    ///
+    /// There is always room for the original data, the appended piece and the boundary marker in one more piece, so
+    /// that only the bytes after the original data, i.e. the boundary marker and zero padding, are overwritten, and the
+    /// boundary marker migrates to the end of the appended piece. For a borrowed encoder, those bytes, and so the
+    /// appended piece, live in the owned trailing pieces, so that the borrowed data is never copied.
+    ///
+    /// Coded pieces emitted before appending are not compatible with the grown generation. Their coding vectors are one
+    /// symbol shorter, so that decoders for the grown generation reject them with `RLNCError::InvalidPieceLength`, and
+    /// even zero-extending their coding vectors doesn't help, as the last source piece, they combine, got overwritten.
+    /// So, the generation should only be grown before its coded pieces are sent out, or by starting over with a new
+    /// generation tag, which makes receivers drop the stale pieces.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success, after which `self.get_piece_count()` is incremented by one.
+    /// Returns `Err(RLNCError::InvalidPieceLength)` if `piece` is not `self.get_piece_byte_len()` bytes long.
+    /// Returns `Err(RLNCError::ParametersTooLarge)` if the padded data would grow longer than `isize::MAX` bytes.
+    pub fn append_piece(&mut self, piece: &[u8]) -> Result<(), RLNCError> {
+        if piece.len() != self.piece_byte_len {
+            return Err(RLNCError::InvalidPieceLength {
+                expected: self.piece_byte_len,
+                got: piece.len(),
+            });
+        }
+
+        let padded_data_len = checked_buffer_byte_len(self.piece_count + 1, self.piece_byte_len)?;
+        let original_data_len = self.original_data_len();
+
+        // Unless the data is borrowed, the tail is empty, so that the boundary marker is in the owned data.
+        let (buffer, buffer_begins_at) = if self.tail.is_empty() {
+            (self.data.to_mut(), 0)
+        } else {
+            (&mut self.tail, self.data.len())
+        };
+
+        buffer.truncate(original_data_len - buffer_begins_at);
+        buffer.extend_from_slice(piece);
+        buffer.push(BOUNDARY_MARKER);
+        buffer.resize(padded_data_len - buffer_begins_at, 0);
+
+        self.piece_count += 1;
+        Ok(())
+    }
+
     /// Encodes the data held by the encoder using a provided coding vector.
     ///
-    /// The resulting coded piece is returned as a `Vec<u8>`, prefixed by the
-    /// coding vector itself (as `u8` values). The total length of the returned
-    /// vector is `self.get_complete_coded_piece_byte_len()`.
+    /// The resulting coded piece is returned as a `CodedPiece`, prefixed by the
+    /// coding vector itself (as `u8` values), and by the generation tag, if any. The total length of the returned
+    /// piece is `self.get_full_coded_piece_byte_len()`.
     ///
     /// Returns `RLNCError::CodingVectorLengthMismatch` if the length of the
     /// provided `coding_vector` does not match `self.piece_count`.
-    #[cfg(not(feature = "parallel"))]
-    pub fn code_with_coding_vector(&self, coding_vector: &[u8]) -> Result<Vec<u8>, RLNCError> {
+    ///
+    /// An all-zero `coding_vector` is accepted, producing a full coded piece whose coding vector and coded data are both
+    /// all-zero, which is useless to any decoder, as `Decoder::decode` rejects it with `RLNCError::ZeroCodingVector`. Use
+    /// `Self::code_with_nonzero_coding_vector` for catching such a coding vector, e.g. coming from a buggy caller, upfront.
+    pub fn code_with_coding_vector(&self, coding_vector: &[u8]) -> Result<CodedPiece, RLNCError> {
         if coding_vector.len() != self.piece_count {
-            return Err(RLNCError::CodingVectorLengthMismatch);
+            return Err(RLNCError::CodingVectorLengthMismatch {
+                expected: self.piece_count,
+                got: coding_vector.len(),
+            });
         }
 
         let mut full_coded_piece = vec![0u8; self.get_full_coded_piece_byte_len()];
-        full_coded_piece[..self.piece_count].copy_from_slice(coding_vector);
+        let tagless_full_coded_piece = self.write_generation_tag(&mut full_coded_piece);
 
-        let coded_piece = &mut full_coded_piece[self.piece_count..];
-        self.data
-            .chunks_exact(self.piece_byte_len)
-            .zip(coding_vector)
-            .for_each(|(piece, &random_symbol)| gf256_mul_vec_by_scalar_then_add_into_vec(coded_piece, piece, random_symbol));
+        let (coding_vector_part, coded_piece) = tagless_full_coded_piece.split_at_mut(self.piece_count);
+        coding_vector_part.copy_from_slice(coding_vector);
+        self.code_data_into(coding_vector, coded_piece);
 
-        Ok(full_coded_piece)
+        Ok(self.wrap_full_coded_piece(full_coded_piece))
     }
 
-    /// Encodes the data held by the encoder using a provided coding vector.
-    ///
-    /// The resulting coded piece is returned as a `Vec<u8>`, prefixed by the
-    /// coding vector itself (as `u8` values). The total length of the returned
-    /// vector is `self.get_complete_coded_piece_byte_len()`.
+    /// Same as `Self::code_with_coding_vector`, but refusing an all-zero `coding_vector`, instead of producing an all-zero,
+    /// useless, full coded piece.
     ///
-    /// Returns `RLNCError::CodingVectorLengthMismatch` if the length of the
-    /// provided `coding_vector` does not match `self.piece_count`.
-    #[cfg(feature = "parallel")]
-    pub fn code_with_coding_vector(&self, coding_vector: &[u8]) -> Result<Vec<u8>, RLNCError> {
+    /// # Returns
+    /// Returns `Ok(CodedPiece)` on success.
+    /// Returns `Err(RLNCError::CodingVectorLengthMismatch)` if the length of `coding_vector` does not match `self.piece_count`.
+    /// Returns `Err(RLNCError::ZeroCodingVector)` if `coding_vector` is entirely zero.
+    pub fn code_with_nonzero_coding_vector(&self, coding_vector: &[u8]) -> Result<CodedPiece, RLNCError> {
         if coding_vector.len() != self.piece_count {
-            return Err(RLNCError::CodingVectorLengthMismatch);
+            return Err(RLNCError::CodingVectorLengthMismatch {
+                expected: self.piece_count,
+                got: coding_vector.len(),
+            });
+        }
+        if coding_vector.iter().all(|&symbol| symbol == 0) {
+            return Err(RLNCError::ZeroCodingVector);
         }
 
-        let coded_piece = self
-            .data
-            .par_chunks_exact(self.piece_byte_len)
-            .zip(coding_vector)
-            .map(|(piece, &random_symbol)| {
-                #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-                {
-                    let mut scalar_x_piece = piece.to_vec();
-                    gf256_inplace_mul_vec_by_scalar(&mut scalar_x_piece, random_symbol);
+        self.code_with_coding_vector(coding_vector)
+    }
 
-                    scalar_x_piece
-                }
+    /// Encodes the data held by the encoder using a randomly sampled coding vector, writing the full coded piece
+    /// into the caller-provided `out` buffer, instead of allocating a new one. This makes it possible to reuse a
+    /// single buffer, across many calls, while producing lots of coded pieces.
+    ///
+    /// A coding vector of `self.piece_count` random `Gf256` symbols is generated using the provided random number
+    /// generator, directly into `out`, using `sample_coding_vector_into`. Previous content of `out` is overwritten.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success, in which case `out` holds the coded piece prefixed by the random coding vector.
+    /// Returns `Err(RLNCError::InvalidPieceLength)` if `out.len()` is not `self.get_full_coded_piece_byte_len()`.
+    pub fn code_into<R: Rng + ?Sized>(&self, rng: &mut R, out: &mut [u8]) -> Result<(), RLNCError> {
+        if out.len() != self.get_full_coded_piece_byte_len() {
+            return Err(RLNCError::InvalidPieceLength {
+                expected: self.get_full_coded_piece_byte_len(),
+                got: out.len(),
+            });
+        }
 
-                #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
-                {
-                    piece.iter().map(move |&symbol| (Gf256::new(symbol) * Gf256::new(random_symbol)).get())
-                }
-            })
-            .fold(
-                || vec![0u8; self.piece_byte_len],
-                |mut acc, cur| {
-                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-                    gf256_inplace_add_vectors(&mut acc, &cur);
-
-                    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
-                    acc.iter_mut().zip(cur).for_each(|(a, b)| {
-                        *a ^= b;
-                    });
-
-                    acc
-                },
-            )
-            .reduce(
-                || vec![0u8; self.piece_byte_len],
-                |mut acc, cur| {
-                    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-                    gf256_inplace_add_vectors(&mut acc, &cur);
-
-                    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
-                    acc.iter_mut().zip(cur).for_each(|(a, b)| {
-                        *a ^= b;
-                    });
-
-                    acc
-                },
-            );
+        let tagless_full_coded_piece = self.write_generation_tag(out);
+
+        let (coding_vector, coded_piece) = tagless_full_coded_piece.split_at_mut(self.piece_count);
+        sample_coding_vector_into(rng, coding_vector);
+        self.code_data_into(coding_vector, coded_piece);
+
+        Ok(())
+    }
+
+    /// Encodes the data held by the encoder using a coding vector, deterministically derived from `seed`, using
+    /// `seed::coding_vector_from_seed`.
+    ///
+    /// Unlike other coding functions, the returned coded piece is *not* prefixed by the coding vector, only by the
+    /// generation tag, if any, so it is `self.get_piece_byte_len()` bytes long, for an untagged encoder. Transmitting
+    /// the 8 -bytes seed, along with it, instead of the `self.get_piece_count()` -bytes coding vector, lets the receiver
+    /// regenerate the coding vector using `Decoder::decode_with_seed`. See `seed::seed_coding_savings` for when it pays off.
+    ///
+    /// Note, each seed must be used only once per generation, as same seed produces the same coded piece.
+    pub fn code_with_seed(&self, seed: u64) -> Vec<u8> {
+        let coding_vector = coding_vector_from_seed(seed, self.piece_count);
+
+        let mut seeded_coded_piece = vec![0u8; self.get_generation_tag_byte_len() + self.piece_byte_len];
+        let coded_piece = self.write_generation_tag(&mut seeded_coded_piece);
+        self.code_data_into(&coding_vector, coded_piece);
+
+        seeded_coded_piece
+    }
+
+    /// Encodes the data held by the encoder using a coding vector, deterministically derived from `piece_index` alone,
+    /// so that the same encoder produces byte-for-byte the same `piece_index` -th coded piece, on every platform and
+    /// with every compiler version. Iterating `piece_index` over `0, 1, 2, ...` yields a fixed, reproducible sequence of
+    /// coded pieces, independent of any random number generator, which is handy for deterministic replay in tests.
+    ///
+    /// Coding vector is `seed::coding_vector_from_seed(piece_index, self.get_piece_count())` i.e. ChaCha8 keystream,
+    /// keyed by `piece_index`, whose output is pinned by a known-answer test. Unlike `Self::code_with_seed`, the returned
+    /// coded piece is prefixed by the coding vector, just like the ones returned by `Self::code`.
+    pub fn code_indexed(&self, piece_index: u64) -> CodedPiece {
+        let coding_vector = coding_vector_from_seed(piece_index, self.piece_count);
 
         let mut full_coded_piece = vec![0u8; self.get_full_coded_piece_byte_len()];
-        full_coded_piece[..self.piece_count].copy_from_slice(coding_vector);
-        full_coded_piece[self.piece_count..].copy_from_slice(&coded_piece);
+        let tagless_full_coded_piece = self.write_generation_tag(&mut full_coded_piece);
+
+        let (coding_vector_part, coded_piece) = tagless_full_coded_piece.split_at_mut(self.piece_count);
+        coding_vector_part.copy_from_slice(&coding_vector);
+        self.code_data_into(&coding_vector, coded_piece);
+
+        self.wrap_full_coded_piece(full_coded_piece)
+    }
+
+    /// Encodes the data held by the encoder using the `piece_index` -th row of a Vandermonde coding matrix, i.e. coding
+    /// vector `[a^0, a^1, ..., a^(piece_count - 1)]`, where evaluation point `a = Gf256::exp(piece_index)`. Evaluation
+    /// points of distinct piece indices are distinct, so that any `self.get_piece_count()` many pieces, coded this way,
+    /// are guaranteed to be linearly independent, and exactly those many pieces always decode, with zero overhead, making
+    /// it an MDS erasure code, unlike random coding.
+    ///
+    /// There are only `VANDERMONDE_EVALUATION_POINT_COUNT` i.e. 255 distinct non-zero evaluation points, so that the
+    /// guarantee holds only for `self.get_piece_count() <= 255`, while at most 255 distinct coded pieces can be produced.
+    ///
+    /// # Returns
+    /// Returns `Ok(CodedPiece)`, the coded piece prefixed by the Vandermonde coding vector, on success.
+    /// Returns `Err(RLNCError::PieceIndexOutOfRange)` if `piece_index` is not less than `VANDERMONDE_EVALUATION_POINT_COUNT`.
+    pub fn code_vandermonde(&self, piece_index: usize) -> Result<CodedPiece, RLNCError> {
+        if piece_index >= VANDERMONDE_EVALUATION_POINT_COUNT {
+            return Err(RLNCError::PieceIndexOutOfRange);
+        }
+
+        let evaluation_point = Gf256::exp(piece_index as u8);
+        let vandermonde_coding_vector = (0..self.piece_count)
+            .scan(Gf256::one(), |power, _| {
+                let coefficient = *power;
+                *power = *power * evaluation_point;
+
+                Some(coefficient.get())
+            })
+            .collect::<Vec<u8>>();
+
+        self.code_with_coding_vector(&vandermonde_coding_vector)
+    }
+
+    /// Computes the linear combination of the pieces of data held by the encoder, using `coding_vector` as
+    /// coefficients, writing it into `coded_piece`, which must be `self.piece_byte_len` bytes long.
+    pub(crate) fn code_data_into(&self, coding_vector: &[u8], coded_piece: &mut [u8]) {
+        let num_leading_pieces = self.data.len() / self.piece_byte_len;
+        let (leading_coding_vector, tail_coding_vector) = coding_vector.split_at(num_leading_pieces.min(coding_vector.len()));
+
+        linear_combination_into::<Gf256>(&self.data, self.piece_byte_len, leading_coding_vector, coded_piece);
+
+        self.tail
+            .chunks_exact(self.piece_byte_len)
+            .zip(tail_coding_vector)
+            .for_each(|(piece, &random_symbol)| gf256_mul_vec_by_scalar_then_add_into_vec(coded_piece, piece, random_symbol));
+    }
+
+    fn wrap_full_coded_piece(&self, full_coded_piece: Vec<u8>) -> CodedPiece {
+        CodedPiece::new_unchecked(full_coded_piece, self.piece_count, self.piece_byte_len, self.generation_tag.is_some())
+    }
 
-        Ok(full_coded_piece)
+    /// Writes the generation tag, if any, at the beginning of `full_coded_piece`, returning the rest of it.
+    fn write_generation_tag<'b>(&self, full_coded_piece: &'b mut [u8]) -> &'b mut [u8] {
+        match self.generation_tag {
+            Some(generation_tag) => {
+                let (tag, rest) = full_coded_piece.split_at_mut(GENERATION_TAG_BYTE_LEN);
+                tag.copy_from_slice(&generation_tag.to_le_bytes());
+                rest
+            }
+            None => full_coded_piece,
+        }
     }
 
     /// Encodes the data held by the encoder using a randomly sampled coding vector.
     ///
     /// A coding vector of `self.piece_count` random `Gf256` symbols is generated
-    /// using the provided random number generator.
+    /// using the provided random number generator, resampling it in the unlikely case of being all-zero.
+    ///
+    /// Calls `code_into` internally, with a freshly allocated buffer.
+    ///
+    /// Returns the coded piece prefixed by the random coding vector.
+    pub fn code<R: Rng + ?Sized>(&self, rng: &mut R) -> CodedPiece {
+        let mut full_coded_piece = vec![0u8; self.get_full_coded_piece_byte_len()];
+        unsafe { self.code_into(rng, &mut full_coded_piece).unwrap_unchecked() };
+
+        self.wrap_full_coded_piece(full_coded_piece)
+    }
+
+    /// Same as `Self::code`, but taking the random number generator as a trait object, e.g. a boxed RNG of a pluggable
+    /// crypto provider, stored alongside the encoder, so that callers don't need to be generic over the RNG type.
+    /// Produces the same coded piece as `Self::code` does, given an RNG in the same state.
+    pub fn code_dyn(&self, rng: &mut dyn RngCore) -> CodedPiece {
+        self.code(rng)
+    }
+
+    /// Returns an endless, lazy, iterator of coded pieces, each of them produced by calling `Self::code`, only when
+    /// the iterator is polled. Handy for composing with iterator adapters, e.g. `encoder.coded_pieces(&mut rng).take(n)`.
+    pub fn coded_pieces<'r, R: Rng + ?Sized>(&'r self, rng: &'r mut R) -> impl Iterator<Item = CodedPiece> + 'r {
+        core::iter::repeat_with(move || self.code(rng))
+    }
+
+    /// Same as `Self::code`, but appends a `CHECKSUM_BYTE_LEN` -bytes CRC-32 checksum of the full coded piece, including
+    /// the generation tag, if any, so that a piece corrupted in transit is rejected by `Decoder::decode_checked`, instead
+    /// of silently turning the decoded data into garbage. It's opt-in, as it costs a pass over the full coded piece and
+    /// 4 extra bytes per piece, which is wasted, if the link layer already guarantees integrity.
+    ///
+    /// Returns the full coded piece, as returned by `Self::code`, followed by its checksum, in little-endian byte order.
+    pub fn code_checked<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<u8> {
+        let full_coded_piece_byte_len = self.get_full_coded_piece_byte_len();
+
+        let mut checked_coded_piece = vec![0u8; full_coded_piece_byte_len + CHECKSUM_BYTE_LEN];
+        let (full_coded_piece, checksum) = checked_coded_piece.split_at_mut(full_coded_piece_byte_len);
+
+        unsafe { self.code_into(rng, full_coded_piece).unwrap_unchecked() };
+        checksum.copy_from_slice(&crc32(full_coded_piece).to_le_bytes());
+
+        checked_coded_piece
+    }
+
+    /// Same as `Self::code`, but prepends a `frame::FRAME_HEADER_BYTE_LEN` -bytes frame header, carrying the piece count
+    /// and the piece byte length, so that a receiver can create its `Decoder` from the first packet, using
+    /// `frame::decode_header`, without knowing the RLNC configuration beforehand.
+    ///
+    /// # Returns
+    /// Returns `Ok(frame)`, the frame header followed by the full coded piece, as returned by `Self::code`, on success.
+    /// Returns `Err(RLNCError::InvalidFrameHeader)` if the piece count or the piece byte length doesn't fit in a frame header.
+    pub fn code_framed<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<Vec<u8>, RLNCError> {
+        let header = encode_header(self.piece_count, self.piece_byte_len)?;
+
+        let mut frame = vec![0u8; FRAME_HEADER_BYTE_LEN + self.get_full_coded_piece_byte_len()];
+        let (frame_header, full_coded_piece) = frame.split_at_mut(FRAME_HEADER_BYTE_LEN);
+
+        frame_header.copy_from_slice(&header);
+        unsafe { self.code_into(rng, full_coded_piece).unwrap_unchecked() };
+
+        Ok(frame)
+    }
+
+    /// Encodes the data held by the encoder `count` times, using independently sampled random coding vectors, just like
+    /// `count` calls to `Self::code` would, but returning all of them in a single buffer, allocated only once. Handy for
+    /// emitting a burst of coded pieces at once.
+    ///
+    /// Instead of walking over the whole data once per coded piece, each source piece is scaled into all `count` coded
+    /// pieces, before moving onto the next one, so that it is read from memory once and stays hot in cache meanwhile.
+    ///
+    /// Returns `count` full coded pieces, each of `self.get_full_coded_piece_byte_len()` bytes, concatenated. Each of them
+    /// is prefixed by the generation tag, if any, and by its random coding vector, same as the ones returned by `Self::code`.
+    pub fn code_batch<R: Rng + ?Sized>(&self, rng: &mut R, count: usize) -> Vec<u8> {
+        let full_coded_piece_byte_len = self.get_full_coded_piece_byte_len();
+        let coding_vector_begins_at = full_coded_piece_byte_len - self.piece_count - self.piece_byte_len;
+        let coded_piece_begins_at = coding_vector_begins_at + self.piece_count;
+
+        let mut full_coded_pieces = vec![0u8; count * full_coded_piece_byte_len];
+        full_coded_pieces.chunks_exact_mut(full_coded_piece_byte_len).for_each(|full_coded_piece| {
+            let tagless_full_coded_piece = self.write_generation_tag(full_coded_piece);
+            sample_coding_vector_into(rng, &mut tagless_full_coded_piece[..self.piece_count]);
+        });
+
+        self.data
+            .chunks_exact(self.piece_byte_len)
+            .chain(self.tail.chunks_exact(self.piece_byte_len))
+            .enumerate()
+            .for_each(|(piece_idx, piece)| {
+                full_coded_pieces.chunks_exact_mut(full_coded_piece_byte_len).for_each(|full_coded_piece| {
+                    let (coding_vector, coded_piece) = full_coded_piece.split_at_mut(coded_piece_begins_at);
+                    gf256_mul_vec_by_scalar_then_add_into_vec(coded_piece, piece, coding_vector[coding_vector_begins_at + piece_idx]);
+                });
+            });
+
+        full_coded_pieces
+    }
+
+    /// Encodes the data held by the encoder using a randomly sampled coding vector, with no zero coefficient.
     ///
-    /// Calls `code_with_coding_vector` internally.
+    /// Each coefficient of the coding vector is sampled uniformly from the non-zero elements of GF(2^8) i.e.
+    /// from `1..=255`, instead of from all 256 elements, as done by `Self::code`. This guarantees that every
+    /// source piece contributes to the coded piece. With uniform sampling, each coefficient is zero with
+    /// probability 1/256, which mostly matters when `self.get_piece_count()` is small, where it measurably reduces
+    /// the chance of a coded piece being innovative. Note that coding vectors sampled this way are not uniformly
+    /// distributed over the whole vector space anymore, which is fine for decoding, but worth keeping in mind when
+    /// reasoning about linear independence probabilities.
     ///
     /// Returns the coded piece prefixed by the random coding vector.
-    pub fn code<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<u8> {
-        let random_coding_vector = (0..self.piece_count).map(|_| rng.random()).collect::<Vec<u8>>();
+    pub fn code_nonzero_coeffs<R: Rng + ?Sized>(&self, rng: &mut R) -> CodedPiece {
+        let random_coding_vector = (0..self.piece_count).map(|_| rng.random_range(1..=u8::MAX)).collect::<Vec<u8>>();
         unsafe { self.code_with_coding_vector(&random_coding_vector).unwrap_unchecked() }
     }
+
+    /// Encodes the data held by the encoder using a dense, randomly sampled, coding vector, i.e. one with no zero
+    /// coefficient, so that every coded piece touches every source piece. Same as `Self::code_nonzero_coeffs`.
+    ///
+    /// With `Self::code`, a coding vector has at least one zero coefficient with probability `1 - (255/256)^piece_count`,
+    /// e.g. ~6% for 16 pieces and ~63% for 256 pieces, which leaves some source pieces out of that coded piece. Sampling
+    /// from `1..=255` rules it out, at the cost of coding vectors not being uniformly distributed over the whole vector
+    /// space anymore, which makes two of them slightly more likely to be linearly dependent, for tiny `piece_count`.
+    ///
+    /// Returns the coded piece prefixed by the random dense coding vector.
+    pub fn code_dense<R: Rng + ?Sized>(&self, rng: &mut R) -> CodedPiece {
+        self.code_nonzero_coeffs(rng)
+    }
+
+    /// Encodes the data held by the encoder using a sparse, randomly sampled, coding vector, which combines only `degree`
+    /// many source pieces.
+    ///
+    /// Indices of `degree` distinct source pieces are sampled uniformly at random, and each of them is assigned a random
+    /// non-zero coefficient, while rest of the coefficients are zero. Lower the degree, cheaper it is to eliminate such
+    /// coded pieces while decoding, but more coded pieces are likely to be needed for recovering all source pieces, as
+    /// sparse coding vectors are linearly dependent more often. Pieces coded this way can be decoded by `Decoder`, as usual.
+    ///
+    /// # Returns
+    /// Returns `Ok(CodedPiece)`, the coded piece prefixed by the sparse coding vector, on success.
+    /// Returns `Err(RLNCError::DegreeOutOfRange)` if `degree` is zero or greater than `self.get_piece_count()`.
+    pub fn code_sparse<R: Rng + ?Sized>(&self, rng: &mut R, degree: usize) -> Result<CodedPiece, RLNCError> {
+        if degree == 0 || degree > self.piece_count {
+            return Err(RLNCError::DegreeOutOfRange);
+        }
+
+        let mut sparse_coding_vector = vec![0u8; self.piece_count];
+        rand::seq::index::sample(rng, self.piece_count, degree)
+            .into_iter()
+            .for_each(|piece_idx| sparse_coding_vector[piece_idx] = rng.random_range(1..=u8::MAX));
+
+        self.code_with_coding_vector(&sparse_coding_vector)
+    }
+
+    /// Encodes the data held by the encoder using a sparse coding vector, which combines only the source pieces at `indices`,
+    /// e.g. the ones a downstream node is known to be missing, each of them assigned a random non-zero coefficient, while
+    /// rest of the coefficients are zero. Unlike `Self::code_sparse`, which source pieces get combined is chosen by the
+    /// caller, maximizing the chance of the coded piece being useful to a peer with a known deficiency set. Repeated
+    /// indices are combined only once.
+    ///
+    /// # Returns
+    /// Returns `Ok(CodedPiece)`, the coded piece prefixed by the sparse coding vector, on success.
+    /// Returns `Err(RLNCError::DegreeOutOfRange)` if `indices` is empty.
+    /// Returns `Err(RLNCError::PieceIndexOutOfRange)` if any of `indices` is not less than `self.get_piece_count()`.
+    pub fn code_over_indices<R: Rng + ?Sized>(&self, rng: &mut R, indices: &[usize]) -> Result<CodedPiece, RLNCError> {
+        if indices.is_empty() {
+            return Err(RLNCError::DegreeOutOfRange);
+        }
+        if indices.iter().any(|&piece_idx| piece_idx >= self.piece_count) {
+            return Err(RLNCError::PieceIndexOutOfRange);
+        }
+
+        let mut sparse_coding_vector = vec![0u8; self.piece_count];
+        indices
+            .iter()
+            .for_each(|&piece_idx| sparse_coding_vector[piece_idx] = rng.random_range(1..=u8::MAX));
+
+        self.code_with_coding_vector(&sparse_coding_vector)
+    }
+
+    /// Encodes the data held by the encoder using a sparse, randomly sampled, coding vector, whose degree, i.e. number
+    /// of source pieces combined, is sampled from `distribution`, e.g. `degree_distribution::RobustSoliton`, making RLNC
+    /// behave like an LT/ fountain code. Given the degree, coding vector is sampled just like `Self::code_sparse` does.
+    ///
+    /// # Returns
+    /// Returns `Ok(CodedPiece)`, the coded piece prefixed by the sparse coding vector, on success.
+    /// Returns `Err(RLNCError::DegreeOutOfRange)` if the sampled degree is zero or greater than `self.get_piece_count()`.
+    pub fn code_with_degree_distribution<R: Rng + ?Sized, D: Distribution<usize> + ?Sized>(
+        &self,
+        rng: &mut R,
+        distribution: &D,
+    ) -> Result<CodedPiece, RLNCError> {
+        let degree = distribution.sample(rng);
+        self.code_sparse(rng, degree)
+    }
+}
+
+/// Computes the byte length of a buffer, holding `num_pieces` pieces of `piece_byte_len` bytes each, without overflowing.
+///
+/// # Returns
+/// Returns `Ok(num_pieces * piece_byte_len)` on success.
+/// Returns `Err(RLNCError::ParametersTooLarge)` if the product overflows `usize` or exceeds `MAX_BUFFER_BYTE_LEN`.
+pub(crate) fn checked_buffer_byte_len(num_pieces: usize, piece_byte_len: usize) -> Result<usize, RLNCError> {
+    checked_buffer_byte_len_within(num_pieces, piece_byte_len, MAX_BUFFER_BYTE_LEN)
+}
+
+/// Same as `checked_buffer_byte_len`, but capping the byte length of the buffer at `max_buffer_byte_len`, as configured
+/// by the caller, e.g. using `Encoder::new_bounded` or `Decoder::new_bounded`, instead of `MAX_BUFFER_BYTE_LEN` alone.
+///
+/// # Returns
+/// Returns `Ok(num_pieces * piece_byte_len)` on success.
+/// Returns `Err(RLNCError::ParametersTooLarge)` if the product overflows `usize`, or exceeds either `max_buffer_byte_len`
+/// or `MAX_BUFFER_BYTE_LEN`.
+pub(crate) fn checked_buffer_byte_len_within(num_pieces: usize, piece_byte_len: usize, max_buffer_byte_len: usize) -> Result<usize, RLNCError> {
+    num_pieces
+        .checked_mul(piece_byte_len)
+        .filter(|&buffer_byte_len| buffer_byte_len <= max_buffer_byte_len.min(MAX_BUFFER_BYTE_LEN))
+        .ok_or(RLNCError::ParametersTooLarge)
+}
+
+/// Fills `coding_vector` with uniformly random `Gf256` symbols, resampling it in the unlikely case of being all-zero, as
+/// such coding vector carries no information and gets rejected by decoders, with `RLNCError::ZeroCodingVector`. This only
+/// matters for tiny generations, e.g. a single source piece gets an all-zero coding vector with probability 1/256.
+pub(crate) fn sample_coding_vector_into<R: Rng + ?Sized>(rng: &mut R, coding_vector: &mut [u8]) {
+    loop {
+        coding_vector.iter_mut().for_each(|symbol| *symbol = rng.random());
+        if coding_vector.is_empty() || coding_vector.iter().any(|&symbol| symbol != 0) {
+            break;
+        }
+    }
+}
+
+/// Minimum number of bytes of source pieces, to be linearly combined, for accumulating them in parallel to be worth it.
+#[cfg(feature = "parallel")]
+const MIN_PARALLEL_LINEAR_COMBINATION_BYTE_LEN: usize = 1 << 20;
+
+/// Computes the linear combination of `piece_byte_len` -bytes pieces, concatenated in `pieces`, over finite field `F`,
+/// using `coefficients` as the coding vector, writing it into `linear_combination`, which must be `piece_byte_len` bytes
+/// long. Both `coefficients` and pieces are vectors over `F`, each of their elements taking `F::BYTE_LEN` bytes.
+///
+/// With `parallel` feature, large enough linear combinations are split across threads, producing exactly the same result.
+pub(crate) fn linear_combination_into<F: Field>(pieces: &[u8], piece_byte_len: usize, coefficients: &[u8], linear_combination: &mut [u8]) {
+    // Distributing a few small pieces over threads costs more than it saves.
+    #[cfg(feature = "parallel")]
+    if rayon::current_num_threads() > 1 && (coefficients.len() / F::BYTE_LEN) * piece_byte_len >= MIN_PARALLEL_LINEAR_COMBINATION_BYTE_LEN {
+        return linear_combination_in_parallel_into::<F>(pieces, piece_byte_len, coefficients, linear_combination);
+    }
+
+    linear_combination_serially_into::<F>(pieces, piece_byte_len, coefficients, linear_combination)
+}
+
+/// Same as `linear_combination_into`, but accumulating scaled pieces, one after another, on the calling thread.
+fn linear_combination_serially_into<F: Field>(pieces: &[u8], piece_byte_len: usize, coefficients: &[u8], linear_combination: &mut [u8]) {
+    linear_combination.fill(0);
+
+    pieces
+        .chunks_exact(piece_byte_len)
+        .zip(coefficients.chunks_exact(F::BYTE_LEN))
+        .for_each(|(piece, coefficient)| F::mul_vec_by_scalar_then_add_into_vec(linear_combination, piece, F::from_le_bytes(coefficient)));
+}
+
+/// Same as `linear_combination_into`, but partitioning pieces into groups, using `rayon`, each group's scaled pieces being
+/// accumulated into a buffer of its own, before adding up all those partial linear combinations. Addition over `F` is
+/// associative and commutative, so that the result doesn't depend on how pieces got grouped.
+#[cfg(feature = "parallel")]
+fn linear_combination_in_parallel_into<F: Field>(pieces: &[u8], piece_byte_len: usize, coefficients: &[u8], linear_combination: &mut [u8]) {
+    let result = pieces
+        .par_chunks_exact(piece_byte_len)
+        .zip(coefficients.par_chunks_exact(F::BYTE_LEN))
+        .fold(
+            || vec![0u8; piece_byte_len],
+            |mut acc, (piece, coefficient)| {
+                F::mul_vec_by_scalar_then_add_into_vec(&mut acc, piece, F::from_le_bytes(coefficient));
+                acc
+            },
+        )
+        .reduce_with(|mut acc, cur| {
+            F::inplace_add_vectors(&mut acc, &cur);
+            acc
+        });
+
+    match result {
+        Some(result) => linear_combination.copy_from_slice(&result),
+        None => linear_combination.fill(0),
+    }
+}
+
+/// Deserialized, but not yet validated, `Encoder`.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct UncheckedEncoder {
+    data: Vec<u8>,
+    tail: Vec<u8>,
+    piece_count: usize,
+    piece_byte_len: usize,
+    generation_tag: Option<u32>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<UncheckedEncoder> for Encoder<'_> {
+    type Error = RLNCError;
+
+    /// Deserialized encoder always owns its data, even if the serialized one was borrowing it.
+    fn try_from(mut encoder: UncheckedEncoder) -> Result<Self, Self::Error> {
+        encoder.data.append(&mut encoder.tail);
+
+        let mut validated = Self::without_padding(encoder.data, encoder.piece_count)?;
+        if validated.piece_byte_len != encoder.piece_byte_len {
+            return Err(RLNCError::DataLengthMismatch);
+        }
+
+        validated.generation_tag = encoder.generation_tag;
+        Ok(validated)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Encoder, RLNCError};
-    use rand::Rng;
+    use super::{Cow, Encoder, RLNCError};
+    #[cfg(feature = "parallel")]
+    use super::{linear_combination_in_parallel_into, linear_combination_serially_into};
+    #[cfg(feature = "std")]
+    use crate::full::degree_distribution::RobustSoliton;
+    use crate::{
+        common::gf256::Gf256,
+        full::{coded_piece::CodedPiece, consts::VANDERMONDE_EVALUATION_POINT_COUNT, decoder::Decoder, seed::coding_vector_from_seed},
+    };
+    use rand::{Rng, RngCore, SeedableRng, rngs::StdRng};
 
     #[test]
     fn test_encoder_without_padding_invalid_data() {
@@ -290,6 +983,15 @@ mod tests {
             RLNCError::DataLengthZero
         );
 
+        // Test case: Padded data, of at least one byte per piece, would be longer than any allocation
+        let data_tiny = vec![1u8; 16];
+        assert_eq!(Encoder::new(data_tiny.clone(), usize::MAX).map(|_| ()), Err(RLNCError::ParametersTooLarge));
+        assert_eq!(
+            Encoder::new(data_tiny.clone(), isize::MAX as usize + 1).map(|_| ()),
+            Err(RLNCError::ParametersTooLarge)
+        );
+        assert_eq!(Encoder::new_borrowed(&data_tiny, usize::MAX).map(|_| ()), Err(RLNCError::ParametersTooLarge));
+
         // Test case 4: Valid input
         let data_byte_len_valid = 1024;
         let piece_count_valid = 32;
@@ -299,6 +1001,45 @@ mod tests {
         assert!(result_valid.is_ok());
     }
 
+    #[test]
+    fn test_encoder_new_bounded() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1023;
+        let piece_count = 32;
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let padded_data_byte_len = data_byte_len + 1;
+
+        // Test case 1: Padded data fits in the cap exactly, coding same as an unbounded encoder does
+        let encoder = Encoder::new_bounded(data.clone(), piece_count, padded_data_byte_len).expect("Encoder must fit in the cap");
+        let unbounded_encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder");
+        assert_eq!(encoder.get_piece_byte_len(), unbounded_encoder.get_piece_byte_len());
+        assert_eq!(encoder.original_data_len(), data_byte_len);
+
+        // Test case 2: Padded data is a byte longer than the cap
+        assert_eq!(
+            Encoder::new_bounded(data.clone(), piece_count, padded_data_byte_len - 1).map(|_| ()),
+            Err(RLNCError::ParametersTooLarge)
+        );
+
+        // Test case 3: Huge piece count pads even tiny data to at least a byte per piece, which is refused
+        assert_eq!(
+            Encoder::new_bounded(vec![1u8; 16], 1 << 30, 1 << 20).map(|_| ()),
+            Err(RLNCError::ParametersTooLarge)
+        );
+
+        // Test case 4: Cap doesn't lift the limit of `isize::MAX` bytes, nor does it skip the other checks
+        assert_eq!(
+            Encoder::new_bounded(vec![1u8; 16], usize::MAX, usize::MAX).map(|_| ()),
+            Err(RLNCError::ParametersTooLarge)
+        );
+        assert_eq!(
+            Encoder::new_bounded(Vec::new(), piece_count, usize::MAX).map(|_| ()),
+            Err(RLNCError::DataLengthZero)
+        );
+        assert_eq!(Encoder::new_bounded(data, 0, usize::MAX).map(|_| ()), Err(RLNCError::PieceCountZero));
+    }
+
     #[test]
     fn test_encoder_code_with_coding_vector_invalid_inputs() {
         let mut rng = rand::rng();
@@ -316,7 +1057,10 @@ mod tests {
         assert!(result_short.is_err());
         assert_eq!(
             result_short.expect_err("Expected CodingVectorLengthMismatch error for short vector"),
-            RLNCError::CodingVectorLengthMismatch
+            RLNCError::CodingVectorLengthMismatch {
+                expected: piece_count,
+                got: short_coding_vector_len
+            }
         );
 
         // Test case 2: Coding vector is longer than expected
@@ -327,7 +1071,10 @@ mod tests {
         assert!(result_long.is_err());
         assert_eq!(
             result_long.expect_err("Expected CodingVectorLengthMismatch error for long vector"),
-            RLNCError::CodingVectorLengthMismatch
+            RLNCError::CodingVectorLengthMismatch {
+                expected: piece_count,
+                got: long_coding_vector_len
+            }
         );
 
         // Test case 3: Empty coding vector
@@ -337,7 +1084,7 @@ mod tests {
         assert!(result_empty.is_err());
         assert_eq!(
             result_empty.expect_err("Expected CodingVectorLengthMismatch error for empty vector"),
-            RLNCError::CodingVectorLengthMismatch
+            RLNCError::CodingVectorLengthMismatch { expected: piece_count, got: 0 }
         );
 
         // Test case 4: Valid coding vector
@@ -346,11 +1093,73 @@ mod tests {
 
         assert!(result_valid.is_ok());
         assert_eq!(
-            result_valid.expect("Expected a valid coded piece").len(),
+            result_valid.expect("Expected a valid coded piece").as_bytes().len(),
             encoder.get_full_coded_piece_byte_len()
         );
     }
 
+    #[test]
+    fn test_encoder_code_with_zero_coding_vector() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data, piece_count).expect("Failed to create Encoder for zero coding vector test");
+        let zero_coding_vector = vec![0u8; piece_count];
+
+        // Test case 1: All-zero coding vector in, all-zero full coded piece out
+        let zero_coded_piece = encoder
+            .code_with_coding_vector(&zero_coding_vector)
+            .expect("Zero coding vector must be accepted");
+        assert_eq!(zero_coded_piece.as_bytes().len(), encoder.get_full_coded_piece_byte_len());
+        assert!(zero_coded_piece.as_bytes().iter().all(|&byte| byte == 0));
+
+        let mut decoder = Decoder::new(encoder.get_piece_byte_len(), piece_count).expect("Failed to create Decoder");
+        assert_eq!(decoder.decode(&zero_coded_piece), Err(RLNCError::ZeroCodingVector));
+
+        // Test case 2: Opt-in check refuses it, while coding any other vector, same as without the check
+        assert_eq!(encoder.code_with_nonzero_coding_vector(&zero_coding_vector), Err(RLNCError::ZeroCodingVector));
+        assert_eq!(
+            encoder.code_with_nonzero_coding_vector(&zero_coding_vector[1..]),
+            Err(RLNCError::CodingVectorLengthMismatch {
+                expected: zero_coding_vector.len(),
+                got: zero_coding_vector.len() - 1
+            })
+        );
+
+        let mut unit_coding_vector = zero_coding_vector;
+        unit_coding_vector[piece_count - 1] = 1;
+        assert_eq!(
+            encoder.code_with_nonzero_coding_vector(&unit_coding_vector),
+            encoder.code_with_coding_vector(&unit_coding_vector)
+        );
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn prop_test_parallel_linear_combination_matches_serial() {
+        const NUM_TEST_ITERATIONS: usize = 10;
+
+        let mut rng = rand::rng();
+
+        (0..NUM_TEST_ITERATIONS).for_each(|_| {
+            let piece_count = rng.random_range(1..=64);
+            let piece_byte_len = rng.random_range(1..=(1usize << 15));
+
+            let pieces = (0..(piece_count * piece_byte_len)).map(|_| rng.random()).collect::<Vec<u8>>();
+            let coefficients = (0..piece_count).map(|_| rng.random()).collect::<Vec<u8>>();
+
+            let mut serial = vec![0u8; piece_byte_len];
+            let mut parallel = vec![0xffu8; piece_byte_len];
+
+            linear_combination_serially_into::<Gf256>(&pieces, piece_byte_len, &coefficients, &mut serial);
+            linear_combination_in_parallel_into::<Gf256>(&pieces, piece_byte_len, &coefficients, &mut parallel);
+
+            assert_eq!(serial, parallel);
+        });
+    }
+
     #[test]
     fn test_encoder_getters() {
         let mut rng = rand::rng();
@@ -400,4 +1209,699 @@ mod tests {
             piece_count_large + (data_byte_len_large + 1).div_ceil(piece_count_large)
         );
     }
+
+    #[test]
+    fn test_encoder_piece_count_around_data_len() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 10usize;
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+
+        // (piece_count, expected piece byte length, expected number of trailing all-zero padding pieces)
+        [
+            (data_byte_len, 2usize, 4usize),
+            (data_byte_len + 1, 1, 0),
+            (data_byte_len + 2, 1, 1),
+            (4 * data_byte_len, 1, 3 * data_byte_len - 1),
+        ]
+        .into_iter()
+        .for_each(|(piece_count, expected_piece_byte_len, expected_num_padding_pieces)| {
+            let encoder = Encoder::new(data.clone(), piece_count).expect("Piece count exceeding data length must be accepted");
+
+            assert_eq!(encoder.get_piece_count(), piece_count);
+            assert_eq!(encoder.get_piece_byte_len(), expected_piece_byte_len);
+            assert_eq!(encoder.get_full_coded_piece_byte_len(), piece_count + expected_piece_byte_len);
+            assert_eq!(encoder.original_data_len(), data_byte_len);
+
+            let pieces = encoder.pieces().collect::<Vec<&[u8]>>();
+            let num_padding_pieces = pieces.iter().rev().take_while(|piece| piece.iter().all(|&byte| byte == 0)).count();
+            assert_eq!(num_padding_pieces, expected_num_padding_pieces);
+
+            let mut decoder = Decoder::new(encoder.get_piece_byte_len(), piece_count).expect("Failed to create Decoder");
+            while !decoder.is_already_decoded() {
+                match decoder.decode(&encoder.code(&mut rng)) {
+                    Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                    Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+                }
+            }
+            assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+        });
+    }
+
+    #[test]
+    fn test_encoder_with_piece_byte_len() {
+        let mut rng = rand::rng();
+
+        assert_eq!(Encoder::with_piece_byte_len(Vec::new(), 16).map(|_| ()), Err(RLNCError::DataLengthZero));
+        assert_eq!(Encoder::with_piece_byte_len(vec![1u8; 16], 0).map(|_| ()), Err(RLNCError::PieceLengthZero));
+
+        let piece_byte_len = 64usize;
+
+        // (data byte length, expected piece count)
+        [
+            (1usize, 1usize),
+            (piece_byte_len - 1, 1),
+            (piece_byte_len, 2),
+            (10 * piece_byte_len - 1, 10),
+            (10 * piece_byte_len + 7, 11),
+        ]
+        .into_iter()
+        .for_each(|(data_byte_len, expected_piece_count)| {
+            let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+            let encoder = Encoder::with_piece_byte_len(data.clone(), piece_byte_len).expect("Failed to create Encoder with piece byte length");
+
+            assert_eq!(encoder.get_piece_count(), expected_piece_count);
+            assert_eq!(encoder.get_piece_byte_len(), piece_byte_len);
+            assert_eq!(encoder.original_data_len(), data_byte_len);
+
+            let mut decoder = Decoder::new(piece_byte_len, encoder.get_piece_count()).expect("Failed to create Decoder");
+            while !decoder.is_already_decoded() {
+                match decoder.decode(&encoder.code(&mut rng)) {
+                    Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                    Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+                }
+            }
+            assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+        });
+    }
+
+    #[test]
+    fn test_encoder_code_nonzero_coeffs() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 4usize;
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for non-zero coefficient test");
+
+        let mut decoder = Decoder::new(encoder.get_piece_byte_len(), encoder.get_piece_count()).expect("Failed to create Decoder");
+
+        (0..1000).for_each(|_| {
+            let coded_piece = encoder.code_nonzero_coeffs(&mut rng);
+
+            assert_eq!(coded_piece.as_bytes().len(), encoder.get_full_coded_piece_byte_len());
+            assert!(coded_piece.coding_vector().iter().all(|&coeff| coeff != 0));
+
+            let _ = decoder.decode(&coded_piece);
+        });
+
+        assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+    }
+
+    #[test]
+    fn test_encoder_code_dense() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 256usize;
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for dense coding test");
+
+        let mut decoder = Decoder::new(encoder.get_piece_byte_len(), encoder.get_piece_count()).expect("Failed to create Decoder");
+
+        // With 256 pieces, most uniformly sampled coding vectors have some zero coefficient, while no dense one does.
+        while !decoder.is_already_decoded() {
+            let coded_piece = encoder.code_dense(&mut rng);
+            assert!(coded_piece.coding_vector().iter().all(|&coeff| coeff != 0));
+
+            match decoder.decode(&coded_piece) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+        }
+
+        assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+    }
+
+    #[test]
+    fn test_encoder_code_sparse() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+        let degree = piece_count / 4;
+
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for sparse coding test");
+
+        assert_eq!(encoder.code_sparse(&mut rng, 0), Err(RLNCError::DegreeOutOfRange));
+        assert_eq!(encoder.code_sparse(&mut rng, piece_count + 1), Err(RLNCError::DegreeOutOfRange));
+
+        let dense_coded_piece = encoder.code_sparse(&mut rng, piece_count).expect("Coding with full degree must not fail");
+        assert!(dense_coded_piece.coding_vector().iter().all(|&coeff| coeff != 0));
+
+        // Decoding using only sparse coded pieces.
+        let mut decoder = Decoder::new(encoder.get_piece_byte_len(), piece_count).expect("Failed to create Decoder for sparse coding test");
+        while !decoder.is_already_decoded() {
+            let coded_piece = encoder.code_sparse(&mut rng, degree).expect("Coding with valid degree must not fail");
+            assert_eq!(coded_piece.coding_vector().iter().filter(|&&coeff| coeff != 0).count(), degree);
+
+            match decoder.decode(&coded_piece) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+        }
+
+        assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+    }
+
+    #[test]
+    fn test_encoder_code_over_indices() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for code_over_indices test");
+
+        // Test case 1: Invalid indices
+        assert_eq!(
+            encoder.code_over_indices(&mut rng, &[]).expect_err("Expected DegreeOutOfRange error"),
+            RLNCError::DegreeOutOfRange
+        );
+        assert_eq!(
+            encoder
+                .code_over_indices(&mut rng, &[0, piece_count])
+                .expect_err("Expected PieceIndexOutOfRange error"),
+            RLNCError::PieceIndexOutOfRange
+        );
+
+        // Test case 2: Only chosen source pieces are combined, each with a non-zero coefficient
+        let indices = [3, 7, 7, piece_count - 1];
+        let coded_piece = encoder.code_over_indices(&mut rng, &indices).expect("Coding must not fail");
+        coded_piece
+            .coding_vector()
+            .iter()
+            .enumerate()
+            .for_each(|(piece_idx, &coefficient)| assert_eq!(coefficient != 0, indices.contains(&piece_idx)));
+
+        // Test case 3: Decoder missing exactly the chosen source pieces gets repaired by pieces combining only those
+        let mut decoder = Decoder::new(encoder.get_piece_byte_len(), piece_count).expect("Failed to create Decoder for code_over_indices test");
+        let missing_indices = [0, 5, 17];
+        (0..piece_count).filter(|piece_idx| !missing_indices.contains(piece_idx)).for_each(|piece_idx| {
+            let mut unit_coding_vector = vec![0u8; piece_count];
+            unit_coding_vector[piece_idx] = 1;
+
+            let coded_piece = encoder.code_with_coding_vector(&unit_coding_vector).expect("Coding must not fail");
+            decoder.decode(&coded_piece).expect("Unit vector coded piece must be useful");
+        });
+
+        while !decoder.is_already_decoded() {
+            match decoder.decode(&encoder.code_over_indices(&mut rng, &missing_indices).expect("Coding must not fail")) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+        }
+
+        assert!(decoder.get_received_piece_count() < piece_count + 4);
+        assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+    }
+
+    #[test]
+    fn test_encoder_try_from_coded_pieces() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for try_from_coded_pieces test");
+        let full_coded_piece_byte_len = encoder.get_full_coded_piece_byte_len();
+
+        // Test case 1: Invalid parameters
+        assert_eq!(
+            Encoder::try_from_coded_pieces(&[], 0, piece_count).expect_err("Expected PieceLengthZero error"),
+            RLNCError::PieceLengthZero
+        );
+        assert_eq!(
+            Encoder::try_from_coded_pieces(&[], full_coded_piece_byte_len, 0).expect_err("Expected PieceCountZero error"),
+            RLNCError::PieceCountZero
+        );
+        assert_eq!(
+            Encoder::try_from_coded_pieces(&[], piece_count, piece_count).expect_err("Expected PieceLengthTooShort error"),
+            RLNCError::PieceLengthTooShort
+        );
+
+        // Test case 2: Not enough pieces to reach full rank
+        let pieces = encoder.coded_pieces(&mut rng).take(piece_count - 1).flatten().collect::<Vec<u8>>();
+        assert_eq!(
+            Encoder::try_from_coded_pieces(&pieces, full_coded_piece_byte_len, piece_count).expect_err("Expected NotAllPiecesReceivedYet error"),
+            RLNCError::NotAllPiecesReceivedYet
+        );
+        assert_eq!(
+            Encoder::try_from_coded_pieces(&pieces[1..], full_coded_piece_byte_len, piece_count).expect_err("Expected InvalidPieceLength error"),
+            RLNCError::InvalidPieceLength {
+                expected: pieces.len(),
+                got: pieces.len() - 1
+            }
+        );
+
+        // Test case 3: Promoted encoder codes over the same source pieces as the original one
+        let pieces = encoder.coded_pieces(&mut rng).take(piece_count + 8).flatten().collect::<Vec<u8>>();
+        let promoted_encoder = Encoder::try_from_coded_pieces(&pieces, full_coded_piece_byte_len, piece_count).expect("Promoting to Encoder must not fail");
+
+        assert_eq!(promoted_encoder.get_piece_count(), piece_count);
+        assert_eq!(promoted_encoder.get_piece_byte_len(), encoder.get_piece_byte_len());
+        assert_eq!(promoted_encoder.original_data_len(), data_byte_len);
+
+        let coding_vector = (0..piece_count).map(|_| rng.random()).collect::<Vec<u8>>();
+        assert_eq!(
+            promoted_encoder.code_with_coding_vector(&coding_vector).expect("Coding must not fail"),
+            encoder.code_with_coding_vector(&coding_vector).expect("Coding must not fail")
+        );
+
+        let mut decoder = Decoder::new(promoted_encoder.get_piece_byte_len(), piece_count).expect("Failed to create Decoder for try_from_coded_pieces test");
+        while !decoder.is_already_decoded() {
+            match decoder.decode(&promoted_encoder.code(&mut rng)) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+        }
+
+        assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+    }
+
+    #[test]
+    fn test_encoder_code_dyn() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+        let encoder =
+            Encoder::new((0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>(), piece_count).expect("Failed to create Encoder for code_dyn test");
+
+        let seed = rng.random::<u64>();
+        let mut boxed_rng: Box<dyn RngCore> = Box::new(StdRng::seed_from_u64(seed));
+        let mut rng_b = StdRng::seed_from_u64(seed);
+
+        (0..piece_count).for_each(|_| {
+            assert_eq!(encoder.code_dyn(boxed_rng.as_mut()), encoder.code(&mut rng_b));
+        });
+    }
+
+    #[test]
+    fn test_encoder_code_into() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for code_into test");
+        let full_coded_piece_byte_len = encoder.get_full_coded_piece_byte_len();
+
+        // Test case 1: Output buffer of invalid length
+        let mut too_short = vec![0u8; full_coded_piece_byte_len - 1];
+        assert_eq!(
+            encoder.code_into(&mut rng, &mut too_short),
+            Err(RLNCError::InvalidPieceLength {
+                expected: full_coded_piece_byte_len,
+                got: full_coded_piece_byte_len - 1
+            })
+        );
+
+        let mut too_long = vec![0u8; full_coded_piece_byte_len + 1];
+        assert_eq!(
+            encoder.code_into(&mut rng, &mut too_long),
+            Err(RLNCError::InvalidPieceLength {
+                expected: full_coded_piece_byte_len,
+                got: full_coded_piece_byte_len + 1
+            })
+        );
+
+        // Test case 2: Coded piece written into a buffer matches coding with the same coding vector
+        let mut out = vec![0xffu8; full_coded_piece_byte_len];
+        encoder.code_into(&mut rng, &mut out).expect("Coding into valid buffer must not fail");
+
+        let expected = encoder.code_with_coding_vector(&out[..piece_count]).expect("Coding must not fail");
+        assert_eq!(out, expected.as_bytes());
+
+        // Test case 3: Reusing the same buffer across a whole decoding session
+        let mut decoder = Decoder::new(encoder.get_piece_byte_len(), piece_count).expect("Failed to create Decoder for code_into test");
+        while !decoder.is_already_decoded() {
+            encoder.code_into(&mut rng, &mut out).expect("Coding into valid buffer must not fail");
+
+            let coded_piece = CodedPiece::from_bytes(out.clone(), piece_count, encoder.get_piece_byte_len()).expect("Failed to parse coded piece");
+            match decoder.decode(&coded_piece) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+        }
+
+        assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+    }
+
+    #[test]
+    fn test_encoder_code_batch() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 16usize;
+        let generation_tag = rng.random::<u32>();
+
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new_tagged(data.clone(), piece_count, generation_tag).expect("Failed to create Encoder for code_batch test");
+        let full_coded_piece_byte_len = encoder.get_full_coded_piece_byte_len();
+
+        // Test case 1: Empty batch
+        assert!(encoder.code_batch(&mut rng, 0).is_empty());
+
+        // Test case 2: Each coded piece of the batch matches coding with its own coding vector, and decodes
+        let batch_size = 2 * piece_count;
+        let batch = encoder.code_batch(&mut rng, batch_size);
+        assert_eq!(batch.len(), batch_size * full_coded_piece_byte_len);
+
+        let mut decoder = Decoder::new_tagged(encoder.get_piece_byte_len(), piece_count, generation_tag).expect("Failed to create Decoder for code_batch test");
+        batch.chunks_exact(full_coded_piece_byte_len).for_each(|full_coded_piece| {
+            let coded_piece =
+                CodedPiece::from_tagged_bytes(full_coded_piece.to_vec(), piece_count, encoder.get_piece_byte_len()).expect("Failed to parse coded piece");
+            assert_eq!(coded_piece.generation_tag(), Some(generation_tag));
+
+            let expected = encoder.code_with_coding_vector(coded_piece.coding_vector()).expect("Coding must not fail");
+            assert_eq!(coded_piece, expected);
+
+            match decoder.decode(&coded_piece) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) | Err(RLNCError::ReceivedAllPieces) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+        });
+
+        assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+
+        // Test case 3: Borrowing encoder covers its owned tail pieces too
+        let borrowed = Encoder::new_borrowed(&data, piece_count).expect("Failed to create borrowing Encoder for code_batch test");
+        borrowed
+            .code_batch(&mut rng, piece_count)
+            .chunks_exact(borrowed.get_full_coded_piece_byte_len())
+            .for_each(|full_coded_piece| {
+                let expected = borrowed
+                    .code_with_coding_vector(&full_coded_piece[..piece_count])
+                    .expect("Coding must not fail");
+                assert_eq!(full_coded_piece, expected.as_bytes());
+            });
+    }
+
+    #[test]
+    fn test_encoder_code_with_seed() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data, piece_count).expect("Failed to create Encoder for code_with_seed test");
+
+        let seed = rng.random::<u64>();
+        let seeded_coded_piece = encoder.code_with_seed(seed);
+        assert_eq!(seeded_coded_piece.len(), encoder.get_piece_byte_len());
+        assert_eq!(seeded_coded_piece, encoder.code_with_seed(seed));
+
+        let coding_vector = coding_vector_from_seed(seed, piece_count);
+        let full_coded_piece = encoder.code_with_coding_vector(&coding_vector).expect("Coding must not fail");
+        assert_eq!(seeded_coded_piece, full_coded_piece.data());
+    }
+
+    #[test]
+    fn test_encoder_append_piece() {
+        let mut rng = rand::rng();
+
+        [(1usize, 1usize), (31, 32), (32, 32), (1023, 32), (1024, 32), (4096, 7)]
+            .into_iter()
+            .for_each(|(data_byte_len, piece_count)| {
+                let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+
+                let mut owned = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder");
+                let mut borrowed = Encoder::new_borrowed(&data, piece_count).expect("Failed to create borrowed Encoder");
+                let piece_byte_len = owned.get_piece_byte_len();
+
+                // Test case 1: Piece of any other length is rejected, leaving the encoder as is
+                assert_eq!(
+                    owned.append_piece(&vec![1u8; piece_byte_len + 1]),
+                    Err(RLNCError::InvalidPieceLength {
+                        expected: piece_byte_len,
+                        got: piece_byte_len + 1
+                    })
+                );
+                assert_eq!(owned.get_piece_count(), piece_count);
+
+                // Test case 2: Grown generation decodes to the original data, followed by the appended pieces
+                let mut grown_data = data.clone();
+                let num_appended_pieces = 3;
+
+                (1..=num_appended_pieces).for_each(|num_appended| {
+                    let piece = (0..piece_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+                    grown_data.extend_from_slice(&piece);
+
+                    owned.append_piece(&piece).expect("Appending piece must not fail");
+                    borrowed.append_piece(&piece).expect("Appending piece to borrowed Encoder must not fail");
+
+                    assert_eq!(owned.get_piece_count(), piece_count + num_appended);
+                    assert_eq!(owned.get_piece_byte_len(), piece_byte_len);
+                    assert_eq!(owned.original_data_len(), grown_data.len());
+                    assert_eq!(borrowed.original_data_len(), grown_data.len());
+                });
+
+                assert!(matches!(borrowed.data, Cow::Borrowed(_)));
+
+                let coding_vector = (0..owned.get_piece_count()).map(|_| rng.random()).collect::<Vec<u8>>();
+                assert_eq!(
+                    owned.code_with_coding_vector(&coding_vector).expect("Coding must not fail"),
+                    borrowed.code_with_coding_vector(&coding_vector).expect("Coding must not fail")
+                );
+
+                let mut decoder = Decoder::matching(&owned).expect("Failed to create Decoder");
+                while !decoder.is_already_decoded() {
+                    let _ = decoder.decode(&borrowed.code(&mut rng));
+                }
+
+                assert_eq!(decoder.get_decoded_data().expect("Decoding must not fail"), grown_data);
+            });
+    }
+
+    #[test]
+    fn test_encoder_new_borrowed() {
+        let mut rng = rand::rng();
+
+        assert_eq!(
+            Encoder::new_borrowed(&[], 32).expect_err("Expected DataLengthZero error"),
+            RLNCError::DataLengthZero
+        );
+        assert_eq!(
+            Encoder::new_borrowed(&[1u8; 64], 0).expect_err("Expected PieceCountZero error"),
+            RLNCError::PieceCountZero
+        );
+
+        // Data lengths around piece boundaries, including data shorter than a single piece.
+        [
+            (1usize, 1usize),
+            (1, 16),
+            (10, 32),
+            (31, 32),
+            (32, 32),
+            (1023, 32),
+            (1024, 32),
+            (1025, 32),
+            (4096, 7),
+        ]
+        .into_iter()
+        .for_each(|(data_byte_len, piece_count)| {
+            let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+
+            let owned = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder");
+            let borrowed = Encoder::new_borrowed(&data, piece_count).expect("Failed to create borrowed Encoder");
+
+            assert_eq!(borrowed.get_piece_count(), owned.get_piece_count());
+            assert_eq!(borrowed.get_piece_byte_len(), owned.get_piece_byte_len());
+            assert_eq!(borrowed.get_full_coded_piece_byte_len(), owned.get_full_coded_piece_byte_len());
+            assert!(borrowed.tail.len() <= piece_count + borrowed.get_piece_byte_len());
+            assert_eq!(owned.original_data_len(), data_byte_len);
+            assert_eq!(borrowed.original_data_len(), data_byte_len);
+
+            (0..8).for_each(|_| {
+                let coding_vector = (0..piece_count).map(|_| rng.random()).collect::<Vec<u8>>();
+                assert_eq!(
+                    borrowed.code_with_coding_vector(&coding_vector).expect("Coding must not fail"),
+                    owned.code_with_coding_vector(&coding_vector).expect("Coding must not fail")
+                );
+            });
+
+            let mut decoder = Decoder::new(borrowed.get_piece_byte_len(), piece_count).expect("Failed to create Decoder");
+            while !decoder.is_already_decoded() {
+                match decoder.decode(&borrowed.code(&mut rng)) {
+                    Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                    Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+                }
+            }
+
+            assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+        });
+    }
+
+    #[test]
+    fn test_encoder_never_samples_zero_coding_vector() {
+        const NUM_TEST_ITERATIONS: usize = 10_000;
+
+        let mut rng = rand::rng();
+
+        // With a single source piece, uniformly sampled coding vector would be all-zero with probability 1/256.
+        let encoder = Encoder::new(vec![1, 2, 3], 1).expect("Failed to create Encoder for zero coding vector test");
+        let mut out = vec![0u8; encoder.get_full_coded_piece_byte_len()];
+
+        (0..NUM_TEST_ITERATIONS).for_each(|_| {
+            assert_ne!(encoder.code(&mut rng).as_bytes()[0], 0);
+
+            encoder.code_into(&mut rng, &mut out).expect("Coding into valid buffer must not fail");
+            assert_ne!(out[0], 0);
+        });
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_encoder_code_with_degree_distribution() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for degree distribution test");
+
+        // Test case 1: Distribution yielding degrees out of range
+        let too_large = rand::distr::Uniform::new_inclusive(piece_count + 1, piece_count + 1).expect("Failed to create Uniform distribution");
+        assert_eq!(encoder.code_with_degree_distribution(&mut rng, &too_large), Err(RLNCError::DegreeOutOfRange));
+
+        let zero = rand::distr::Uniform::new_inclusive(0usize, 0).expect("Failed to create Uniform distribution");
+        assert_eq!(encoder.code_with_degree_distribution(&mut rng, &zero), Err(RLNCError::DegreeOutOfRange));
+
+        // Test case 2: Pieces coded with robust soliton distributed degrees decode
+        let distribution = RobustSoliton::new(piece_count, 0.1, 0.05).expect("Failed to create RobustSoliton distribution");
+        let mut decoder = Decoder::new(encoder.get_piece_byte_len(), piece_count).expect("Failed to create Decoder for degree distribution test");
+
+        while !decoder.is_already_decoded() {
+            let coded_piece = encoder
+                .code_with_degree_distribution(&mut rng, &distribution)
+                .expect("Coding with valid degree distribution must not fail");
+
+            let degree = coded_piece.coding_vector().iter().filter(|&&coefficient| coefficient != 0).count();
+            assert!((1..=piece_count).contains(&degree));
+
+            match decoder.decode(&coded_piece) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+        }
+
+        assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+    }
+
+    #[test]
+    fn test_encoder_coded_pieces() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for coded_pieces test");
+
+        // Iterator yields same pieces as calling `code`, with an identically seeded RNG.
+        let seed = rng.random::<u64>();
+        let mut rng_a = StdRng::seed_from_u64(seed);
+        let mut rng_b = StdRng::seed_from_u64(seed);
+
+        let coded_pieces = encoder.coded_pieces(&mut rng_a).take(piece_count).collect::<Vec<CodedPiece>>();
+        assert_eq!(coded_pieces.len(), piece_count);
+        coded_pieces.iter().for_each(|coded_piece| assert_eq!(coded_piece, &encoder.code(&mut rng_b)));
+
+        let mut decoder = Decoder::new(encoder.get_piece_byte_len(), piece_count).expect("Failed to create Decoder for coded_pieces test");
+        for coded_piece in encoder.coded_pieces(&mut rng) {
+            match decoder.decode(&coded_piece) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+
+            if decoder.is_already_decoded() {
+                break;
+            }
+        }
+
+        assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+    }
+
+    #[test]
+    fn test_encoder_code_indexed() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for code_indexed test");
+
+        let piece_index = rng.random::<u64>();
+        let coded_piece = encoder.code_indexed(piece_index);
+        assert_eq!(coded_piece, encoder.code_indexed(piece_index));
+        assert_ne!(coded_piece, encoder.code_indexed(piece_index.wrapping_add(1)));
+
+        let coding_vector = coding_vector_from_seed(piece_index, piece_count);
+        assert_eq!(coded_piece, encoder.code_with_coding_vector(&coding_vector).expect("Coding must not fail"));
+
+        // Known answer, pinning the sequence of coded pieces, for a fixed tiny input, across platforms and releases.
+        let tiny_encoder = Encoder::new(vec![1, 2, 3], 2).expect("Failed to create Encoder for code_indexed test");
+        assert_eq!(tiny_encoder.code_indexed(0).as_bytes(), [108, 59, 33, 47]);
+
+        // Replaying indices 0, 1, 2, ... decodes the data.
+        let mut decoder = Decoder::new(encoder.get_piece_byte_len(), piece_count).expect("Failed to create Decoder for code_indexed test");
+        let mut piece_index = 0u64;
+        while !decoder.is_already_decoded() {
+            match decoder.decode(&encoder.code_indexed(piece_index)) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+            piece_index += 1;
+        }
+
+        assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+    }
+
+    #[test]
+    fn test_encoder_code_vandermonde() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for code_vandermonde test");
+
+        // Test case 1: Evaluation points are exhausted
+        assert_eq!(
+            encoder
+                .code_vandermonde(VANDERMONDE_EVALUATION_POINT_COUNT)
+                .expect_err("Expected PieceIndexOutOfRange error"),
+            RLNCError::PieceIndexOutOfRange
+        );
+
+        // Test case 2: Coding vector consists of successive powers of the evaluation point
+        let coded_piece = encoder.code_vandermonde(1).expect("Coding must not fail");
+        assert!(
+            coded_piece
+                .coding_vector()
+                .iter()
+                .enumerate()
+                .all(|(idx, &coefficient)| coefficient == Gf256::primitive_element().pow(idx as u32).get())
+        );
+
+        // Test case 3: Any `piece_count` many pieces decode, without a single one of them being useless
+        let mut decoder = Decoder::new(encoder.get_piece_byte_len(), piece_count).expect("Failed to create Decoder for code_vandermonde test");
+        rand::seq::index::sample(&mut rng, VANDERMONDE_EVALUATION_POINT_COUNT, piece_count)
+            .into_iter()
+            .for_each(|piece_index| {
+                let coded_piece = encoder.code_vandermonde(piece_index).expect("Coding must not fail");
+                decoder.decode(&coded_piece).expect("Vandermonde coded piece must be useful");
+            });
+
+        assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+    }
 }
@@ -0,0 +1,166 @@
+//! Framing helpers for a self-describing wire format, where each packet carries the RLNC configuration, i.e. the number
+//! of pieces coded together and the byte length of each piece, in a small header, so that a receiver can bootstrap its
+//! `Decoder` purely from the first packet, instead of agreeing on the configuration out of band.
+//!
+//! A frame header is `FRAME_HEADER_BYTE_LEN` bytes: the piece count as a `u16`, the piece byte length as a `u32` and the
+//! lower 16 bits of the CRC-32 of those 6 bytes, as a `u16`, all in little-endian byte order. The header doesn't say
+//! whether the following full coded piece is prefixed by a generation tag, as a receiver expecting a generation must
+//! already know its tag, for creating a `Decoder` using `Decoder::new_tagged`.
+
+use super::checksum::crc32;
+use crate::RLNCError;
+
+/// Byte length of the frame header, prepended to each full coded piece by `Encoder::code_framed`.
+pub const FRAME_HEADER_BYTE_LEN: usize = 8;
+
+/// Byte length of the part of the frame header, which is protected by its check.
+const FRAME_HEADER_BODY_BYTE_LEN: usize = FRAME_HEADER_BYTE_LEN - 2;
+
+/// Computes the check of a frame header body, i.e. the lower 16 bits of its CRC-32.
+fn frame_header_check(header_body: &[u8]) -> u16 {
+    crc32(header_body) as u16
+}
+
+/// Encodes the RLNC configuration, i.e. `piece_count` pieces of `piece_byte_len` bytes each, into a frame header.
+///
+/// # Returns
+/// Returns `Ok(header)` on success.
+/// Returns `Err(RLNCError::PieceCountZero)` if `piece_count` is zero.
+/// Returns `Err(RLNCError::PieceLengthZero)` if `piece_byte_len` is zero.
+/// Returns `Err(RLNCError::InvalidFrameHeader)` if `piece_count` doesn't fit in a `u16` or `piece_byte_len` doesn't fit in a `u32`.
+pub fn encode_header(piece_count: usize, piece_byte_len: usize) -> Result<[u8; FRAME_HEADER_BYTE_LEN], RLNCError> {
+    if piece_count == 0 {
+        return Err(RLNCError::PieceCountZero);
+    }
+    if piece_byte_len == 0 {
+        return Err(RLNCError::PieceLengthZero);
+    }
+
+    let piece_count = u16::try_from(piece_count).map_err(|_| RLNCError::InvalidFrameHeader)?;
+    let piece_byte_len = u32::try_from(piece_byte_len).map_err(|_| RLNCError::InvalidFrameHeader)?;
+
+    let mut header = [0u8; FRAME_HEADER_BYTE_LEN];
+    header[..2].copy_from_slice(&piece_count.to_le_bytes());
+    header[2..FRAME_HEADER_BODY_BYTE_LEN].copy_from_slice(&piece_byte_len.to_le_bytes());
+
+    let check = frame_header_check(&header[..FRAME_HEADER_BODY_BYTE_LEN]);
+    header[FRAME_HEADER_BODY_BYTE_LEN..].copy_from_slice(&check.to_le_bytes());
+
+    Ok(header)
+}
+
+/// Decodes the RLNC configuration from the frame header at the beginning of `frame`, which can be a whole framed packet,
+/// as only its first `FRAME_HEADER_BYTE_LEN` bytes are read. The full coded piece follows the header, in a packet
+/// produced by `Encoder::code_framed`.
+///
+/// # Returns
+/// Returns `Ok((piece_count, piece_byte_len))` on success, ready to be passed to `Decoder::new`, in reverse order.
+/// Returns `Err(RLNCError::InvalidFrameHeader)` if `frame` is shorter than a frame header, or the header doesn't match its
+/// check, or it holds a zero piece count or piece byte length, which `encode_header` never produces.
+pub fn decode_header(frame: &[u8]) -> Result<(usize, usize), RLNCError> {
+    let header = frame.get(..FRAME_HEADER_BYTE_LEN).ok_or(RLNCError::InvalidFrameHeader)?;
+    let (header_body, check) = header.split_at(FRAME_HEADER_BODY_BYTE_LEN);
+
+    if frame_header_check(header_body) != u16::from_le_bytes([check[0], check[1]]) {
+        return Err(RLNCError::InvalidFrameHeader);
+    }
+
+    let piece_count = u16::from_le_bytes([header_body[0], header_body[1]]) as usize;
+    let piece_byte_len = u32::from_le_bytes([header_body[2], header_body[3], header_body[4], header_body[5]]) as usize;
+
+    if piece_count == 0 || piece_byte_len == 0 {
+        return Err(RLNCError::InvalidFrameHeader);
+    }
+
+    Ok((piece_count, piece_byte_len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FRAME_HEADER_BYTE_LEN, decode_header, encode_header};
+    use crate::{
+        RLNCError,
+        full::{coded_piece::CodedPiece, decoder::Decoder, encoder::Encoder},
+    };
+    use rand::Rng;
+
+    #[test]
+    fn test_frame_header_round_trip() {
+        let mut rng = rand::rng();
+
+        let configs = [(1, 1), (u16::MAX as usize, u32::MAX as usize), (32, 1024)]
+            .into_iter()
+            .chain((0..100).map(|_| (rng.random_range(1..=u16::MAX as usize), rng.random_range(1..=u32::MAX as usize))));
+
+        configs.for_each(|(piece_count, piece_byte_len)| {
+            let header = encode_header(piece_count, piece_byte_len).expect("Encoding valid frame header must not fail");
+            assert_eq!(decode_header(&header), Ok((piece_count, piece_byte_len)));
+
+            // Only the header is read, out of a whole framed packet.
+            let mut frame = header.to_vec();
+            frame.extend_from_slice(&[0xff; 16]);
+            assert_eq!(decode_header(&frame), Ok((piece_count, piece_byte_len)));
+        });
+
+        assert_eq!(encode_header(0, 1024), Err(RLNCError::PieceCountZero));
+        assert_eq!(encode_header(32, 0), Err(RLNCError::PieceLengthZero));
+        assert_eq!(encode_header(u16::MAX as usize + 1, 1024), Err(RLNCError::InvalidFrameHeader));
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(encode_header(32, u32::MAX as usize + 1), Err(RLNCError::InvalidFrameHeader));
+    }
+
+    #[test]
+    fn test_frame_header_corruption_is_detected() {
+        let header = encode_header(32, 1024).expect("Encoding valid frame header must not fail");
+
+        assert_eq!(decode_header(&header[..FRAME_HEADER_BYTE_LEN - 1]), Err(RLNCError::InvalidFrameHeader));
+        assert_eq!(decode_header(&[]), Err(RLNCError::InvalidFrameHeader));
+        assert_eq!(decode_header(&[0u8; FRAME_HEADER_BYTE_LEN]), Err(RLNCError::InvalidFrameHeader));
+
+        (0..(FRAME_HEADER_BYTE_LEN * u8::BITS as usize)).for_each(|bit_idx| {
+            let mut corrupted = header;
+            corrupted[bit_idx / 8] ^= 1 << (bit_idx % 8);
+
+            assert_eq!(
+                decode_header(&corrupted),
+                Err(RLNCError::InvalidFrameHeader),
+                "flipping bit {bit_idx} went unnoticed"
+            );
+        });
+    }
+
+    #[test]
+    fn test_decoder_bootstraps_from_framed_pieces() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 16usize;
+
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for framing test");
+
+        let first_frame = encoder.code_framed(&mut rng).expect("Coding framed piece must not fail");
+        assert_eq!(first_frame.len(), FRAME_HEADER_BYTE_LEN + encoder.get_full_coded_piece_byte_len());
+
+        let (received_piece_count, received_piece_byte_len) = decode_header(&first_frame).expect("Decoding frame header must not fail");
+        assert_eq!((received_piece_count, received_piece_byte_len), (piece_count, encoder.get_piece_byte_len()));
+
+        let mut decoder = Decoder::new(received_piece_byte_len, received_piece_count).expect("Failed to create Decoder from frame header");
+
+        let mut frame = first_frame;
+        while !decoder.is_already_decoded() {
+            assert_eq!(decode_header(&frame), Ok((received_piece_count, received_piece_byte_len)));
+
+            let coded_piece = CodedPiece::from_bytes(frame[FRAME_HEADER_BYTE_LEN..].to_vec(), received_piece_count, received_piece_byte_len)
+                .expect("Failed to parse coded piece");
+            match decoder.decode(&coded_piece) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+
+            frame = encoder.code_framed(&mut rng).expect("Coding framed piece must not fail");
+        }
+
+        assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+    }
+}
@@ -1,6 +1,17 @@
+pub mod checksum;
+pub mod coded_piece;
 pub mod decoder;
+#[cfg(feature = "std")]
+pub mod decoder_pool;
+#[cfg(feature = "std")]
+pub mod degree_distribution;
 pub mod encoder;
+pub mod frame;
+pub mod padding;
+pub mod recoded_decoder;
 pub mod recoder;
+pub mod seed;
+pub mod sliding_window;
 
 mod consts;
 mod decoder_matrix;
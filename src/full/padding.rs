@@ -0,0 +1,116 @@
+//! Padding scheme, which makes data of any length splittable into equal sized pieces, as used by `Encoder::new`, and
+//! undone by `Decoder::get_decoded_data`. A 1 -byte `BOUNDARY_MARKER` is appended to the data, followed by as many zero
+//! bytes as needed, for the padded data to be a multiple of the piece count. See `BOUNDARY_MARKER` for why trimming
+//! the padding is never ambiguous.
+
+pub use super::consts::BOUNDARY_MARKER;
+use super::encoder::checked_buffer_byte_len;
+use crate::RLNCError;
+use alloc::vec::Vec;
+
+/// Byte length of the boundary marker, appended to the data, before zero padding it.
+const BOUNDARY_MARKER_BYTE_LEN: usize = 1;
+
+/// Pads `data` in place, appending the boundary marker and zero bytes, so that it can be split into `piece_count` many
+/// equal sized pieces, each of `(data.len() + 1).div_ceil(piece_count)` bytes.
+///
+/// # Returns
+/// Returns `Ok(usize)`, the byte length of each piece, on success.
+/// Returns `Err(RLNCError::DataLengthZero)` if `data` is empty.
+/// Returns `Err(RLNCError::PieceCountZero)` if `piece_count` is zero.
+/// Returns `Err(RLNCError::ParametersTooLarge)` if the padded data would be longer than `isize::MAX` bytes.
+pub fn pad(data: &mut Vec<u8>, piece_count: usize) -> Result<usize, RLNCError> {
+    if data.is_empty() {
+        return Err(RLNCError::DataLengthZero);
+    }
+    if piece_count == 0 {
+        return Err(RLNCError::PieceCountZero);
+    }
+
+    let piece_byte_len = (data.len() + BOUNDARY_MARKER_BYTE_LEN).div_ceil(piece_count);
+    pad_into_pieces(data, piece_count, piece_byte_len)?;
+
+    Ok(piece_byte_len)
+}
+
+/// Pads `data` in place, appending the boundary marker and zero bytes, so that it becomes `piece_count` many pieces of
+/// `piece_byte_len` bytes each, which must leave room for the boundary marker.
+///
+/// # Returns
+/// Returns `Ok(())` on success.
+/// Returns `Err(RLNCError::ParametersTooLarge)` if the padded data would be longer than `isize::MAX` bytes.
+pub(crate) fn pad_into_pieces(data: &mut Vec<u8>, piece_count: usize, piece_byte_len: usize) -> Result<(), RLNCError> {
+    let padded_data_len = checked_buffer_byte_len(piece_count, piece_byte_len)?;
+    debug_assert!(data.len() < padded_data_len);
+
+    let in_data_len = data.len();
+    data.resize(padded_data_len, 0);
+    data[in_data_len] = BOUNDARY_MARKER;
+
+    Ok(())
+}
+
+/// Trims the boundary marker, along with the zero bytes following it, from `data` in place, recovering the original data,
+/// which was padded using `pad`. The boundary marker is the last non-zero byte of the padded data.
+///
+/// # Returns
+/// Returns `Ok(())` on success.
+/// Returns `Err(RLNCError::InvalidDecodedDataFormat)` if the last non-zero byte of `data` is not the boundary marker, or
+/// there is no data before it, in which case `data` is left as is.
+pub fn unpad(data: &mut Vec<u8>) -> Result<(), RLNCError> {
+    let boundary_marker_index = data.iter().rposition(|&byte| byte != 0).ok_or(RLNCError::InvalidDecodedDataFormat)?;
+    if data[boundary_marker_index] != BOUNDARY_MARKER || boundary_marker_index == 0 {
+        return Err(RLNCError::InvalidDecodedDataFormat);
+    }
+
+    data.truncate(boundary_marker_index);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BOUNDARY_MARKER, pad, unpad};
+    use crate::RLNCError;
+    use alloc::{vec, vec::Vec};
+    use rand::Rng;
+
+    #[test]
+    fn test_pad_unpad() {
+        let mut rng = rand::rng();
+
+        // Test case 1: Invalid inputs
+        assert_eq!(pad(&mut Vec::new(), 4), Err(RLNCError::DataLengthZero));
+        assert_eq!(pad(&mut vec![1], 0), Err(RLNCError::PieceCountZero));
+
+        assert_eq!(unpad(&mut Vec::new()), Err(RLNCError::InvalidDecodedDataFormat));
+        assert_eq!(unpad(&mut vec![0, 0]), Err(RLNCError::InvalidDecodedDataFormat));
+        assert_eq!(unpad(&mut vec![BOUNDARY_MARKER, 0]), Err(RLNCError::InvalidDecodedDataFormat));
+
+        let mut not_padded = vec![1, 2, 0];
+        assert_eq!(unpad(&mut not_padded), Err(RLNCError::InvalidDecodedDataFormat));
+        assert_eq!(not_padded, [1, 2, 0]);
+
+        // Test case 2: Padded data splits into equal sized pieces, and unpadding recovers the original data, even when it
+        // ends with the boundary marker, followed by zeros
+        for data in [
+            (0..1023).map(|_| rng.random()).collect::<Vec<u8>>(),
+            vec![1, BOUNDARY_MARKER],
+            vec![BOUNDARY_MARKER, 0, 0],
+            vec![0],
+        ] {
+            for piece_count in [1, 2, 16, 32, data.len() + 8] {
+                let mut padded = data.clone();
+                let piece_byte_len = pad(&mut padded, piece_count).expect("Padding must not fail");
+
+                assert_eq!(piece_byte_len, (data.len() + 1).div_ceil(piece_count));
+                assert_eq!(padded.len(), piece_count * piece_byte_len);
+                assert_eq!(padded[..data.len()], data);
+                assert_eq!(padded[data.len()], BOUNDARY_MARKER);
+                assert!(padded[(data.len() + 1)..].iter().all(|&byte| byte == 0));
+
+                unpad(&mut padded).expect("Unpadding must not fail");
+                assert_eq!(padded, data);
+            }
+        }
+    }
+}
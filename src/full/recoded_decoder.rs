@@ -0,0 +1,240 @@
+use super::{coded_piece::CodedPiece, consts::GENERATION_TAG_BYTE_LEN, decoder::Decoder, encoder::sample_coding_vector_into};
+use crate::{RLNCError, common::simd::gf256_mul_vec_by_scalar_then_add_into_vec};
+use alloc::vec;
+use rand::Rng;
+
+/// `RecodedDecoder` is meant for relay nodes, which both recode received pieces for their downstream peers and try to
+/// decode the original data themselves, without keeping a separate `Recoder` and `Decoder`, fed with the same pieces.
+///
+/// It wraps a `Decoder` and recodes directly from the rows of the decoder's matrix, in reduced row echelon form, which
+/// are a linearly independent basis of everything received so far. So, there is a single copy of the received data,
+/// and recoded pieces are never combined from redundant pieces. Unlike `Decoder::into_recoder`, the decoder keeps
+/// absorbing pieces, while recoding from it, and each recoded piece covers all the useful pieces received so far.
+#[derive(Clone, Debug)]
+pub struct RecodedDecoder {
+    decoder: Decoder,
+}
+
+impl RecodedDecoder {
+    /// Creates a new `RecodedDecoder`, wrapping a decoder created using `Decoder::new`, which holds no coded pieces yet.
+    ///
+    /// # Returns
+    /// Same as `Decoder::new`.
+    pub fn new(piece_byte_len: usize, required_piece_count: usize) -> Result<RecodedDecoder, RLNCError> {
+        Ok(RecodedDecoder {
+            decoder: Decoder::new(piece_byte_len, required_piece_count)?,
+        })
+    }
+
+    /// Returns the wrapped decoder, e.g. for inspecting its statistics or for recovering the decoded data, using
+    /// `Decoder::get_decoded_data_ref`.
+    pub fn get_decoder(&self) -> &Decoder {
+        &self.decoder
+    }
+
+    /// Unwraps the decoder, e.g. for recovering the decoded data, using `Decoder::get_decoded_data`.
+    pub fn into_decoder(self) -> Decoder {
+        self.decoder
+    }
+
+    /// Number of linearly independent pieces received so far, which is getting recoded together, producing new pieces.
+    pub fn get_num_pieces_recoded_together(&self) -> usize {
+        self.decoder.rank()
+    }
+
+    /// Each recoded full coded piece is of same byte length as each received one, i.e. `Decoder::get_full_coded_piece_byte_len`.
+    pub fn get_full_coded_piece_byte_len(&self) -> usize {
+        self.decoder.get_full_coded_piece_byte_len()
+    }
+
+    /// Decodes a received full coded piece, same as `Decoder::decode`, so that it takes part in the following recodings,
+    /// if it is useful.
+    pub fn decode(&mut self, full_coded_piece: &CodedPiece) -> Result<(), RLNCError> {
+        self.decoder.decode(full_coded_piece)
+    }
+
+    /// Generates a new coded piece by linearly combining the rows of the decoder's matrix, using a randomly sampled,
+    /// never all-zero, recoding vector of `self.get_num_pieces_recoded_together()` symbols. As the rows are linearly
+    /// independent, the coding vector of the recoded piece is never all-zero either. If the decoder is tagged, the
+    /// recoded piece carries its expected generation tag, so that downstream decoders, expecting the same generation,
+    /// accept it.
+    ///
+    /// # Returns
+    /// Returns `Ok(CodedPiece)`, of `self.get_full_coded_piece_byte_len()` bytes, on success.
+    /// Returns `Err(RLNCError::NotEnoughPiecesToRecode)` if no useful piece is received yet.
+    pub fn recode<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<CodedPiece, RLNCError> {
+        let mut full_recoded_piece = vec![0u8; self.get_full_coded_piece_byte_len()];
+        self.recode_into(rng, &mut full_recoded_piece)?;
+
+        let is_tagged = self.decoder.get_expected_generation_tag().is_some();
+        Ok(CodedPiece::new_unchecked(
+            full_recoded_piece,
+            self.decoder.get_num_pieces_coded_together(),
+            self.decoder.get_piece_byte_len(),
+            is_tagged,
+        ))
+    }
+
+    /// Same as `Self::recode`, but writing the full recoded piece into the caller-provided `out` buffer, instead of
+    /// allocating a new one. Previous content of `out` is overwritten.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success, in which case `out` holds the recoded piece prefixed by its source coding vector,
+    /// and by the generation tag, if any.
+    /// Returns `Err(RLNCError::InvalidPieceLength)` if `out.len()` is not `self.get_full_coded_piece_byte_len()`.
+    /// Returns `Err(RLNCError::NotEnoughPiecesToRecode)` if no useful piece is received yet.
+    pub fn recode_into<R: Rng + ?Sized>(&self, rng: &mut R, out: &mut [u8]) -> Result<(), RLNCError> {
+        if out.len() != self.get_full_coded_piece_byte_len() {
+            return Err(RLNCError::InvalidPieceLength {
+                expected: self.get_full_coded_piece_byte_len(),
+                got: out.len(),
+            });
+        }
+        if self.get_num_pieces_recoded_together() == 0 {
+            return Err(RLNCError::NotEnoughPiecesToRecode);
+        }
+
+        let untagged_out = match self.decoder.get_expected_generation_tag() {
+            Some(generation_tag) => {
+                let (tag, rest) = out.split_at_mut(GENERATION_TAG_BYTE_LEN);
+                tag.copy_from_slice(&generation_tag.to_le_bytes());
+                rest
+            }
+            None => out,
+        };
+
+        let mut random_recoding_vector = vec![0u8; self.get_num_pieces_recoded_together()];
+        sample_coding_vector_into(rng, &mut random_recoding_vector);
+
+        let (coding_vector, recoded_piece) = untagged_out.split_at_mut(self.decoder.get_num_pieces_coded_together());
+        coding_vector.fill(0);
+        recoded_piece.fill(0);
+
+        random_recoding_vector.iter().enumerate().for_each(|(row_idx, &random_symbol)| {
+            gf256_mul_vec_by_scalar_then_add_into_vec(coding_vector, self.decoder.matrix.coefficients(row_idx), random_symbol);
+            gf256_mul_vec_by_scalar_then_add_into_vec(recoded_piece, self.decoder.matrix.payload(row_idx), random_symbol);
+        });
+
+        Ok(())
+    }
+}
+
+impl From<Decoder> for RecodedDecoder {
+    /// Wraps an existing, possibly tagged or partially filled, decoder, so that it can recode from the pieces already
+    /// received by it.
+    fn from(decoder: Decoder) -> Self {
+        RecodedDecoder { decoder }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RLNCError, RecodedDecoder};
+    use crate::full::{decoder::Decoder, encoder::Encoder};
+    use rand::Rng;
+
+    #[test]
+    fn test_recoded_decoder_relays_while_decoding() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 16usize;
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for recoded decoder test");
+
+        let mut relay = RecodedDecoder::new(encoder.get_piece_byte_len(), piece_count).expect("Failed to create RecodedDecoder");
+        assert_eq!(relay.recode(&mut rng).map(|_| ()), Err(RLNCError::NotEnoughPiecesToRecode));
+        assert_eq!(relay.get_full_coded_piece_byte_len(), encoder.get_full_coded_piece_byte_len());
+
+        let mut out = vec![0u8; relay.get_full_coded_piece_byte_len() - 1];
+        assert_eq!(
+            relay.recode_into(&mut rng, &mut out),
+            Err(RLNCError::InvalidPieceLength {
+                expected: encoder.get_full_coded_piece_byte_len(),
+                got: out.len()
+            })
+        );
+
+        let mut downstream = Decoder::new(encoder.get_piece_byte_len(), piece_count).expect("Failed to create downstream Decoder");
+
+        // Relay receives half of the pieces, each of them twice, while recoding for downstream, all the way.
+        let num_received_pieces = piece_count / 2;
+        (0..num_received_pieces).for_each(|_| {
+            let coded_piece = encoder.code(&mut rng);
+            relay.decode(&coded_piece).expect("Decoding useful piece must not fail");
+            assert_eq!(relay.decode(&coded_piece), Err(RLNCError::PieceNotUseful));
+
+            let recoded_piece = relay.recode(&mut rng).expect("Recoding must not fail");
+            assert!(recoded_piece.coding_vector().iter().any(|&coefficient| coefficient != 0));
+
+            match downstream.decode(&recoded_piece) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+        });
+        assert_eq!(relay.get_num_pieces_recoded_together(), num_received_pieces);
+
+        // Recoded pieces never carry more information than the relay holds.
+        while downstream.get_useful_piece_count() < num_received_pieces {
+            match downstream.decode(&relay.recode(&mut rng).expect("Recoding must not fail")) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+        }
+        assert_eq!(downstream.is_useful(&relay.recode(&mut rng).expect("Recoding must not fail")), Ok(false));
+
+        // Once the relay decodes, pieces recoded by it are enough for downstream to decode too.
+        while !relay.get_decoder().is_already_decoded() {
+            match relay.decode(&encoder.code(&mut rng)) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+        }
+        while !downstream.is_already_decoded() {
+            match downstream.decode(&relay.recode(&mut rng).expect("Recoding must not fail")) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+        }
+
+        assert_eq!(downstream.get_decoded_data().expect("Decoding must succeed"), data);
+        assert_eq!(relay.into_decoder().get_decoded_data().expect("Decoding must succeed"), data);
+    }
+
+    #[test]
+    fn test_recoded_decoder_keeps_generation_tag() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 8usize;
+        let generation_tag = rng.random::<u32>();
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new_tagged(data.clone(), piece_count, generation_tag).expect("Failed to create tagged Encoder");
+
+        let decoder = Decoder::new_tagged(encoder.get_piece_byte_len(), piece_count, generation_tag).expect("Failed to create tagged Decoder");
+        let mut relay = RecodedDecoder::from(decoder);
+        assert_eq!(relay.get_full_coded_piece_byte_len(), encoder.get_full_coded_piece_byte_len());
+
+        let mut downstream = Decoder::new_tagged(encoder.get_piece_byte_len(), piece_count, generation_tag).expect("Failed to create tagged Decoder");
+
+        while !downstream.is_already_decoded() {
+            // Relay may decode ahead of downstream, after which it keeps recoding from the full basis.
+            if !relay.get_decoder().is_already_decoded() {
+                match relay.decode(&encoder.code(&mut rng)) {
+                    Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                    Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+                }
+            }
+
+            let recoded_piece = relay.recode(&mut rng).expect("Recoding must not fail");
+            assert_eq!(recoded_piece.generation_tag(), Some(generation_tag));
+
+            match downstream.decode(&recoded_piece) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+        }
+
+        assert_eq!(downstream.get_decoded_data().expect("Decoding must succeed"), data);
+    }
+}
@@ -1,6 +1,20 @@
-use super::encoder::Encoder;
-use crate::{RLNCError, common::gf256::Gf256};
-use rand::Rng;
+use super::{
+    coded_piece::{CodedPiece, concatenated_pieces_length_error},
+    consts::GENERATION_TAG_BYTE_LEN,
+    decoder_matrix::DecoderMatrix,
+    encoder::{Encoder, checked_buffer_byte_len},
+    frame::{FRAME_HEADER_BYTE_LEN, decode_header},
+};
+use crate::RLNCError;
+use alloc::{vec, vec::Vec};
+
+#[cfg(feature = "parallel")]
+use super::encoder::sample_coding_vector_into;
+#[cfg(not(feature = "parallel"))]
+use crate::common::simd::gf256_mul_vec_by_scalar_then_add_into_vec;
+#[cfg(feature = "parallel")]
+use crate::common::simd::gf256_vec_mat_mul;
+use rand::{Rng, RngCore};
 
 /// `Recoder` takes already coded pieces and recodes these coded pieces using
 /// a new random sampled coding vector. This is useful for distributing coded
@@ -12,11 +26,12 @@ use rand::Rng;
 /// Internally, it uses an `Encoder` initialized with the coded pieces.
 #[derive(Clone, Debug)]
 pub struct Recoder {
-    coding_vectors: Vec<Gf256>,
-    encoder: Encoder,
+    coding_vectors: Vec<u8>,
+    encoder: Encoder<'static>,
     num_pieces_received: usize,
     full_coded_piece_byte_len: usize,
     num_pieces_coded_together: usize,
+    generation_tag: Option<u32>,
 }
 
 impl Recoder {
@@ -40,6 +55,16 @@ impl Recoder {
         self.full_coded_piece_byte_len
     }
 
+    /// Generation tag, each received piece must be prefixed with, and each piece recoded using `Self::recode_tagged` is
+    /// prefixed with, if the recoder was created using `Self::new_tagged` or `Self::with_capacity_tagged`.
+    pub fn get_generation_tag(&self) -> Option<u32> {
+        self.generation_tag
+    }
+
+    fn get_generation_tag_byte_len(&self) -> usize {
+        if self.generation_tag.is_some() { GENERATION_TAG_BYTE_LEN } else { 0 }
+    }
+
     /// Creates a new `Recoder` instance from a vector of received coded pieces.
     ///
     /// Each full coded piece in `data` is of `full_coded_piece_byte_len` bytes.
@@ -65,10 +90,199 @@ impl Recoder {
     /// Returns `Err(RLNCError::PieceLengthZero)` if `full_coded_piece_byte_len` is zero.
     /// Returns `Err(RLNCError::PieceCountZero)` if `num_pieces_coded_together` is zero.
     /// Returns `Err(RLNCError::PieceLengthTooShort)` if `full_coded_piece_byte_len` is not greater than `num_pieces_coded_together`.
+    /// Returns `Err(RLNCError::ParametersTooLarge)` if `num_pieces_coded_together` many full coded pieces would be longer than
+    /// `isize::MAX` bytes.
+    /// Returns `Err(RLNCError::InvalidPieceLength)` if length of `data` is not a multiple of `full_coded_piece_byte_len`,
+    /// e.g. when the last piece got truncated, instead of silently dropping the trailing bytes.
+    ///
+    /// Note, nothing in a full coded piece tells how many source pieces it was coded from, so that pieces coded with a
+    /// different piece count, but happening to have the same full coded piece byte length, can't be told apart. They
+    /// are accepted as they are, while their coding vectors get misread, producing recoded pieces no decoder can use.
+    /// Pieces received from untrusted or misconfigured upstreams are better framed, using `Encoder::code_framed`, and
+    /// passed to `Self::new_framed`, which rejects pieces of a different configuration.
     pub fn new(data: Vec<u8>, full_coded_piece_byte_len: usize, num_pieces_coded_together: usize) -> Result<Recoder, RLNCError> {
         if data.is_empty() {
             return Err(RLNCError::NotEnoughPiecesToRecode);
         }
+
+        let num_pieces_received = data.len().checked_div(full_coded_piece_byte_len).unwrap_or(0);
+
+        let mut recoder = Self::with_capacity(full_coded_piece_byte_len, num_pieces_coded_together, num_pieces_received)?;
+        if num_pieces_received == 0 {
+            return Err(RLNCError::NotEnoughPiecesToRecode);
+        }
+        if data.len() % full_coded_piece_byte_len != 0 {
+            return Err(concatenated_pieces_length_error(data.len(), full_coded_piece_byte_len));
+        }
+
+        data.chunks_exact(full_coded_piece_byte_len)
+            .for_each(|full_coded_piece| unsafe { recoder.add_piece(full_coded_piece).unwrap_unchecked() });
+
+        Ok(recoder)
+    }
+
+    /// Creates a new `Recoder` instance from a vector of received coded pieces, same as `Self::new` does, but reusing the
+    /// allocation backing `data` as storage for the coded pieces, instead of copying them into a freshly allocated one, so
+    /// that a relay receiving pieces into a big arena doesn't need to keep a second copy of them.
+    ///
+    /// Each full coded piece in `data` interleaves its coding vector with its coded piece, while recoding needs the coded
+    /// pieces laid out back to back, for linearly combining them. Walking a strided view of `data` instead would avoid
+    /// moving any byte, but would also have to be taught to every SIMD and parallel linear combination routine, so `data`
+    /// gets de-interleaved in place instead. Only the coding vectors, `num_pieces_coded_together` bytes per piece, are
+    /// copied out, while the coded pieces are shifted towards the front of `data`, which gets truncated afterwards. Cost is
+    /// a single pass of `memmove` over `data`, which is far cheaper than the recoding itself, while no memory is allocated
+    /// for the coded pieces.
+    ///
+    /// # Returns
+    /// Same as `Self::new`.
+    pub fn from_owned(mut data: Vec<u8>, full_coded_piece_byte_len: usize, num_pieces_coded_together: usize) -> Result<Recoder, RLNCError> {
+        if data.is_empty() {
+            return Err(RLNCError::NotEnoughPiecesToRecode);
+        }
+
+        let num_pieces_received = data.len().checked_div(full_coded_piece_byte_len).unwrap_or(0);
+
+        let mut recoder = Self::with_capacity(full_coded_piece_byte_len, num_pieces_coded_together, 0)?;
+        if num_pieces_received == 0 {
+            return Err(RLNCError::NotEnoughPiecesToRecode);
+        }
+        if data.len() % full_coded_piece_byte_len != 0 {
+            return Err(concatenated_pieces_length_error(data.len(), full_coded_piece_byte_len));
+        }
+
+        let piece_byte_len = recoder.get_piece_byte_len();
+        let mut coding_vectors = Vec::with_capacity(num_pieces_received * num_pieces_coded_together);
+
+        // Coded piece of `piece_idx` -th full coded piece moves to where no later full coded piece, still to be read, begins.
+        (0..num_pieces_received).for_each(|piece_idx| {
+            let piece_begins_at = piece_idx * full_coded_piece_byte_len;
+            let coded_piece_begins_at = piece_begins_at + num_pieces_coded_together;
+
+            coding_vectors.extend_from_slice(&data[piece_begins_at..coded_piece_begins_at]);
+            data.copy_within(coded_piece_begins_at..(piece_begins_at + full_coded_piece_byte_len), piece_idx * piece_byte_len);
+        });
+        data.truncate(num_pieces_received * piece_byte_len);
+
+        recoder.coding_vectors = coding_vectors;
+        recoder.encoder = Encoder::with_owned_pieces(data, piece_byte_len);
+        recoder.num_pieces_received = num_pieces_received;
+
+        Ok(recoder)
+    }
+
+    /// Creates a new `Recoder` instance from a vector of received coded pieces, same as `Self::new` does, but refusing
+    /// `data` implying more than `max_pieces` many received full coded pieces, before allocating any buffer, so that a
+    /// public-facing relay can't be made to allocate unbounded memory by an untrusted upstream.
+    ///
+    /// # Returns
+    /// Same as `Self::new`, while additionally returning `Err(RLNCError::TooManyPieces)` if `data` holds more than
+    /// `max_pieces` many full coded pieces.
+    pub fn new_bounded(data: Vec<u8>, full_coded_piece_byte_len: usize, num_pieces_coded_together: usize, max_pieces: usize) -> Result<Recoder, RLNCError> {
+        let num_pieces_received = data.len().checked_div(full_coded_piece_byte_len).unwrap_or(0);
+        if num_pieces_received > max_pieces {
+            return Err(RLNCError::TooManyPieces);
+        }
+
+        Self::new(data, full_coded_piece_byte_len, num_pieces_coded_together)
+    }
+
+    /// Creates a new `Recoder` instance from a vector of received coded pieces, same as `Self::new` does, but each full
+    /// coded piece in `data` is prefixed by `generation_tag`, serialized as `GENERATION_TAG_BYTE_LEN` little-endian bytes,
+    /// as produced by an encoder created using `Encoder::new_tagged`. So each piece in `data` is
+    /// `GENERATION_TAG_BYTE_LEN + full_coded_piece_byte_len` bytes long, while `full_coded_piece_byte_len` excludes the tag.
+    ///
+    /// The recoder remembers `generation_tag`, refusing pieces of any other generation, added using `Self::add_piece`,
+    /// so that a relay never mixes coding vectors across generations.
+    ///
+    /// # Returns
+    /// Same as `Self::new`, while additionally returning `Err(RLNCError::GenerationMismatch)` if any piece in `data`
+    /// carries a generation tag other than `generation_tag`.
+    pub fn new_tagged(data: Vec<u8>, full_coded_piece_byte_len: usize, num_pieces_coded_together: usize, generation_tag: u32) -> Result<Recoder, RLNCError> {
+        if data.is_empty() {
+            return Err(RLNCError::NotEnoughPiecesToRecode);
+        }
+
+        let tagged_full_coded_piece_byte_len = full_coded_piece_byte_len.saturating_add(GENERATION_TAG_BYTE_LEN);
+        let num_pieces_received = data.len() / tagged_full_coded_piece_byte_len;
+
+        let mut recoder = Self::with_capacity_tagged(full_coded_piece_byte_len, num_pieces_coded_together, num_pieces_received, generation_tag)?;
+        if num_pieces_received == 0 {
+            return Err(RLNCError::NotEnoughPiecesToRecode);
+        }
+        if data.len() % tagged_full_coded_piece_byte_len != 0 {
+            return Err(concatenated_pieces_length_error(data.len(), tagged_full_coded_piece_byte_len));
+        }
+
+        data.chunks_exact(tagged_full_coded_piece_byte_len)
+            .try_for_each(|tagged_full_coded_piece| recoder.add_piece(tagged_full_coded_piece))?;
+
+        Ok(recoder)
+    }
+
+    /// Creates a new `Recoder` instance from a vector of received framed coded pieces, as produced by `Encoder::code_framed`,
+    /// reading the RLNC configuration off the frame header of the first one, instead of trusting it to be passed in. Each
+    /// frame is `frame::FRAME_HEADER_BYTE_LEN` bytes of frame header followed by the full coded piece, while every frame
+    /// must carry the same configuration, so that pieces coded with a different piece count are rejected, instead of
+    /// having their coding vectors misread.
+    ///
+    /// # Returns
+    /// Same as `Self::new`, while additionally returning `Err(RLNCError::InvalidFrameHeader)` if any frame header is
+    /// corrupted, and `Err(RLNCError::CodingVectorLengthMismatch)` or `Err(RLNCError::InvalidPieceLength)` if any frame
+    /// carries a configuration other than the first one, same as `Self::add_framed_piece` does.
+    pub fn new_framed(data: Vec<u8>) -> Result<Recoder, RLNCError> {
+        if data.is_empty() {
+            return Err(RLNCError::NotEnoughPiecesToRecode);
+        }
+
+        let (num_pieces_coded_together, piece_byte_len) = decode_header(&data)?;
+        let full_coded_piece_byte_len = num_pieces_coded_together.checked_add(piece_byte_len).ok_or(RLNCError::ParametersTooLarge)?;
+        let frame_byte_len = full_coded_piece_byte_len
+            .checked_add(FRAME_HEADER_BYTE_LEN)
+            .ok_or(RLNCError::ParametersTooLarge)?;
+        let num_pieces_received = data.len() / frame_byte_len;
+
+        let mut recoder = Self::with_capacity(full_coded_piece_byte_len, num_pieces_coded_together, num_pieces_received)?;
+        if num_pieces_received == 0 {
+            return Err(RLNCError::NotEnoughPiecesToRecode);
+        }
+        if data.len() % frame_byte_len != 0 {
+            return Err(concatenated_pieces_length_error(data.len(), frame_byte_len));
+        }
+
+        data.chunks_exact(frame_byte_len).try_for_each(|frame| recoder.add_framed_piece(frame))?;
+
+        Ok(recoder)
+    }
+
+    /// Creates a new `Recoder` instance, same as `Self::new` does, but keeping only a linearly independent basis of the
+    /// received coded pieces, using `Self::reduce`, so that `Self::get_num_pieces_recoded_together` reflects the true
+    /// dimension of the subspace spanned by them. Recoded pieces live in the same subspace, while each of them costs
+    /// less to produce, when the upstream link delivered linearly dependent pieces.
+    ///
+    /// # Returns
+    /// Same as `Self::new`.
+    pub fn new_reduced(data: Vec<u8>, full_coded_piece_byte_len: usize, num_pieces_coded_together: usize) -> Result<Recoder, RLNCError> {
+        let mut recoder = Self::new(data, full_coded_piece_byte_len, num_pieces_coded_together)?;
+        recoder.reduce();
+
+        Ok(recoder)
+    }
+
+    /// Creates a new `Recoder` instance, holding no coded pieces yet, so that pieces can be added one by one, using
+    /// `Self::add_piece`, as they arrive, e.g. at a relay node. Buffers are preallocated for `expected_pieces` many full
+    /// coded pieces, though more pieces can be added, at the cost of reallocation.
+    ///
+    /// Note, until the first piece is added, `Self::recode` produces a zero piece, which is useless to any decoder, while
+    /// `Self::try_recode` fails.
+    ///
+    /// # Returns
+    /// Returns `Ok(Recoder)` on successful creation.
+    /// Returns `Err(RLNCError::PieceLengthZero)` if `full_coded_piece_byte_len` is zero.
+    /// Returns `Err(RLNCError::PieceCountZero)` if `num_pieces_coded_together` is zero.
+    /// Returns `Err(RLNCError::PieceLengthTooShort)` if `full_coded_piece_byte_len` is not greater than `num_pieces_coded_together`.
+    /// Returns `Err(RLNCError::ParametersTooLarge)` if either `expected_pieces` or `num_pieces_coded_together` many full coded
+    /// pieces would be longer than `isize::MAX` bytes.
+    pub fn with_capacity(full_coded_piece_byte_len: usize, num_pieces_coded_together: usize, expected_pieces: usize) -> Result<Recoder, RLNCError> {
         if full_coded_piece_byte_len == 0 {
             return Err(RLNCError::PieceLengthZero);
         }
@@ -79,29 +293,165 @@ impl Recoder {
             return Err(RLNCError::PieceLengthTooShort);
         }
 
+        // Received pieces are kept as they are, while `Self::reduce` needs room for a full rank decoder matrix.
+        checked_buffer_byte_len(expected_pieces, full_coded_piece_byte_len)?;
+        checked_buffer_byte_len(num_pieces_coded_together, full_coded_piece_byte_len)?;
+
         let piece_byte_len = full_coded_piece_byte_len - num_pieces_coded_together;
-        let num_pieces_received = data.len() / full_coded_piece_byte_len;
 
-        let mut coding_vectors = Vec::with_capacity(num_pieces_received * num_pieces_coded_together);
-        let mut coded_pieces = Vec::with_capacity(num_pieces_received * piece_byte_len);
+        Ok(Recoder {
+            coding_vectors: Vec::with_capacity(expected_pieces * num_pieces_coded_together),
+            encoder: Encoder::with_piece_capacity(piece_byte_len, expected_pieces),
+            num_pieces_received: 0,
+            full_coded_piece_byte_len,
+            num_pieces_coded_together,
+            generation_tag: None,
+        })
+    }
+
+    /// Creates a new `Recoder` instance, holding no coded pieces yet, same as `Self::with_capacity` does, but expecting each
+    /// piece, added using `Self::add_piece`, to be prefixed by `generation_tag`. See `Self::new_tagged`.
+    ///
+    /// # Returns
+    /// Same as `Self::with_capacity`.
+    pub fn with_capacity_tagged(
+        full_coded_piece_byte_len: usize,
+        num_pieces_coded_together: usize,
+        expected_pieces: usize,
+        generation_tag: u32,
+    ) -> Result<Recoder, RLNCError> {
+        let mut recoder = Self::with_capacity(full_coded_piece_byte_len, num_pieces_coded_together, expected_pieces)?;
+        recoder.generation_tag = Some(generation_tag);
 
-        data.chunks_exact(full_coded_piece_byte_len).for_each(|full_coded_piece| {
-            let coding_vector = &full_coded_piece[..num_pieces_coded_together];
-            let coded_piece = &full_coded_piece[num_pieces_coded_together..];
+        Ok(recoder)
+    }
+
+    /// Adds one more received full coded piece i.e. coding vector ++ coded piece, to the pieces being recoded together.
+    /// If the recoder is tagged, the full coded piece must be prefixed by the recoder's generation tag, which is dropped
+    /// before the piece is stored.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success.
+    /// Returns `Err(RLNCError::InvalidPieceLength)` if `full_coded_piece` is not `self.get_full_coded_piece_byte_len()` bytes
+    /// long, plus `GENERATION_TAG_BYTE_LEN` bytes, if the recoder is tagged.
+    /// Returns `Err(RLNCError::GenerationMismatch)` if the `full_coded_piece` carries a generation tag other than the recoder's.
+    pub fn add_piece(&mut self, full_coded_piece: &[u8]) -> Result<(), RLNCError> {
+        if full_coded_piece.len() != self.get_generation_tag_byte_len() + self.full_coded_piece_byte_len {
+            return Err(RLNCError::InvalidPieceLength {
+                expected: self.get_generation_tag_byte_len() + self.full_coded_piece_byte_len,
+                got: full_coded_piece.len(),
+            });
+        }
+
+        let full_coded_piece = match self.generation_tag {
+            Some(generation_tag) => {
+                let (received_generation_tag, rest) = full_coded_piece.split_at(GENERATION_TAG_BYTE_LEN);
+                if received_generation_tag != generation_tag.to_le_bytes() {
+                    return Err(RLNCError::GenerationMismatch);
+                }
+
+                rest
+            }
+            None => full_coded_piece,
+        };
+
+        let (coding_vector, coded_piece) = full_coded_piece.split_at(self.num_pieces_coded_together);
+
+        self.coding_vectors.extend_from_slice(coding_vector);
+        self.encoder.push_piece(coded_piece);
+        self.num_pieces_received += 1;
+
+        Ok(())
+    }
+
+    /// Adds one more received framed coded piece, as produced by `Encoder::code_framed`, i.e. frame header ++ full coded
+    /// piece, same as `Self::add_piece` does, but checking the RLNC configuration, carried by the frame header, against
+    /// the recoder's, before touching the received pieces.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success.
+    /// Returns `Err(RLNCError::InvalidFrameHeader)` if the frame header is corrupted, see `frame::decode_header`.
+    /// Returns `Err(RLNCError::CodingVectorLengthMismatch)` if the frame header carries a piece count other than the recoder's.
+    /// Returns `Err(RLNCError::InvalidPieceLength)`, carrying the full coded piece byte lengths, if the frame header carries
+    /// a piece byte length other than the recoder's, or if the full coded piece is not of expected length.
+    /// Returns `Err(RLNCError::GenerationMismatch)` if the full coded piece carries a generation tag other than the recoder's.
+    pub fn add_framed_piece(&mut self, frame: &[u8]) -> Result<(), RLNCError> {
+        let (num_pieces_coded_together, piece_byte_len) = decode_header(frame)?;
+        if num_pieces_coded_together != self.num_pieces_coded_together {
+            return Err(RLNCError::CodingVectorLengthMismatch {
+                expected: self.num_pieces_coded_together,
+                got: num_pieces_coded_together,
+            });
+        }
+        if piece_byte_len != self.get_piece_byte_len() {
+            return Err(RLNCError::InvalidPieceLength {
+                expected: self.get_generation_tag_byte_len() + self.full_coded_piece_byte_len,
+                got: self.get_generation_tag_byte_len() + num_pieces_coded_together.saturating_add(piece_byte_len),
+            });
+        }
 
-            coding_vectors.extend(coding_vector.iter().map(|&symbol| Gf256::new(symbol)));
-            coded_pieces.extend_from_slice(coded_piece);
+        self.add_piece(&frame[FRAME_HEADER_BYTE_LEN..])
+    }
+
+    /// Replaces the received coded pieces with a linearly independent basis of them, i.e. the rows of their reduced row
+    /// echelon form, obtained by Gaussian elimination, dropping linearly dependent pieces, including all-zero ones.
+    /// Pieces added later, using `Self::add_piece`, are kept as they are, until this function is called again.
+    ///
+    /// # Returns
+    /// Returns the number of dropped, linearly dependent, pieces.
+    pub fn reduce(&mut self) -> usize {
+        let piece_byte_len = self.get_piece_byte_len();
+
+        let mut matrix: DecoderMatrix = unsafe { DecoderMatrix::new(self.num_pieces_coded_together, piece_byte_len).unwrap_unchecked() };
+        let mut full_coded_piece = Vec::with_capacity(self.full_coded_piece_byte_len);
+
+        for (coding_vector, coded_piece) in self.coding_vectors.chunks_exact(self.num_pieces_coded_together).zip(self.encoder.pieces()) {
+            if matrix.rank() == self.num_pieces_coded_together {
+                break;
+            }
+
+            full_coded_piece.clear();
+            full_coded_piece.extend_from_slice(coding_vector);
+            full_coded_piece.extend_from_slice(coded_piece);
+
+            unsafe { matrix.add_row_and_reduce(&full_coded_piece).unwrap_unchecked() };
+        }
+
+        let num_dropped_pieces = self.num_pieces_received - matrix.rank();
+
+        self.coding_vectors.clear();
+        self.encoder = Encoder::with_piece_capacity(piece_byte_len, matrix.rank());
+        self.num_pieces_received = 0;
+
+        (0..matrix.rank()).for_each(|row_idx| {
+            self.coding_vectors.extend_from_slice(matrix.coefficients(row_idx));
+            self.encoder.push_piece(matrix.payload(row_idx));
+            self.num_pieces_received += 1;
         });
 
-        let encoder = unsafe { Encoder::without_padding(coded_pieces, num_pieces_received).unwrap_unchecked() };
+        num_dropped_pieces
+    }
 
-        Ok(Recoder {
-            coding_vectors,
-            encoder,
-            num_pieces_received,
-            full_coded_piece_byte_len,
-            num_pieces_coded_together,
-        })
+    /// Dimension of the subspace spanned by the received coded pieces, i.e. rank of the matrix of their coding vectors,
+    /// which is the number of useful pieces a downstream decoder can get out of this recoder, at most, as every recoded
+    /// piece lives in that subspace. Once a consumer has received these many useful pieces from it, pulling more from this
+    /// recoder is a wasted transmission.
+    ///
+    /// It's computed, on each call, by Gaussian elimination over a copy of the coding vectors, leaving the recoder as is.
+    /// After `Self::reduce`, or for a recoder created using `Self::new_reduced`, it equals
+    /// `self.get_num_pieces_recoded_together()`, until more pieces are added.
+    pub fn subspace_dimension(&self) -> usize {
+        let mut matrix: DecoderMatrix = unsafe { DecoderMatrix::new(self.num_pieces_coded_together, 0).unwrap_unchecked() };
+
+        for coding_vector in self.coding_vectors.chunks_exact(self.num_pieces_coded_together) {
+            if matrix.rank() == self.num_pieces_coded_together {
+                break;
+            }
+
+            unsafe { matrix.add_row_and_reduce(coding_vector).unwrap_unchecked() };
+        }
+
+        matrix.rank()
     }
 
     /// Generates a new coded piece by recoding the source pieces using a randomly sampled coding vector.
@@ -121,44 +471,150 @@ impl Recoder {
     ///
     /// # Returns
     ///
-    /// Returns a `Vec<u8>` representing the new coded piece prepended with its
-    /// source coding vector. The length of the returned vector is
+    /// Returns a `CodedPiece` representing the new coded piece prepended with its
+    /// source coding vector. The length of the returned piece is
     /// `self.get_full_coded_piece_byte_len()`.
-    pub fn recode<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<u8> {
-        let random_recoding_vector = (0..self.num_pieces_received).map(|_| rng.random()).collect::<Vec<u8>>();
+    ///
+    /// Calls `recode_into` internally, with a freshly allocated buffer.
+    pub fn recode<R: Rng + ?Sized>(&self, rng: &mut R) -> CodedPiece {
+        let mut full_recoded_piece = vec![0u8; self.full_coded_piece_byte_len];
+        unsafe { self.recode_into(rng, &mut full_recoded_piece).unwrap_unchecked() };
 
-        // Compute the resulting coding vector for the original source pieces
-        // by multiplying the random sampled recoding vector by the matrix of received coding vectors.
-        let computed_coding_vector = (0..self.num_pieces_coded_together)
-            .map(|coeff_idx| {
-                random_recoding_vector
-                    .iter()
-                    .enumerate()
-                    .fold(Gf256::default(), |acc, (recoding_vec_idx, &cur)| {
-                        let row_begins_at = recoding_vec_idx * self.num_pieces_coded_together;
-                        acc + Gf256::new(cur) * self.coding_vectors[row_begins_at + coeff_idx]
-                    })
-                    .get()
-            })
-            .collect::<Vec<u8>>();
+        CodedPiece::new_unchecked(full_recoded_piece, self.num_pieces_coded_together, self.get_piece_byte_len(), false)
+    }
 
-        let full_coded_piece = unsafe { self.encoder.code_with_coding_vector(&random_recoding_vector).unwrap_unchecked() };
-        let coded_piece = &full_coded_piece[self.num_pieces_received..];
+    /// Same as `Self::recode`, but also returning a copy of the source coding vector of the recoded piece, e.g. for
+    /// logging it, when auditing recoding, or measuring how the density of coding vectors drifts across relay hops.
+    ///
+    /// The coding vector is over the original basis, i.e. its `self.get_num_pieces_coded_together()` symbols are the
+    /// coefficients of the source pieces, the encoder split the original data into, not of the pieces received by this
+    /// recoder, so that the recoded piece is `Encoder::code_with_coding_vector` of it. It's the same as the coding vector
+    /// prefixing the recoded piece, i.e. `CodedPiece::coding_vector`.
+    ///
+    /// # Returns
+    /// A tuple of the full recoded piece and its `self.get_num_pieces_coded_together()` -symbols source coding vector.
+    pub fn recode_with_vector<R: Rng + ?Sized>(&self, rng: &mut R) -> (CodedPiece, Vec<u8>) {
+        let full_recoded_piece = self.recode(rng);
+        let coding_vector = full_recoded_piece.coding_vector().to_vec();
 
-        let mut full_recoded_piece = vec![0u8; self.full_coded_piece_byte_len];
+        (full_recoded_piece, coding_vector)
+    }
 
-        full_recoded_piece[..self.num_pieces_coded_together].copy_from_slice(&computed_coding_vector);
-        full_recoded_piece[self.num_pieces_coded_together..].copy_from_slice(coded_piece);
+    /// Same as `Self::recode`, but taking the random number generator as a trait object, so that callers don't need to
+    /// be generic over the RNG type. See `Encoder::code_dyn`.
+    pub fn recode_dyn(&self, rng: &mut dyn RngCore) -> CodedPiece {
+        self.recode(rng)
+    }
 
-        full_recoded_piece
+    /// Same as `Self::recode`, but refusing to recode when every received coding vector is all-zero, i.e. when the
+    /// received pieces span nothing, including when no piece is received yet. Each piece recoded from such pieces is
+    /// all-zero too, which is useless to any decoder, so that a relay can avoid forwarding it.
+    ///
+    /// # Returns
+    /// Returns `Ok(CodedPiece)`, same as `Self::recode`, on success.
+    /// Returns `Err(RLNCError::NotEnoughPiecesToRecode)` if the received coding vectors are of rank zero.
+    pub fn try_recode<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<CodedPiece, RLNCError> {
+        if self.coding_vectors.iter().all(|&symbol| symbol == 0) {
+            return Err(RLNCError::NotEnoughPiecesToRecode);
+        }
+
+        Ok(self.recode(rng))
+    }
+
+    /// Same as `Self::recode`, but the returned full recoded piece is prefixed by the recoder's generation tag, serialized as
+    /// `GENERATION_TAG_BYTE_LEN` little-endian bytes, if the recoder is tagged, so that it can be forwarded to downstream
+    /// peers of the same generation. Such a piece can be parsed using `CodedPiece::from_tagged_bytes`, and decoded by a
+    /// decoder created using `Decoder::new_tagged`, or recoded again by a recoder created using `Self::new_tagged`.
+    ///
+    /// For an untagged recoder, it returns the same bytes as `Self::recode`.
+    pub fn recode_tagged<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<u8> {
+        let generation_tag_byte_len = self.get_generation_tag_byte_len();
+
+        let mut tagged_full_recoded_piece = vec![0u8; generation_tag_byte_len + self.full_coded_piece_byte_len];
+        let (generation_tag, full_recoded_piece) = tagged_full_recoded_piece.split_at_mut(generation_tag_byte_len);
+
+        if let Some(tag) = self.generation_tag {
+            generation_tag.copy_from_slice(&tag.to_le_bytes());
+        }
+        unsafe { self.recode_into(rng, full_recoded_piece).unwrap_unchecked() };
+
+        tagged_full_recoded_piece
+    }
+
+    /// Generates a new coded piece by recoding the source pieces using a randomly sampled coding vector, writing the
+    /// full recoded piece into the caller-provided `out` buffer, instead of allocating a new one. This makes it possible
+    /// to reuse a single buffer, across many calls, on a relay node producing lots of recoded pieces.
+    ///
+    /// Unless the `parallel` feature is enabled, no memory is allocated at all, as each coefficient of the random recoding
+    /// vector is sampled right before the corresponding received piece is accumulated into `out`. Previous content of `out`
+    /// is overwritten.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success, in which case `out` holds the recoded piece prefixed by its source coding vector.
+    /// Returns `Err(RLNCError::InvalidPieceLength)` if `out.len()` is not `self.get_full_coded_piece_byte_len()`.
+    pub fn recode_into<R: Rng + ?Sized>(&self, rng: &mut R, out: &mut [u8]) -> Result<(), RLNCError> {
+        if out.len() != self.full_coded_piece_byte_len {
+            return Err(RLNCError::InvalidPieceLength {
+                expected: self.full_coded_piece_byte_len,
+                got: out.len(),
+            });
+        }
+
+        let (coding_vector, recoded_piece) = out.split_at_mut(self.num_pieces_coded_together);
+        self.recode_data_into(rng, coding_vector, recoded_piece);
+
+        Ok(())
+    }
+
+    /// Linearly combines the received pieces, using a random sampled recoding vector, writing the resulting source coding
+    /// vector into `coding_vector` and the recoded data into `recoded_piece`.
+    ///
+    /// Random recoding vector is sampled on the fly, without materializing it, and resampled in the unlikely case of
+    /// being all-zero, just like `sample_coding_vector_into` does.
+    #[cfg(not(feature = "parallel"))]
+    fn recode_data_into<R: Rng + ?Sized>(&self, rng: &mut R, coding_vector: &mut [u8], recoded_piece: &mut [u8]) {
+        loop {
+            coding_vector.fill(0);
+            recoded_piece.fill(0);
+
+            let mut is_zero_recoding_vector = true;
+
+            self.coding_vectors
+                .chunks_exact(self.num_pieces_coded_together)
+                .zip(self.encoder.pieces())
+                .for_each(|(received_coding_vector, received_piece)| {
+                    let random_symbol = rng.random();
+                    is_zero_recoding_vector &= random_symbol == 0;
+
+                    gf256_mul_vec_by_scalar_then_add_into_vec(coding_vector, received_coding_vector, random_symbol);
+                    gf256_mul_vec_by_scalar_then_add_into_vec(recoded_piece, received_piece, random_symbol);
+                });
+
+            if !is_zero_recoding_vector || self.num_pieces_received == 0 {
+                break;
+            }
+        }
+    }
+
+    /// Linearly combines the received pieces, using a random sampled recoding vector, writing the resulting source coding
+    /// vector into `coding_vector` and the recoded data into `recoded_piece`.
+    #[cfg(feature = "parallel")]
+    fn recode_data_into<R: Rng + ?Sized>(&self, rng: &mut R, coding_vector: &mut [u8], recoded_piece: &mut [u8]) {
+        let mut random_recoding_vector = vec![0u8; self.num_pieces_received];
+        sample_coding_vector_into(rng, &mut random_recoding_vector);
+
+        // Compute the resulting coding vector for the original source pieces
+        // by multiplying the random sampled recoding vector by the matrix of received coding vectors.
+        gf256_vec_mat_mul(&random_recoding_vector, &self.coding_vectors, coding_vector);
+        self.encoder.code_data_into(&random_recoding_vector, recoded_piece);
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{RLNCError, Recoder};
-    use crate::full::encoder::Encoder;
-    use rand::Rng;
+    use super::{GENERATION_TAG_BYTE_LEN, RLNCError, Recoder};
+    use crate::full::{coded_piece::CodedPiece, decoder::Decoder, encoder::Encoder};
+    use rand::{Rng, RngCore, SeedableRng, rngs::StdRng};
 
     #[test]
     fn test_recoder_new_invalid_inputs() {
@@ -222,7 +678,29 @@ mod tests {
             RLNCError::PieceLengthTooShort
         );
 
-        // Test case 5: Valid input (using existing encoder setup to generate valid data)
+        // Test case 5: Trailing partial piece, e.g. truncated on the wire
+        let mut data_with_trailing_byte: Vec<u8> = (0..3).flat_map(|_| encoder.code(&mut rng)).collect();
+        data_with_trailing_byte.push(rng.random());
+        assert_eq!(data_with_trailing_byte.len(), full_coded_piece_byte_len * 3 + 1);
+
+        let result_trailing_byte = Recoder::new(data_with_trailing_byte.clone(), full_coded_piece_byte_len, num_pieces_coded_together);
+        assert_eq!(
+            result_trailing_byte.expect_err("Expected InvalidPieceLength error for trailing partial piece"),
+            RLNCError::InvalidPieceLength {
+                expected: full_coded_piece_byte_len * 3,
+                got: full_coded_piece_byte_len * 3 + 1
+            }
+        );
+        let result_trailing_byte_reduced = Recoder::new_reduced(data_with_trailing_byte, full_coded_piece_byte_len, num_pieces_coded_together);
+        assert_eq!(
+            result_trailing_byte_reduced.expect_err("Expected InvalidPieceLength error for trailing partial piece"),
+            RLNCError::InvalidPieceLength {
+                expected: full_coded_piece_byte_len * 3,
+                got: full_coded_piece_byte_len * 3 + 1
+            }
+        );
+
+        // Test case 6: Valid input (using existing encoder setup to generate valid data)
         let num_pieces_to_recode_with = 5;
         let coded_pieces_for_recoder: Vec<u8> = (0..num_pieces_to_recode_with).flat_map(|_| encoder.code(&mut rng)).collect();
 
@@ -260,4 +738,570 @@ mod tests {
         assert_eq!(recoder.get_piece_byte_len(), original_piece_byte_len);
         assert_eq!(recoder.get_full_coded_piece_byte_len(), full_coded_piece_byte_len);
     }
+
+    #[test]
+    fn test_recoder_subspace_dimension() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+        let encoder = Encoder::new((0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>(), piece_count)
+            .expect("Failed to create Encoder for recoder subspace dimension test");
+
+        let num_independent_pieces = 10;
+        let independent_pieces = (0..num_independent_pieces).flat_map(|_| encoder.code(&mut rng)).collect::<Vec<u8>>();
+        let upstream = Recoder::new(independent_pieces.clone(), encoder.get_full_coded_piece_byte_len(), piece_count).expect("Recoder creation failed");
+
+        // Test case 1: Linearly dependent pieces don't grow the subspace
+        let mut recoder = Recoder::new(independent_pieces, encoder.get_full_coded_piece_byte_len(), piece_count).expect("Recoder creation failed");
+        assert_eq!(recoder.subspace_dimension(), num_independent_pieces);
+
+        (0..num_independent_pieces).for_each(|_| {
+            recoder
+                .add_piece(upstream.recode(&mut rng).as_bytes())
+                .expect("Adding valid piece must not fail");
+        });
+        assert_eq!(recoder.get_num_pieces_recoded_together(), 2 * num_independent_pieces);
+        assert_eq!(recoder.subspace_dimension(), num_independent_pieces);
+
+        recoder.reduce();
+        assert_eq!(recoder.get_num_pieces_recoded_together(), num_independent_pieces);
+        assert_eq!(recoder.subspace_dimension(), num_independent_pieces);
+
+        // Test case 2: A downstream decoder gets exactly these many useful pieces out of the recoder
+        let mut decoder = Decoder::new(encoder.get_piece_byte_len(), piece_count).expect("Failed to create Decoder");
+        (0..(4 * num_independent_pieces)).for_each(|_| {
+            let _ = decoder.decode(&recoder.recode(&mut rng));
+        });
+        assert_eq!(decoder.rank(), recoder.subspace_dimension());
+
+        // Test case 3: Subspace dimension never exceeds the number of pieces coded together
+        let full_recoder = Recoder::new(
+            (0..(2 * piece_count)).flat_map(|_| encoder.code(&mut rng)).collect(),
+            encoder.get_full_coded_piece_byte_len(),
+            piece_count,
+        )
+        .expect("Recoder creation failed");
+        assert_eq!(full_recoder.subspace_dimension(), piece_count);
+    }
+
+    #[test]
+    fn test_recoder_new_framed() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for recoder new framed test");
+        let frames = (0..piece_count)
+            .flat_map(|_| encoder.code_framed(&mut rng).expect("Framing must not fail"))
+            .collect::<Vec<u8>>();
+
+        // Test case 1: Configuration is read off the frame headers
+        let mut recoder = Recoder::new_framed(frames.clone()).expect("Recoder creation failed");
+        assert_eq!(recoder.get_original_num_pieces_coded_together(), piece_count);
+        assert_eq!(recoder.get_num_pieces_recoded_together(), piece_count);
+        assert_eq!(recoder.get_piece_byte_len(), encoder.get_piece_byte_len());
+
+        let mut decoder = Decoder::new(recoder.get_piece_byte_len(), piece_count).expect("Failed to create Decoder");
+        while !decoder.is_already_decoded() {
+            match decoder.decode(&recoder.recode(&mut rng)) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+        }
+        assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+
+        // Test case 2: Pieces coded with half the piece count, but of the same full coded piece byte length, are rejected
+        let mismatching_piece_byte_len = encoder.get_full_coded_piece_byte_len() - piece_count / 2;
+        let mismatching_data = data[..((piece_count / 2) * mismatching_piece_byte_len - 1)].to_vec();
+        let mismatching_encoder = Encoder::new(mismatching_data, piece_count / 2).expect("Failed to create Encoder for recoder new framed test");
+        assert_eq!(mismatching_encoder.get_full_coded_piece_byte_len(), encoder.get_full_coded_piece_byte_len());
+
+        let mismatching_frame = mismatching_encoder.code_framed(&mut rng).expect("Framing must not fail");
+        let mismatching_piece_count_error = RLNCError::CodingVectorLengthMismatch {
+            expected: piece_count,
+            got: piece_count / 2,
+        };
+        assert_eq!(recoder.add_framed_piece(&mismatching_frame), Err(mismatching_piece_count_error.clone()));
+
+        let mut mixed_frames = frames.clone();
+        mixed_frames.extend_from_slice(&mismatching_frame);
+        assert_eq!(Recoder::new_framed(mixed_frames).map(|_| ()), Err(mismatching_piece_count_error));
+
+        // Test case 3: Corrupted or truncated frames are rejected
+        let mut corrupted_frames = frames.clone();
+        corrupted_frames[0] ^= 1;
+        assert_eq!(Recoder::new_framed(corrupted_frames).map(|_| ()), Err(RLNCError::InvalidFrameHeader));
+        assert_eq!(
+            Recoder::new_framed(frames[..(frames.len() - 1)].to_vec()).map(|_| ()),
+            Err(RLNCError::InvalidPieceLength {
+                expected: frames.len(),
+                got: frames.len() - 1
+            })
+        );
+        assert_eq!(Recoder::new_framed(Vec::new()).map(|_| ()), Err(RLNCError::NotEnoughPiecesToRecode));
+
+        assert_eq!(recoder.get_num_pieces_recoded_together(), piece_count);
+    }
+
+    #[test]
+    fn test_recoder_add_piece() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+        let encoder = Encoder::new((0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>(), piece_count)
+            .expect("Failed to create Encoder for recoder add piece test");
+        let full_coded_piece_byte_len = encoder.get_full_coded_piece_byte_len();
+
+        let num_pieces_to_recode_with = 10;
+        let coded_pieces = (0..num_pieces_to_recode_with).map(|_| encoder.code(&mut rng)).collect::<Vec<_>>();
+
+        assert_eq!(
+            Recoder::with_capacity(0, piece_count, 1).expect_err("Expected PieceLengthZero error"),
+            RLNCError::PieceLengthZero
+        );
+        assert_eq!(
+            Recoder::with_capacity(full_coded_piece_byte_len, 0, 1).expect_err("Expected PieceCountZero error"),
+            RLNCError::PieceCountZero
+        );
+        assert_eq!(
+            Recoder::with_capacity(full_coded_piece_byte_len, piece_count, usize::MAX / 2).expect_err("Expected ParametersTooLarge error"),
+            RLNCError::ParametersTooLarge
+        );
+        assert_eq!(
+            Recoder::with_capacity(usize::MAX / 2, usize::MAX / 4, 1).expect_err("Expected ParametersTooLarge error"),
+            RLNCError::ParametersTooLarge
+        );
+        assert_eq!(
+            Recoder::with_capacity(piece_count, piece_count, 1).expect_err("Expected PieceLengthTooShort error"),
+            RLNCError::PieceLengthTooShort
+        );
+
+        let mut incremental_recoder =
+            Recoder::with_capacity(full_coded_piece_byte_len, piece_count, num_pieces_to_recode_with / 2).expect("Recoder creation failed");
+        assert_eq!(incremental_recoder.get_num_pieces_recoded_together(), 0);
+
+        coded_pieces.iter().enumerate().for_each(|(piece_idx, coded_piece)| {
+            incremental_recoder.add_piece(coded_piece.as_bytes()).expect("Adding valid piece must not fail");
+            assert_eq!(incremental_recoder.get_num_pieces_recoded_together(), piece_idx + 1);
+        });
+
+        assert_eq!(
+            incremental_recoder
+                .add_piece(&coded_pieces[0].as_bytes()[1..])
+                .expect_err("Expected InvalidPieceLength error"),
+            RLNCError::InvalidPieceLength {
+                expected: full_coded_piece_byte_len,
+                got: full_coded_piece_byte_len - 1
+            }
+        );
+        assert_eq!(incremental_recoder.get_num_pieces_recoded_together(), num_pieces_to_recode_with);
+
+        // Recoder built incrementally must recode exactly like the one built from all pieces at once.
+        let recoder = Recoder::new(
+            coded_pieces.iter().flat_map(|coded_piece| coded_piece.as_bytes()).copied().collect(),
+            full_coded_piece_byte_len,
+            piece_count,
+        )
+        .expect("Recoder creation failed");
+
+        assert_eq!(incremental_recoder.get_piece_byte_len(), recoder.get_piece_byte_len());
+        assert_eq!(incremental_recoder.get_full_coded_piece_byte_len(), recoder.get_full_coded_piece_byte_len());
+
+        let seed = rng.random::<u64>();
+        let mut rng_a = StdRng::seed_from_u64(seed);
+        let mut rng_b = StdRng::seed_from_u64(seed);
+
+        (0..num_pieces_to_recode_with).for_each(|_| {
+            assert_eq!(incremental_recoder.recode(&mut rng_a), recoder.recode(&mut rng_b));
+        });
+    }
+
+    #[test]
+    fn test_recoder_try_recode() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+        let encoder = Encoder::new((0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>(), piece_count)
+            .expect("Failed to create Encoder for recoder try recode test");
+        let full_coded_piece_byte_len = encoder.get_full_coded_piece_byte_len();
+
+        // Test case 1: No piece received yet
+        let mut recoder = Recoder::with_capacity(full_coded_piece_byte_len, piece_count, 4).expect("Recoder creation failed");
+        assert_eq!(recoder.try_recode(&mut rng), Err(RLNCError::NotEnoughPiecesToRecode));
+
+        // Test case 2: Only pieces with all-zero coding vectors received, so recoded pieces would be all-zero too
+        (0..3).for_each(|_| {
+            let mut zero_coded_piece = (0..full_coded_piece_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+            zero_coded_piece[..piece_count].fill(0);
+
+            recoder.add_piece(&zero_coded_piece).expect("Adding valid piece must not fail");
+        });
+        assert_eq!(recoder.try_recode(&mut rng), Err(RLNCError::NotEnoughPiecesToRecode));
+        assert!(recoder.recode(&mut rng).coding_vector().iter().all(|&symbol| symbol == 0));
+
+        // Test case 3: A useful piece received, so recoding succeeds, same as infallible recoding
+        recoder.add_piece(encoder.code(&mut rng).as_bytes()).expect("Adding valid piece must not fail");
+
+        let seed = rng.random::<u64>();
+        let mut rng_a = StdRng::seed_from_u64(seed);
+        let mut rng_b = StdRng::seed_from_u64(seed);
+
+        let recoded_piece = recoder.try_recode(&mut rng_a).expect("Recoding must not fail");
+        assert!(recoded_piece.coding_vector().iter().any(|&symbol| symbol != 0));
+        assert_eq!(recoded_piece, recoder.recode(&mut rng_b));
+    }
+
+    #[test]
+    fn test_recoder_from_owned() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+        let encoder =
+            Encoder::new((0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>(), piece_count).expect("Failed to create Encoder for owned recoder test");
+        let full_coded_piece_byte_len = encoder.get_full_coded_piece_byte_len();
+
+        let num_pieces_to_recode_with = 8;
+        let coded_pieces = encoder.coded_pieces(&mut rng).take(num_pieces_to_recode_with).flatten().collect::<Vec<u8>>();
+
+        // Test case 1: Recodes exactly the same way as a recoder copying the received pieces
+        let copying_recoder = Recoder::new(coded_pieces.clone(), full_coded_piece_byte_len, piece_count).expect("Recoder creation failed");
+        let mut recoder = Recoder::from_owned(coded_pieces.clone(), full_coded_piece_byte_len, piece_count).expect("Owned Recoder creation failed");
+        assert_eq!(recoder.get_num_pieces_recoded_together(), num_pieces_to_recode_with);
+
+        let seed = rng.random::<u64>();
+        let mut rng_a = StdRng::seed_from_u64(seed);
+        let mut rng_b = StdRng::seed_from_u64(seed);
+        (0..4).for_each(|_| assert_eq!(recoder.recode(&mut rng_a), copying_recoder.recode(&mut rng_b)));
+
+        // Test case 2: More pieces can still be added later
+        recoder.add_piece(encoder.code(&mut rng).as_bytes()).expect("Adding valid piece must not fail");
+        assert_eq!(recoder.get_num_pieces_recoded_together(), num_pieces_to_recode_with + 1);
+
+        // Test case 3: Invalid inputs are refused same as copying recoder
+        assert_eq!(
+            Recoder::from_owned(Vec::new(), full_coded_piece_byte_len, piece_count).expect_err("Expected NotEnoughPiecesToRecode error"),
+            RLNCError::NotEnoughPiecesToRecode
+        );
+        assert_eq!(
+            Recoder::from_owned(coded_pieces[1..].to_vec(), full_coded_piece_byte_len, piece_count).expect_err("Expected InvalidPieceLength error"),
+            RLNCError::InvalidPieceLength {
+                expected: coded_pieces.len(),
+                got: coded_pieces.len() - 1
+            }
+        );
+        assert_eq!(
+            Recoder::from_owned(coded_pieces, piece_count, piece_count).expect_err("Expected PieceLengthTooShort error"),
+            RLNCError::PieceLengthTooShort
+        );
+    }
+
+    #[test]
+    fn test_recoder_new_bounded() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+        let encoder = Encoder::new((0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>(), piece_count)
+            .expect("Failed to create Encoder for bounded recoder test");
+        let full_coded_piece_byte_len = encoder.get_full_coded_piece_byte_len();
+
+        let num_pieces_to_recode_with = 8;
+        let coded_pieces = encoder.coded_pieces(&mut rng).take(num_pieces_to_recode_with).flatten().collect::<Vec<u8>>();
+
+        // Test case 1: More pieces than allowed
+        assert_eq!(
+            Recoder::new_bounded(coded_pieces.clone(), full_coded_piece_byte_len, piece_count, num_pieces_to_recode_with - 1)
+                .expect_err("Expected TooManyPieces error"),
+            RLNCError::TooManyPieces
+        );
+
+        // Test case 2: Exactly as many pieces as allowed, same as unbounded recoder
+        let recoder =
+            Recoder::new_bounded(coded_pieces, full_coded_piece_byte_len, piece_count, num_pieces_to_recode_with).expect("Bounded Recoder creation failed");
+        assert_eq!(recoder.get_num_pieces_recoded_together(), num_pieces_to_recode_with);
+
+        // Test case 3: Other invalid inputs are refused same as unbounded recoder
+        assert_eq!(
+            Recoder::new_bounded(Vec::new(), full_coded_piece_byte_len, piece_count, num_pieces_to_recode_with)
+                .expect_err("Expected NotEnoughPiecesToRecode error"),
+            RLNCError::NotEnoughPiecesToRecode
+        );
+    }
+
+    #[test]
+    fn test_recoder_tagged() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let generation_tag = rng.random::<u32>();
+        let other_generation_tag = generation_tag.wrapping_add(1);
+
+        let encoder = Encoder::new_tagged(data.clone(), piece_count, generation_tag).expect("Failed to create tagged Encoder");
+        let other_encoder = Encoder::new_tagged(data.clone(), piece_count, other_generation_tag).expect("Failed to create tagged Encoder");
+        let full_coded_piece_byte_len = piece_count + encoder.get_piece_byte_len();
+
+        // Test case 1: Pieces of another generation are refused, both in bulk and one by one
+        let mixed_pieces = [encoder.code(&mut rng), other_encoder.code(&mut rng)]
+            .iter()
+            .flat_map(|coded_piece| coded_piece.as_bytes().to_vec())
+            .collect::<Vec<u8>>();
+        assert_eq!(
+            Recoder::new_tagged(mixed_pieces, full_coded_piece_byte_len, piece_count, generation_tag).expect_err("Expected GenerationMismatch error"),
+            RLNCError::GenerationMismatch
+        );
+
+        let mut truncated_pieces = encoder.coded_pieces(&mut rng).take(2).flatten().collect::<Vec<u8>>();
+        truncated_pieces.pop();
+        assert_eq!(
+            Recoder::new_tagged(truncated_pieces, full_coded_piece_byte_len, piece_count, generation_tag).expect_err("Expected InvalidPieceLength error"),
+            RLNCError::InvalidPieceLength {
+                expected: 2 * (GENERATION_TAG_BYTE_LEN + full_coded_piece_byte_len),
+                got: 2 * (GENERATION_TAG_BYTE_LEN + full_coded_piece_byte_len) - 1
+            }
+        );
+
+        let num_pieces_to_recode_with = 8;
+        let mut recoder = Recoder::new_tagged(
+            encoder.coded_pieces(&mut rng).take(num_pieces_to_recode_with).flatten().collect(),
+            full_coded_piece_byte_len,
+            piece_count,
+            generation_tag,
+        )
+        .expect("Tagged Recoder creation failed");
+        assert_eq!(recoder.get_generation_tag(), Some(generation_tag));
+        assert_eq!(recoder.get_full_coded_piece_byte_len(), full_coded_piece_byte_len);
+        assert_eq!(recoder.get_num_pieces_recoded_together(), num_pieces_to_recode_with);
+
+        assert_eq!(
+            recoder
+                .add_piece(other_encoder.code(&mut rng).as_bytes())
+                .expect_err("Expected GenerationMismatch error"),
+            RLNCError::GenerationMismatch
+        );
+        assert_eq!(
+            recoder
+                .add_piece(encoder.code(&mut rng).untagged_bytes())
+                .expect_err("Expected InvalidPieceLength error"),
+            RLNCError::InvalidPieceLength {
+                expected: GENERATION_TAG_BYTE_LEN + full_coded_piece_byte_len,
+                got: full_coded_piece_byte_len
+            }
+        );
+        assert_eq!(recoder.get_num_pieces_recoded_together(), num_pieces_to_recode_with);
+
+        recoder
+            .add_piece(encoder.code(&mut rng).as_bytes())
+            .expect("Adding piece of same generation must not fail");
+        assert_eq!(recoder.get_num_pieces_recoded_together(), num_pieces_to_recode_with + 1);
+
+        // Test case 2: Recoded pieces carry the generation tag, while recoding the same way as untagged recoding
+        let seed = rng.random::<u64>();
+        let mut rng_a = StdRng::seed_from_u64(seed);
+        let mut rng_b = StdRng::seed_from_u64(seed);
+
+        let tagged_recoded_piece = recoder.recode_tagged(&mut rng_a);
+        assert_eq!(tagged_recoded_piece[..GENERATION_TAG_BYTE_LEN], generation_tag.to_le_bytes());
+        assert_eq!(tagged_recoded_piece[GENERATION_TAG_BYTE_LEN..], *recoder.recode(&mut rng_b).as_bytes());
+
+        // Test case 3: Tagged recoded pieces are decodable by a decoder expecting the same generation
+        let mut decoder = Decoder::new_tagged(encoder.get_piece_byte_len(), piece_count, generation_tag).expect("Failed to create tagged Decoder");
+        while !decoder.is_already_decoded() {
+            let full_coded_piece = if decoder.get_useful_piece_count() < recoder.get_num_pieces_recoded_together() {
+                recoder.recode_tagged(&mut rng)
+            } else {
+                encoder.code(&mut rng).as_bytes().to_vec()
+            };
+            let coded_piece =
+                CodedPiece::from_tagged_bytes(full_coded_piece, piece_count, encoder.get_piece_byte_len()).expect("Failed to parse tagged coded piece");
+            assert_eq!(coded_piece.generation_tag(), Some(generation_tag));
+
+            match decoder.decode(&coded_piece) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+        }
+        assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+
+        // Test case 4: Untagged recoder's tagged recoding is same as untagged recoding
+        let untagged_encoder = Encoder::new(data, piece_count).expect("Failed to create Encoder");
+        let untagged_recoder = Recoder::new(
+            untagged_encoder.coded_pieces(&mut rng).take(num_pieces_to_recode_with).flatten().collect(),
+            full_coded_piece_byte_len,
+            piece_count,
+        )
+        .expect("Recoder creation failed");
+        assert_eq!(untagged_recoder.get_generation_tag(), None);
+        assert_eq!(untagged_recoder.recode_tagged(&mut rng_a), untagged_recoder.recode(&mut rng_b).as_bytes());
+    }
+
+    #[test]
+    fn test_recoder_recode_dyn() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+        let encoder = Encoder::new((0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>(), piece_count)
+            .expect("Failed to create Encoder for recoder recode dyn test");
+
+        let num_pieces_to_recode_with = 10;
+        let recoder = Recoder::new(
+            (0..num_pieces_to_recode_with).flat_map(|_| encoder.code(&mut rng)).collect(),
+            encoder.get_full_coded_piece_byte_len(),
+            piece_count,
+        )
+        .expect("Recoder creation failed");
+
+        let seed = rng.random::<u64>();
+        let mut boxed_rng: Box<dyn RngCore> = Box::new(StdRng::seed_from_u64(seed));
+        let mut rng_b = StdRng::seed_from_u64(seed);
+
+        (0..num_pieces_to_recode_with).for_each(|_| {
+            assert_eq!(recoder.recode_dyn(boxed_rng.as_mut()), recoder.recode(&mut rng_b));
+        });
+    }
+
+    #[test]
+    fn test_recoder_recode_into() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+        let encoder = Encoder::new((0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>(), piece_count)
+            .expect("Failed to create Encoder for recoder recode into test");
+
+        let num_pieces_to_recode_with = 10;
+        let recoder = Recoder::new(
+            (0..num_pieces_to_recode_with).flat_map(|_| encoder.code(&mut rng)).collect(),
+            encoder.get_full_coded_piece_byte_len(),
+            piece_count,
+        )
+        .expect("Recoder creation failed");
+
+        let mut short_out = vec![0u8; recoder.get_full_coded_piece_byte_len() - 1];
+        assert_eq!(
+            recoder.recode_into(&mut rng, &mut short_out).expect_err("Expected InvalidPieceLength error"),
+            RLNCError::InvalidPieceLength {
+                expected: recoder.get_full_coded_piece_byte_len(),
+                got: short_out.len()
+            }
+        );
+
+        let seed = rng.random::<u64>();
+        let mut rng_a = StdRng::seed_from_u64(seed);
+        let mut rng_b = StdRng::seed_from_u64(seed);
+
+        // Same buffer is reused across calls, so stale content of previous call must be overwritten.
+        let mut out = (0..recoder.get_full_coded_piece_byte_len()).map(|_| rng.random()).collect::<Vec<u8>>();
+        (0..num_pieces_to_recode_with).for_each(|_| {
+            recoder.recode_into(&mut rng_a, &mut out).expect("Recoding into valid buffer must not fail");
+            assert_eq!(out, recoder.recode(&mut rng_b).as_bytes());
+        });
+    }
+
+    #[test]
+    fn test_recoder_recode_with_vector() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 32usize;
+        let encoder = Encoder::new((0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>(), piece_count)
+            .expect("Failed to create Encoder for recoder recode with vector test");
+
+        let num_pieces_to_recode_with = 10;
+        let recoder = Recoder::new(
+            (0..num_pieces_to_recode_with).flat_map(|_| encoder.code(&mut rng)).collect(),
+            encoder.get_full_coded_piece_byte_len(),
+            piece_count,
+        )
+        .expect("Recoder creation failed");
+
+        let seed = rng.random::<u64>();
+        let mut rng_a = StdRng::seed_from_u64(seed);
+        let mut rng_b = StdRng::seed_from_u64(seed);
+
+        (0..num_pieces_to_recode_with).for_each(|_| {
+            let (full_recoded_piece, coding_vector) = recoder.recode_with_vector(&mut rng_a);
+
+            assert_eq!(full_recoded_piece, recoder.recode(&mut rng_b));
+            assert_eq!(coding_vector.len(), piece_count);
+            assert_eq!(full_recoded_piece.coding_vector(), coding_vector);
+
+            // Coding vector is over the source pieces, so that coding them with it reproduces the recoded piece.
+            assert_eq!(
+                encoder.code_with_coding_vector(&coding_vector).expect("Coding must not fail"),
+                full_recoded_piece
+            );
+        });
+    }
+
+    #[test]
+    fn test_recoder_new_reduced() {
+        let mut rng = rand::rng();
+
+        let data_byte_len = 1024usize;
+        let piece_count = 16usize;
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder for reduced recoder test");
+        let full_coded_piece_byte_len = encoder.get_full_coded_piece_byte_len();
+
+        // Half of the pieces are linearly independent, while the other half are linear combinations of them.
+        let num_independent_pieces = piece_count / 2;
+        let independent_pieces = encoder.coded_pieces(&mut rng).take(num_independent_pieces).flatten().collect::<Vec<u8>>();
+        let recoder = Recoder::new(independent_pieces.clone(), full_coded_piece_byte_len, piece_count).expect("Recoder creation failed");
+
+        let mut received_pieces = independent_pieces;
+        received_pieces.extend((0..num_independent_pieces).flat_map(|_| recoder.recode(&mut rng)));
+
+        let reduced_recoder = Recoder::new_reduced(received_pieces.clone(), full_coded_piece_byte_len, piece_count).expect("Reduced Recoder creation failed");
+        assert_eq!(reduced_recoder.get_num_pieces_recoded_together(), num_independent_pieces);
+        assert_eq!(reduced_recoder.get_full_coded_piece_byte_len(), full_coded_piece_byte_len);
+
+        let mut recoder = Recoder::new(received_pieces, full_coded_piece_byte_len, piece_count).expect("Recoder creation failed");
+        assert_eq!(recoder.get_num_pieces_recoded_together(), 2 * num_independent_pieces);
+        assert_eq!(recoder.reduce(), num_independent_pieces);
+        assert_eq!(recoder.reduce(), 0);
+        assert_eq!(recoder.get_num_pieces_recoded_together(), num_independent_pieces);
+
+        // Pieces recoded by the reduced recoder still carry all of the received information.
+        let mut decoder = Decoder::new(encoder.get_piece_byte_len(), piece_count).expect("Failed to create Decoder for reduced recoder test");
+        while decoder.get_useful_piece_count() < num_independent_pieces {
+            match decoder.decode(&reduced_recoder.recode(&mut rng)) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+        }
+        assert_eq!(decoder.is_useful(&reduced_recoder.recode(&mut rng)), Ok(false));
+
+        while !decoder.is_already_decoded() {
+            match decoder.decode(&encoder.code(&mut rng)) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+        }
+        assert_eq!(decoder.get_decoded_data().expect("Decoding must succeed"), data);
+    }
+
+    #[test]
+    fn test_recoder_never_samples_zero_recoding_vector() {
+        const NUM_TEST_ITERATIONS: usize = 10_000;
+
+        let mut rng = rand::rng();
+
+        // With a single received piece, uniformly sampled recoding vector would be all-zero with probability 1/256,
+        // zeroing out the recoded coding vector.
+        let encoder = Encoder::new(vec![1, 2, 3], 1).expect("Failed to create Encoder for zero recoding vector test");
+        let recoder = Recoder::new(encoder.code(&mut rng).as_bytes().to_vec(), encoder.get_full_coded_piece_byte_len(), 1).expect("Recoder creation failed");
+
+        (0..NUM_TEST_ITERATIONS).for_each(|_| {
+            assert_ne!(recoder.recode(&mut rng).as_bytes()[0], 0);
+        });
+    }
 }
@@ -0,0 +1,279 @@
+use super::{
+    consts::WINDOW_BASE_INDEX_BYTE_LEN,
+    encoder::{Encoder, checked_buffer_byte_len, sample_coding_vector_into},
+};
+use crate::RLNCError;
+use alloc::{vec, vec::Vec};
+use rand::Rng;
+
+/// Sliding window RLNC encoder, for unbounded streams of source pieces, such as live telemetry, where buffering the
+/// whole object before encoding is not an option.
+///
+/// Source pieces, of fixed byte length, are pushed one at a time, and each coded piece combines only the most recent
+/// `window_size` of them. Source pieces are indexed by their position in the stream, starting from 0, and the window
+/// covers source pieces `window_base_index..(window_base_index + window_len)`. Once the window is full, pushing a new
+/// source piece evicts the oldest one, advancing the window base index by one.
+///
+/// A full coded piece consists of the window base index, serialized as `WINDOW_BASE_INDEX_BYTE_LEN` little-endian bytes,
+/// followed by `window_size` coefficients, `i` -th of them applying to source piece `window_base_index + i`, followed by
+/// the coded data. While the window is not full yet, coefficients of the missing source pieces are zero.
+///
+/// Internally, the window is kept in an `Encoder`, used as a ring buffer, where the source piece with stream index `i`
+/// is stored at slot `i % window_size`. So, evicting a source piece costs a single piece copy, irrespective of the
+/// window size.
+#[derive(Clone, Debug)]
+pub struct SlidingWindowEncoder {
+    encoder: Encoder<'static>,
+    window_size: usize,
+    num_pieces_pushed: u64,
+}
+
+impl SlidingWindowEncoder {
+    /// Creates a new `SlidingWindowEncoder`, with an empty window, which can hold up to `window_size` source pieces of
+    /// `piece_byte_len` bytes each.
+    ///
+    /// # Returns
+    /// Returns `Ok(SlidingWindowEncoder)` on success.
+    /// Returns `Err(RLNCError::PieceCountZero)` if `window_size` is zero.
+    /// Returns `Err(RLNCError::PieceLengthZero)` if `piece_byte_len` is zero.
+    /// Returns `Err(RLNCError::ParametersTooLarge)` if a full window would be longer than `isize::MAX` bytes.
+    pub fn new(window_size: usize, piece_byte_len: usize) -> Result<SlidingWindowEncoder, RLNCError> {
+        if window_size == 0 {
+            return Err(RLNCError::PieceCountZero);
+        }
+        if piece_byte_len == 0 {
+            return Err(RLNCError::PieceLengthZero);
+        }
+        checked_buffer_byte_len(window_size, piece_byte_len)?;
+
+        Ok(SlidingWindowEncoder {
+            encoder: Encoder::with_piece_capacity(piece_byte_len, window_size),
+            window_size,
+            num_pieces_pushed: 0,
+        })
+    }
+
+    /// Maximum number of most recent source pieces, being coded together.
+    pub fn get_window_size(&self) -> usize {
+        self.window_size
+    }
+
+    /// Byte length of each source piece.
+    pub fn get_piece_byte_len(&self) -> usize {
+        self.encoder.get_piece_byte_len()
+    }
+
+    /// Number of source pieces currently in the window, which is `self.get_window_size()`, once enough pieces are pushed.
+    pub fn get_window_len(&self) -> usize {
+        self.encoder.get_piece_count()
+    }
+
+    /// Stream index of the oldest source piece in the window.
+    pub fn get_window_base_index(&self) -> u64 {
+        self.num_pieces_pushed - self.get_window_len() as u64
+    }
+
+    /// Each full coded piece consists of the `WINDOW_BASE_INDEX_BYTE_LEN` -bytes window base index, `self.get_window_size()`
+    /// random coefficients and the coded data of `self.get_piece_byte_len()` bytes.
+    pub fn get_full_coded_piece_byte_len(&self) -> usize {
+        WINDOW_BASE_INDEX_BYTE_LEN + self.window_size + self.get_piece_byte_len()
+    }
+
+    /// Pushes the next source piece of the stream into the window, evicting the oldest source piece, if the window is full.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success.
+    /// Returns `Err(RLNCError::InvalidPieceLength)` if `data` is not `self.get_piece_byte_len()` bytes long.
+    pub fn push_piece(&mut self, data: &[u8]) -> Result<(), RLNCError> {
+        if data.len() != self.get_piece_byte_len() {
+            return Err(RLNCError::InvalidPieceLength {
+                expected: self.get_piece_byte_len(),
+                got: data.len(),
+            });
+        }
+
+        if self.get_window_len() < self.window_size {
+            self.encoder.push_piece(data);
+        } else {
+            let slot_idx = (self.num_pieces_pushed % self.window_size as u64) as usize;
+            self.encoder.replace_piece(slot_idx, data);
+        }
+
+        self.num_pieces_pushed += 1;
+        Ok(())
+    }
+
+    /// Encodes source pieces in the current window, using a randomly sampled coding vector, writing the full coded piece
+    /// into the caller-provided `out` buffer. Previous content of `out` is overwritten.
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success, in which case `out` holds the coded piece, prefixed by the window base index and
+    /// the random coding vector.
+    /// Returns `Err(RLNCError::DataLengthZero)` if no source piece is pushed yet.
+    /// Returns `Err(RLNCError::InvalidPieceLength)` if `out.len()` is not `self.get_full_coded_piece_byte_len()`.
+    pub fn code_into<R: Rng + ?Sized>(&self, rng: &mut R, out: &mut [u8]) -> Result<(), RLNCError> {
+        if self.num_pieces_pushed == 0 {
+            return Err(RLNCError::DataLengthZero);
+        }
+        if out.len() != self.get_full_coded_piece_byte_len() {
+            return Err(RLNCError::InvalidPieceLength {
+                expected: self.get_full_coded_piece_byte_len(),
+                got: out.len(),
+            });
+        }
+
+        let (window_base_index, rest) = out.split_at_mut(WINDOW_BASE_INDEX_BYTE_LEN);
+        let (coding_vector, coded_piece) = rest.split_at_mut(self.window_size);
+
+        window_base_index.copy_from_slice(&self.get_window_base_index().to_le_bytes());
+
+        // Coefficients are sampled in the order of ring buffer slots, as the encoder stores source pieces, and missing
+        // source pieces of a not yet full window get zero coefficients.
+        let window_len = self.get_window_len();
+        sample_coding_vector_into(rng, &mut coding_vector[..window_len]);
+        coding_vector[window_len..].fill(0);
+
+        self.encoder.code_data_into(coding_vector, coded_piece);
+
+        // Slot of the oldest source piece goes first, so that coefficients are in the order of stream indices.
+        let oldest_slot_idx = (self.get_window_base_index() % self.window_size as u64) as usize;
+        coding_vector.rotate_left(oldest_slot_idx);
+
+        Ok(())
+    }
+
+    /// Encodes source pieces in the current window, using a randomly sampled coding vector.
+    ///
+    /// Calls `code_into` internally, with a freshly allocated buffer.
+    ///
+    /// # Returns
+    /// Returns `Ok(Vec<u8>)`, the coded piece prefixed by the window base index and the random coding vector, on success.
+    /// Returns `Err(RLNCError::DataLengthZero)` if no source piece is pushed yet.
+    pub fn code<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<Vec<u8>, RLNCError> {
+        let mut full_coded_piece = vec![0u8; self.get_full_coded_piece_byte_len()];
+        self.code_into(rng, &mut full_coded_piece)?;
+
+        Ok(full_coded_piece)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RLNCError, SlidingWindowEncoder, WINDOW_BASE_INDEX_BYTE_LEN};
+    use crate::full::{coded_piece::CodedPiece, decoder::Decoder, encoder::Encoder};
+    use rand::Rng;
+
+    #[test]
+    fn test_sliding_window_encoder_new_invalid_inputs() {
+        assert_eq!(
+            SlidingWindowEncoder::new(0, 64).expect_err("Expected PieceCountZero error"),
+            RLNCError::PieceCountZero
+        );
+        assert_eq!(
+            SlidingWindowEncoder::new(8, 0).expect_err("Expected PieceLengthZero error"),
+            RLNCError::PieceLengthZero
+        );
+        assert_eq!(
+            SlidingWindowEncoder::new(usize::MAX, 2).expect_err("Expected ParametersTooLarge error"),
+            RLNCError::ParametersTooLarge
+        );
+
+        let mut rng = rand::rng();
+        let encoder = SlidingWindowEncoder::new(8, 64).expect("Failed to create SlidingWindowEncoder");
+        assert_eq!(encoder.code(&mut rng), Err(RLNCError::DataLengthZero));
+    }
+
+    #[test]
+    fn test_sliding_window_encoder_window_management() {
+        let mut rng = rand::rng();
+
+        let window_size = 8usize;
+        let piece_byte_len = 64usize;
+
+        let mut encoder = SlidingWindowEncoder::new(window_size, piece_byte_len).expect("Failed to create SlidingWindowEncoder");
+        assert_eq!(encoder.get_window_size(), window_size);
+        assert_eq!(encoder.get_piece_byte_len(), piece_byte_len);
+        assert_eq!(
+            encoder.get_full_coded_piece_byte_len(),
+            WINDOW_BASE_INDEX_BYTE_LEN + window_size + piece_byte_len
+        );
+
+        assert_eq!(
+            encoder.push_piece(&[0u8; 63]),
+            Err(RLNCError::InvalidPieceLength {
+                expected: piece_byte_len,
+                got: 63
+            })
+        );
+        assert_eq!(encoder.get_window_len(), 0);
+
+        let num_pieces_to_push = 3 * window_size + 3;
+        let source_pieces = (0..num_pieces_to_push)
+            .map(|_| (0..piece_byte_len).map(|_| rng.random()).collect::<Vec<u8>>())
+            .collect::<Vec<Vec<u8>>>();
+
+        source_pieces.iter().enumerate().for_each(|(stream_idx, source_piece)| {
+            encoder.push_piece(source_piece).expect("Pushing valid piece must not fail");
+
+            let expected_window_len = (stream_idx + 1).min(window_size);
+            let expected_window_base_index = stream_idx + 1 - expected_window_len;
+
+            assert_eq!(encoder.get_window_len(), expected_window_len);
+            assert_eq!(encoder.get_window_base_index(), expected_window_base_index as u64);
+
+            // Coded piece must be the linear combination of the source pieces in the window, in order of their stream
+            // indices, which is what a regular encoder over the same source pieces produces.
+            let full_coded_piece = encoder.code(&mut rng).expect("Coding must not fail");
+            assert_eq!(full_coded_piece.len(), encoder.get_full_coded_piece_byte_len());
+
+            let (window_base_index, rest) = full_coded_piece.split_at(WINDOW_BASE_INDEX_BYTE_LEN);
+            let (coding_vector, coded_piece) = rest.split_at(window_size);
+
+            assert_eq!(window_base_index, (expected_window_base_index as u64).to_le_bytes());
+            assert!(coding_vector[expected_window_len..].iter().all(|&coeff| coeff == 0));
+
+            let window = source_pieces[expected_window_base_index..=stream_idx].concat();
+            let expected = Encoder::without_padding(window, expected_window_len)
+                .expect("Failed to create Encoder")
+                .code_with_coding_vector(&coding_vector[..expected_window_len])
+                .expect("Coding must not fail");
+            assert_eq!(coded_piece, expected.data());
+        });
+    }
+
+    #[test]
+    fn test_sliding_window_encoder_decodes_full_window() {
+        let mut rng = rand::rng();
+
+        let window_size = 16usize;
+        let piece_byte_len = 128usize;
+
+        let mut encoder = SlidingWindowEncoder::new(window_size, piece_byte_len).expect("Failed to create SlidingWindowEncoder");
+
+        let num_pieces_to_push = window_size + window_size / 2;
+        let source_pieces = (0..num_pieces_to_push)
+            .map(|_| (0..piece_byte_len).map(|_| rng.random()).collect::<Vec<u8>>())
+            .collect::<Vec<Vec<u8>>>();
+        source_pieces
+            .iter()
+            .for_each(|source_piece| encoder.push_piece(source_piece).expect("Pushing valid piece must not fail"));
+
+        let window_base_index = encoder.get_window_base_index() as usize;
+        let mut decoder = Decoder::new(piece_byte_len, window_size).expect("Failed to create Decoder");
+
+        while !decoder.is_already_decoded() {
+            let full_coded_piece = encoder.code(&mut rng).expect("Coding must not fail");
+            let coded_piece = CodedPiece::from_bytes(full_coded_piece[WINDOW_BASE_INDEX_BYTE_LEN..].to_vec(), window_size, piece_byte_len)
+                .expect("Failed to parse coded piece");
+
+            match decoder.decode(&coded_piece) {
+                Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                Err(e) => panic!("Unexpected error during decoding: {e:?}"),
+            }
+        }
+
+        (0..window_size).for_each(|piece_idx| {
+            assert_eq!(decoder.get_piece(piece_idx), Some(source_pieces[window_base_index + piece_idx].clone()));
+        });
+    }
+}
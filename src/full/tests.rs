@@ -1,7 +1,10 @@
 #![cfg(test)]
 
-use super::{decoder::Decoder, encoder::Encoder, recoder::Recoder};
-use crate::RLNCError;
+use super::{coded_piece::CodedPiece, decoder::Decoder, decoder_matrix::DecoderMatrix, encoder::Encoder, encoder::linear_combination_into, recoder::Recoder};
+use crate::{
+    RLNCError,
+    common::{field::Field, gf256::Gf256, gf65536::Gf65536},
+};
 use rand::Rng;
 
 #[test]
@@ -151,7 +154,7 @@ fn prop_test_rlnc_decoding_with_useless_pieces() {
             let coded_piece = encoder.code(&mut rng);
 
             match decoder.decode(&coded_piece) {
-                Ok(_) => coded_pieces_for_recoding.extend_from_slice(&coded_piece),
+                Ok(_) => coded_pieces_for_recoding.extend_from_slice(coded_piece.as_bytes()),
                 Err(e) => match e {
                     RLNCError::PieceNotUseful => {}
                     _ => panic!("Did not expect this error during decoding: {e}"),
@@ -201,3 +204,177 @@ fn prop_test_rlnc_decoding_with_useless_pieces() {
         assert_eq!(data_copy, decoded_data);
     });
 }
+
+#[test]
+fn prop_test_rlnc_interop_across_simd_backends() {
+    use crate::common::simd::forced_backend;
+
+    const NUM_TEST_ITERATIONS: usize = 10;
+
+    const MIN_DATA_BYTE_LEN: usize = 1;
+    const MAX_DATA_BYTE_LEN: usize = 1usize << 14;
+
+    const MIN_PIECE_COUNT: usize = 1;
+    const MAX_PIECE_COUNT: usize = 1usize << 6;
+
+    let mut rng = rand::rng();
+
+    let backends = forced_backend::available();
+    let forced_backend_guard = forced_backend::exclusive();
+
+    (0..NUM_TEST_ITERATIONS).for_each(|_| {
+        // Odd lengths exercise the scalar tail of SIMD routines too.
+        let data_byte_len = rng.random_range(MIN_DATA_BYTE_LEN..=MAX_DATA_BYTE_LEN);
+        let piece_count = rng.random_range(MIN_PIECE_COUNT..=MAX_PIECE_COUNT);
+
+        let data = (0..data_byte_len).map(|_| rng.random()).collect::<Vec<u8>>();
+        let encoder = Encoder::new(data.clone(), piece_count).expect("Failed to create Encoder");
+
+        // Same coding vector must produce byte-for-byte identical coded piece, on every backend.
+        let coding_vector = (0..piece_count).map(|_| rng.random()).collect::<Vec<u8>>();
+        let coded_pieces = backends
+            .iter()
+            .map(|&backend| {
+                forced_backend_guard.set(Some(backend));
+                encoder.code_with_coding_vector(&coding_vector).expect("Coding must not fail")
+            })
+            .collect::<Vec<CodedPiece>>();
+        assert!(coded_pieces.windows(2).all(|pair| pair[0] == pair[1]));
+
+        for &coding_backend in &backends {
+            for &decoding_backend in &backends {
+                forced_backend_guard.set(Some(coding_backend));
+
+                let coded_pieces = (0..(2 * piece_count)).map(|_| encoder.code(&mut rng)).collect::<Vec<CodedPiece>>();
+                let recoder = Recoder::new(
+                    coded_pieces.iter().flat_map(|coded_piece| coded_piece.as_bytes()).copied().collect(),
+                    encoder.get_full_coded_piece_byte_len(),
+                    piece_count,
+                )
+                .expect("Failed to create Recoder");
+                let recoded_pieces = (0..(2 * piece_count)).map(|_| recoder.recode(&mut rng)).collect::<Vec<CodedPiece>>();
+
+                forced_backend_guard.set(Some(decoding_backend));
+
+                let mut decoder = Decoder::new(encoder.get_piece_byte_len(), piece_count).expect("Failed to create Decoder");
+                for piece in coded_pieces.iter().zip(recoded_pieces.iter()).flat_map(|(coded, recoded)| [coded, recoded]) {
+                    match decoder.decode(piece) {
+                        Ok(_) | Err(RLNCError::PieceNotUseful) => {}
+                        Err(RLNCError::ReceivedAllPieces) => break,
+                        Err(e) => panic!("Did not expect this error during decoding: {e}"),
+                    }
+                }
+
+                assert!(
+                    decoder.is_already_decoded(),
+                    "Failed to decode pieces coded on `{coding_backend}`, using `{decoding_backend}`"
+                );
+                assert_eq!(decoder.get_decoded_data().expect("Extracting decoded data must not fail!"), data);
+            }
+        }
+    });
+}
+
+/// Codes random pieces over field `F`, using random coding vectors, and decodes them back, using `DecoderMatrix<F>`.
+fn rlnc_round_trip_over_field<F: Field>() {
+    const NUM_TEST_ITERATIONS: usize = 10;
+
+    const MIN_PIECE_COUNT: usize = 1;
+    const MAX_PIECE_COUNT: usize = 300;
+
+    const MIN_PIECE_SYMBOL_COUNT: usize = 1;
+    const MAX_PIECE_SYMBOL_COUNT: usize = 64;
+
+    let mut rng = rand::rng();
+
+    (0..NUM_TEST_ITERATIONS).for_each(|_| {
+        let piece_count = rng.random_range(MIN_PIECE_COUNT..=MAX_PIECE_COUNT);
+        let piece_byte_len = rng.random_range(MIN_PIECE_SYMBOL_COUNT..=MAX_PIECE_SYMBOL_COUNT) * F::BYTE_LEN;
+        let coding_vector_byte_len = piece_count * F::BYTE_LEN;
+
+        let pieces = (0..(piece_count * piece_byte_len)).map(|_| rng.random()).collect::<Vec<u8>>();
+        let mut matrix = DecoderMatrix::<F>::new(piece_count, piece_byte_len).expect("creating matrix must not fail");
+
+        while matrix.rank() < piece_count {
+            let mut full_coded_piece = vec![0u8; coding_vector_byte_len + piece_byte_len];
+            let (coding_vector, coded_piece) = full_coded_piece.split_at_mut(coding_vector_byte_len);
+
+            coding_vector
+                .chunks_exact_mut(F::BYTE_LEN)
+                .for_each(|coefficient| F::random(&mut rng).write_le_bytes(coefficient));
+            linear_combination_into::<F>(&pieces, piece_byte_len, coding_vector, coded_piece);
+
+            matrix.add_row_and_reduce(&full_coded_piece).expect("Adding coded piece must not fail");
+        }
+
+        (0..piece_count).for_each(|row_idx| {
+            (0..piece_count).for_each(|col_idx| {
+                let expected = if row_idx == col_idx { F::one() } else { F::zero() };
+                assert_eq!(matrix.coefficient(row_idx, col_idx), expected);
+            });
+
+            assert_eq!(matrix.payload(row_idx), &pieces[(row_idx * piece_byte_len)..((row_idx + 1) * piece_byte_len)]);
+        });
+    });
+}
+
+#[test]
+fn prop_test_rlnc_round_trip_over_fields() {
+    rlnc_round_trip_over_field::<Gf256>();
+    rlnc_round_trip_over_field::<Gf65536>();
+}
+
+#[test]
+fn test_rlnc_single_piece_generation() {
+    let mut rng = rand::rng();
+
+    // A tiny final generation, e.g. produced by a chunker, ends up being coded as a single piece.
+    let data = (0..rng.random_range(1..=1024usize)).map(|_| rng.random()).collect::<Vec<u8>>();
+    let encoder = Encoder::new(data.clone(), 1).expect("Failed to create Encoder");
+    assert_eq!(encoder.get_piece_byte_len(), data.len() + 1);
+
+    // Test case 1: Encode -> decode, where the first piece with a non-zero coefficient completes decoding
+    let mut decoder = Decoder::new(encoder.get_piece_byte_len(), 1).expect("Failed to create Decoder");
+
+    let coded_piece = encoder.code_with_coding_vector(&[0]).expect("Coding must not fail");
+    assert_eq!(decoder.decode(&coded_piece), Err(RLNCError::ZeroCodingVector));
+    assert_eq!(decoder.rank(), 0);
+
+    let coefficient = rng.random_range(1..=u8::MAX);
+    let coded_piece = encoder.code_with_coding_vector(&[coefficient]).expect("Coding must not fail");
+    decoder.decode(&coded_piece).expect("Piece with non-zero coefficient must be useful");
+
+    assert!(decoder.is_already_decoded());
+    assert_eq!(decoder.get_received_piece_count(), 1);
+    assert_eq!(decoder.coefficient_matrix(), [[1]]);
+    assert_eq!(decoder.decode(&encoder.code(&mut rng)), Err(RLNCError::ReceivedAllPieces));
+    assert_eq!(decoder.get_decoded_data().expect("Extracting decoded data must not fail!"), data);
+
+    // Test case 2: Encode -> recode -> decode, where each recoded piece is just a scalar multiple of the source piece
+    let coded_pieces = (0..3).flat_map(|_| encoder.code(&mut rng)).collect::<Vec<u8>>();
+    let recoder = Recoder::new(coded_pieces, encoder.get_full_coded_piece_byte_len(), 1).expect("Construction of RLNC recoder must not fail!");
+
+    let mut decoder = Decoder::new(encoder.get_piece_byte_len(), 1).expect("Failed to create Decoder");
+    while !decoder.is_already_decoded() {
+        match decoder.decode(&recoder.recode(&mut rng)) {
+            Ok(_) | Err(RLNCError::ZeroCodingVector) => {}
+            Err(e) => panic!("Did not expect this error during decoding: {e}"),
+        }
+    }
+
+    assert_eq!(decoder.get_useful_piece_count(), 1);
+    assert_eq!(decoder.get_decoded_data().expect("Extracting decoded data must not fail!"), data);
+
+    // Test case 3: Batch RREF of a single column matrix normalizes its only row, while no more rows fit in
+    let mut matrix = DecoderMatrix::<Gf256>::new(1, encoder.get_piece_byte_len()).expect("creating matrix must not fail");
+    let coded_piece = encoder.code_with_coding_vector(&[rng.random_range(2..=u8::MAX)]).expect("Coding must not fail");
+
+    matrix.add_row(coded_piece.as_bytes()).expect("Adding coded piece must not fail");
+    assert_eq!(matrix.add_row(coded_piece.as_bytes()).map(|_| ()), Err(RLNCError::ReceivedAllPieces));
+
+    matrix.rref();
+    assert_eq!(matrix.rank(), 1);
+    assert_eq!(matrix.pivot_cols(), [0]);
+    assert_eq!(matrix.coefficient(0, 0), Gf256::one());
+    assert_eq!(matrix.payload(0)[..data.len()], data);
+}
@@ -39,6 +39,9 @@
 //! -   **Flexible data handling**: Supports arbitrary byte lengths for input
 //!     data, with internal padding and boundary markers for robust decoding.
 //! -   **Error Handling**: Comprehensive `RLNCError` enum for various failure scenarios.
+//! -   **`no_std` support**: With the default `std` feature disabled, the crate only depends on `core` and `alloc`,
+//!     so that it can run on embedded targets with a global allocator. SIMD backends are then selected based on
+//!     target features enabled at compile-time, as CPU features can't be detected at runtime.
 //!
 //! ## Example Usage
 //!
@@ -48,10 +51,7 @@
 //!
 //! ```rust
 //! use rand::Rng;
-//! use rlnc::{
-//!     RLNCError,
-//!     full::{decoder::Decoder, encoder::Encoder},
-//! };
+//! use rlnc::prelude::*;
 //!
 //! let mut rng = rand::rng();
 //!
@@ -65,7 +65,7 @@
 //! let encoder = Encoder::new(original_data, piece_count).expect("Failed to create RLNC encoder");
 //!
 //! // 3. Initialize the Decoder
-//! let mut decoder = Decoder::new(encoder.get_piece_byte_len(), encoder.get_piece_count()).expect("Failed to create RLNC decoder");
+//! let mut decoder = Decoder::matching(&encoder).expect("Failed to create RLNC decoder");
 //!
 //! // 4. Generate coded pieces and feed them to the decoder until decoding is complete
 //! while !decoder.is_already_decoded() {
@@ -95,13 +95,21 @@
 //! [dependencies]
 //! rlnc = "=0.8.1"                                      # On x86 target, it offers AVX2 and SSSE3 optimization for fast encoding/ recoding.
 //! # or
-//! rlnc = { version = "=0.8.1", features = "parallel" } # Uses `rayon`-based data-parallelism for fast encoding/ recoding.
+//! rlnc = { version = "=0.8.1", features = "parallel" } # Uses `rayon`-based data-parallelism for fast encoding/ recoding/ decoding.
 //!
 //! rand = { version = "=0.9.1" } # Required for random number generation
 //! ```
 //!
 //! For more see README in `rlnc` repository @ <https://github.com/itzmeanjan/rlnc>.
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+pub mod binary;
+#[cfg(feature = "capi")]
+pub mod capi;
 pub mod common;
 pub mod full;
+pub mod prelude;
 pub use crate::common::errors::RLNCError;
@@ -0,0 +1,7 @@
+//! Re-exports the everyday API of the crate, so that a single `use rlnc::prelude::*;` brings in encoder, recoder and
+//! decoder, along with the coded piece they exchange and the error type they return.
+
+pub use crate::{
+    RLNCError,
+    full::{coded_piece::CodedPiece, decoder::Decoder, encoder::Encoder, recoder::Recoder},
+};